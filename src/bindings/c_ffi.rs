@@ -1,23 +1,59 @@
 // src/bindings/c_ffi.rs
 #![cfg(feature = "native_ffi_setup")] // Atau fitur yang lebih spesifik jika perlu
 use crate::core::{
-    validate_input_with_llm_sync,
+    configure_default_api_key, default_validator, reset_default_validator, resolve_label, BatchInput, FormInput,
+    SemanticValidator, ValidationError, DEFAULT_CACHE,
 };
 
-use crate::config::API_CONFIG;
-
-use crate::models::{
-    SupportedModel,ValidationResponse
-};
+use crate::models::{list_input_types, InputType, SupportedModel};
 
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+use std::sync::Arc;
+use std::time::Duration;
 
-/// Fungsi error handler internal untuk FFI C
+/// Detail error terstruktur yang disertakan pada JSON hasil FFI.
+#[derive(Serialize)]
+struct FfiErrorInfo {
+    code: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_after_secs: Option<f64>,
+}
+
+/// Bentuk JSON error FFI: kompatibel dengan `ValidationResponse` plus objek `error`.
+#[derive(Serialize)]
+struct FfiErrorResponse {
+    valid: bool,
+    message: String,
+    error: FfiErrorInfo,
+}
+
+/// Fungsi error handler internal untuk FFI C (argumen tidak valid dari pemanggil)
 fn handle_c_ffi_error(message: String) -> *mut c_char {
-    let error_response = ValidationResponse {
+    ffi_error_json(message, FfiErrorInfo { code: "invalid_argument", status: None, retry_after_secs: None })
+}
+
+/// Mengubah ValidationError menjadi JSON error FFI dengan kode yang sesuai.
+fn handle_validation_error(err: &ValidationError) -> *mut c_char {
+    ffi_error_json(
+        err.to_string(),
+        FfiErrorInfo {
+            code: err.code(),
+            status: err.status(),
+            retry_after_secs: err.retry_after().map(|d| d.as_secs_f64()),
+        },
+    )
+}
+
+fn ffi_error_json(message: String, error: FfiErrorInfo) -> *mut c_char {
+    let error_response = FfiErrorResponse {
         valid: false,
         message,
+        error,
     };
     let json_error = serde_json::to_string(&error_response).unwrap_or_else(|_| {
         "{\"valid\":false,\"message\":\"Failed to serialize FFI error response\"}".to_string()
@@ -32,81 +68,187 @@ fn handle_c_ffi_error(message: String) -> *mut c_char {
     )
 }
 
+/// Membaca argumen string C. Error berupa JSON error FFI yang siap dikembalikan.
+fn cstr_arg<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, *mut c_char> {
+    if ptr.is_null() {
+        return Err(handle_c_ffi_error(format!("Pointer for {} is null.", name)));
+    }
+    // Kontrak UTF-8/terminasi NUL dan masa hidup pointer ada di sisi pemanggil C.
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| handle_c_ffi_error(format!("Invalid UTF-8 input string for {}.", name)))
+}
+
+/// Memeriksa nilai `SupportedModel` dari C, yang bisa berisi angka di luar enum.
+fn model_arg(model_selector: SupportedModel) -> Result<SupportedModel, *mut c_char> {
+    SupportedModel::from_int(model_selector as i32).ok_or_else(|| {
+        handle_c_ffi_error(format!(
+            "Invalid model selector value received: {}. Valid options are: [{}].",
+            model_selector as i32,
+            SupportedModel::valid_options_desc()
+        ))
+    })
+}
+
+/// Mem-parse argumen JSON dari C.
+fn json_arg<T: DeserializeOwned>(json: &str, name: &str) -> Result<T, *mut c_char> {
+    serde_json::from_str(json).map_err(|e| handle_c_ffi_error(format!("Invalid {} JSON: {}", name, e)))
+}
+
+/// Menyerialisasi hasil menjadi string C milik Rust (bebaskan dengan `free_rust_string`).
+fn json_out<T: Serialize>(value: &T) -> *mut c_char {
+    let json = match serde_json::to_string(value) {
+        Ok(json) => json,
+        Err(e) => return handle_c_ffi_error(format!("Failed to serialize FFI result: {}", e)),
+    };
+    CString::new(json).map_or_else(
+        |e| handle_c_ffi_error(format!("Failed to create CString from JSON result: {}", e)),
+        |cs| cs.into_raw(),
+    )
+}
+
+/// Hasil operasi validator sebagai JSON, atau JSON error dengan kode yang sesuai.
+fn json_result<T: Serialize>(result: Result<T, ValidationError>) -> *mut c_char {
+    match result {
+        Ok(value) => json_out(&value),
+        Err(e) => handle_validation_error(&e),
+    }
+}
+
+/// Menjalankan isi fungsi FFI; `Err` berisi JSON error yang sudah jadi.
+fn ffi_output(body: impl FnOnce() -> Result<*mut c_char, *mut c_char>) -> *mut c_char {
+    body().unwrap_or_else(|error_json| error_json)
+}
+
+fn validator_arg() -> Result<Arc<SemanticValidator>, *mut c_char> {
+    default_validator().map_err(|e| handle_validation_error(&e))
+}
+
 #[no_mangle]
 pub extern "C" fn validate_text_ffi(
     text_ptr: *const c_char,
     model_selector: SupportedModel,
     input_type_ptr: *const c_char,
 ) -> *mut c_char {
-    if text_ptr.is_null() {
-        return handle_c_ffi_error("Input text pointer is null.".to_string());
-    }
+    ffi_output(|| {
+        let text_input = cstr_arg(text_ptr, "text")?;
+        let model_variant = model_arg(model_selector)?;
+        let input_type_str = cstr_arg(input_type_ptr, "input type")?;
+        let validator = validator_arg()?;
+        Ok(json_result(validator.validate_with_model_sync(text_input, model_variant, input_type_str)))
+    })
+}
 
-    let c_str_text = unsafe { CStr::from_ptr(text_ptr) };
-    let text_input = match c_str_text.to_str() {
-        Ok(s) => s,
-        Err(_) => return handle_c_ffi_error("Invalid UTF-8 input string for text.".to_string()),
-    };
+/// Validasi banyak field sekaligus. `items_json` berupa array JSON
+/// `[{"field": ..., "value": ..., "input_type": ...}]`; hasilnya objek JSON
+/// `{field: {valid, message, model}}` atau JSON error seperti `validate_text_ffi`.
+#[no_mangle]
+pub extern "C" fn validate_batch_ffi(items_json_ptr: *const c_char, model_selector: SupportedModel) -> *mut c_char {
+    ffi_output(|| {
+        let items: Vec<BatchInput> = json_arg(cstr_arg(items_json_ptr, "batch items")?, "batch items")?;
+        let model_variant = model_arg(model_selector)?;
+        let validator = validator_arg()?;
+        Ok(json_result(validator.validate_batch_with_model_sync(&items, model_variant)))
+    })
+}
 
-    let model_name_to_use: &'static str;
-    match SupportedModel::from_int(model_selector as i32) {
-        Some(valid_model_variant) => {
-            model_name_to_use = valid_model_variant.as_str();
-        }
-        None => {
-            return handle_c_ffi_error(format!(
-                "Invalid model selector value received: {}. Valid options are: [{}].",
-                model_selector as i32,
-                SupportedModel::valid_options_desc()
-            ));
-        }
-    }
+/// Pemeriksaan konsistensi antar-field. `form_json` berupa objek JSON
+/// `{"fields": {...}, "relationships": [...]}`; hasilnya JSON laporan formulir
+/// atau JSON error seperti `validate_text_ffi`.
+#[no_mangle]
+pub extern "C" fn check_form_ffi(form_json_ptr: *const c_char, model_selector: SupportedModel) -> *mut c_char {
+    ffi_output(|| {
+        let form: FormInput = json_arg(cstr_arg(form_json_ptr, "form")?, "form")?;
+        let model_variant = model_arg(model_selector)?;
+        let validator = validator_arg()?;
+        Ok(json_result(validator.check_form_with_model_sync(&form, model_variant)))
+    })
+}
 
-    // Validasi dan konversi input_type_ptr
-    if input_type_ptr.is_null() {
-        return handle_c_ffi_error("Input type pointer is null.".to_string());
-    }
-    let c_str_input_type = unsafe { CStr::from_ptr(input_type_ptr) };
-    let input_type_str = match c_str_input_type.to_str() {
-        Ok(s) => s,
-        Err(_) => {
-            return handle_c_ffi_error("Invalid UTF-8 input string for input type.".to_string())
-        }
-    };
+/// Menebak tipe sebuah nilai. `label_ptr` boleh null; jika diisi, nilai yang jelas bertipe
+/// lain ditandai di `label_mismatch`. Hasilnya JSON `{"candidates", "label_mismatch", "model"}`
+/// atau JSON error seperti `validate_text_ffi`.
+#[no_mangle]
+pub extern "C" fn classify_input_ffi(
+    value_ptr: *const c_char,
+    label_ptr: *const c_char,
+    model_selector: SupportedModel,
+) -> *mut c_char {
+    ffi_output(|| {
+        let value = cstr_arg(value_ptr, "value")?;
+        let label = if label_ptr.is_null() { None } else { Some(cstr_arg(label_ptr, "label")?) };
+        let model_variant = model_arg(model_selector)?;
+        let validator = validator_arg()?;
+        Ok(json_result(validator.classify_input_with_model_sync(value, label, model_variant)))
+    })
+}
 
-    match &*API_CONFIG {
-        Ok(context_ref) => {
-            match validate_input_with_llm_sync(
-                text_input,
-                model_name_to_use,
-                input_type_str,
-                context_ref,
-            ) {
-                Ok(res) => {
-                    let json_res = serde_json::to_string(&res).unwrap_or_else(|_| "{\"valid\":false,\"message\":\"Failed to serialize successful validation response\"}".to_string());
-                    CString::new(json_res).map_or_else(
-                        |e| {
-                            handle_c_ffi_error(format!(
-                                "Failed to create CString from JSON result: {}",
-                                e
-                            ))
-                        },
-                        |cs| cs.into_raw(),
-                    )
-                }
-                Err(e) => handle_c_ffi_error(format!(
-                    "Error during validation with model '{}': {}",
-                    model_name_to_use, e
-                )),
-            }
-        }
-        Err(init_err_msg) => handle_c_ffi_error(format!(
-            "AppContext initialization failed: {}",
-            init_err_msg
-        )),
-    }
+/// Mengatur atau merotasi API key validator bawaan (dipakai semua fungsi `*_ffi`)
+/// tanpa me-restart proses. Pengaturan lain tetap dari konfigurasi aktif atau
+/// variabel lingkungan.
+///
+/// Mengembalikan null jika berhasil, atau JSON error seperti `validate_text_ffi`
+/// yang harus dibebaskan dengan `free_rust_string`.
+#[no_mangle]
+pub extern "C" fn configure_api_key_ffi(api_key_ptr: *const c_char) -> *mut c_char {
+    ffi_output(|| {
+        let api_key = cstr_arg(api_key_ptr, "API key")?;
+        configure_default_api_key(api_key).map_err(|e| handle_validation_error(&e.redacted(api_key)))?;
+        Ok(std::ptr::null_mut())
+    })
+}
+
+/// Melupakan validator bawaan beserta API key-nya; panggilan berikutnya
+/// membaca ulang variabel lingkungan.
+#[no_mangle]
+pub extern "C" fn reset_configuration_ffi() {
+    reset_default_validator();
+}
+
+/// Mengatur cache hasil milik `validate_text_ffi`.
+/// `capacity` 0 menonaktifkan cache; `ttl_secs` 0 berarti tanpa kedaluwarsa.
+#[no_mangle]
+pub extern "C" fn cache_configure_ffi(capacity: usize, ttl_secs: u64) {
+    let ttl = (ttl_secs > 0).then(|| Duration::from_secs(ttl_secs));
+    DEFAULT_CACHE.configure(capacity, ttl);
+}
+
+/// Statistik cache dalam bentuk JSON. Bebaskan hasilnya dengan `free_rust_string`.
+#[no_mangle]
+pub extern "C" fn cache_stats_ffi() -> *mut c_char {
+    json_out(&DEFAULT_CACHE.stats())
+}
+
+#[no_mangle]
+pub extern "C" fn cache_clear_ffi() {
+    DEFAULT_CACHE.clear();
+}
+
+/// Daftar tipe input bawaan sebagai array JSON `[{id, label, description, aliases}]`.
+/// Bebaskan hasilnya dengan `free_rust_string`.
+#[no_mangle]
+pub extern "C" fn list_input_types_ffi() -> *mut c_char {
+    json_out(&list_input_types())
+}
+
+/// Tipe kanonis untuk sebuah label sebagai JSON `{id, label, description, aliases}`,
+/// atau `null` jika label tidak dikenal. Bebaskan hasilnya dengan `free_rust_string`.
+#[no_mangle]
+pub extern "C" fn resolve_input_type_ffi(label_ptr: *const c_char) -> *mut c_char {
+    ffi_output(|| {
+        let label = cstr_arg(label_ptr, "input type")?;
+        Ok(json_out(&InputType::resolve(label).map(|input_type| input_type.info())))
+    })
+}
+
+/// Pemetaan longgar untuk label formulir sebagai JSON `{input_type, confidence, method, matched}`,
+/// atau `null` jika tidak ada tipe yang cocok. Bebaskan hasilnya dengan `free_rust_string`.
+#[no_mangle]
+pub extern "C" fn resolve_label_ffi(label_ptr: *const c_char) -> *mut c_char {
+    ffi_output(|| Ok(json_out(&resolve_label(cstr_arg(label_ptr, "label")?))))
 }
 
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 #[no_mangle]
 pub extern "C" fn free_rust_string(s: *mut c_char) {
     if !s.is_null() {
@@ -1,12 +1,21 @@
 // src/bindings/flutter/api.rs
 
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use flutter_rust_bridge::DartFnFuture;
+
+use crate::core::label_resolver::{self, LabelResolution};
 use crate::core::{
-    validate_input_with_llm_sync,
+    BatchInput, FormInput, Locale, Relationship, RelationshipKind, SemanticValidator, TypeRules, ValidationError,
 };
-use crate::config::ApiConfig;
 
 use crate::models::{
+    InputType,
+    InputTypeInfo,
     SupportedModel,
+    ValidationResponse,
 };
 // ---------------------------------------------------------
 // 1. OBJECT / CLASS (Stateful)
@@ -15,17 +24,36 @@ use crate::models::{
 // Di FRB, cukup definisikan struct public biasa.
 // FRB otomatis mendeteksi ini sebagai Class di Dart.
 pub struct SemanticValidatorFrb {
-    config: ApiConfig,
+    inner: Arc<SemanticValidator>,
 }
 
 impl SemanticValidatorFrb {
     
     // Constructor
     // FRB otomatis mengenali method bernama 'new' sebagai constructor di Dart.
-    pub fn new(api_key: String) -> Self {
-        Self {
-            config: ApiConfig { api_key },
-        }
+    pub fn new(api_key: String) -> Result<Self, ValidationErrorFrb> {
+        let inner = SemanticValidator::builder()
+            .api_key(api_key)
+            .build()?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    // Constructor dengan bahasa pesan ("id" atau "en")
+    pub fn with_locale(api_key: String, locale: String) -> Result<Self, ValidationErrorFrb> {
+        let inner = SemanticValidator::builder()
+            .api_key(api_key)
+            .locale(locale.parse::<Locale>()?)
+            .build()?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    // Constructor dengan file aturan tipe input buatan pengguna (.toml/.yaml)
+    pub fn with_rules_file(api_key: String, rules_file: String) -> Result<Self, ValidationErrorFrb> {
+        let inner = SemanticValidator::builder()
+            .api_key(api_key)
+            .type_rules(Arc::new(TypeRules::from_path(rules_file)?))
+            .build()?;
+        Ok(Self { inner: Arc::new(inner) })
     }
 
     // Method Validasi
@@ -37,24 +65,171 @@ impl SemanticValidatorFrb {
         text: String, 
         model: ModelSelectorFrb, 
         label: String
-    ) -> anyhow::Result<ResponseDataFrb> {
+    ) -> Result<ResponseDataFrb, ValidationErrorFrb> {
         // Kita konversi enum FRB ke enum Core Logic
         let model_core: SupportedModel = model.into();
 
         // Panggil core logic (Sync)
         // Meskipun ini blocking di Rust, FRB membuatnya Async di Dart. Aman!
-        let result = validate_input_with_llm_sync(
-            &text, 
-            model_core.as_str(), 
-            &label, 
-            &self.config
-        ).map_err(|e| anyhow::anyhow!(e.to_string()))?; // Simplifikasi Error dengan Anyhow
-
-        Ok(ResponseDataFrb {
-            valid: result.valid,
-            message: result.message,
+        let result = self.inner
+            .validate_with_model_sync(&text, model_core, &label)?; // Dipetakan ke ValidationErrorFrb
+
+        Ok(result.into())
+    }
+
+    // Validasi banyak field dalam satu request; hasil dikunci dengan field_id
+    pub fn validate_batch(
+        &self,
+        items: Vec<BatchItemFrb>,
+        model: ModelSelectorFrb,
+    ) -> Result<HashMap<String, ResponseDataFrb>, ValidationErrorFrb> {
+        let items: Vec<BatchInput> = items
+            .into_iter()
+            .map(|item| BatchInput::new(item.field_id, item.value, item.input_type))
+            .collect();
+
+        let results = self.inner
+            .validate_batch_with_model_sync(&items, model.into())?;
+
+        Ok(results
+            .into_iter()
+            .map(|(field, result)| (field, result.into()))
+            .collect())
+    }
+
+    // Validasi paralel, satu request per item; hasil sesuai urutan input.
+    // `on_progress` dipanggil (tanpa ditunggu) setiap kali satu item selesai.
+    pub fn validate_many(
+        &self,
+        items: Vec<BatchItemFrb>,
+        model: ModelSelectorFrb,
+        concurrency: u32,
+        on_progress: impl Fn(ProgressFrb) -> DartFnFuture<()>,
+    ) -> Vec<ItemOutcomeFrb> {
+        let items: Vec<BatchInput> = items
+            .into_iter()
+            .map(|item| BatchInput::new(item.field_id, item.value, item.input_type))
+            .collect();
+
+        let results = self.inner.validate_many_sync(&items, model.into(), concurrency as usize, |progress| {
+            // Callback dijalankan di dalam runtime runner, jadi future Dart cukup di-spawn.
+            tokio::spawn(on_progress(ProgressFrb {
+                completed: progress.completed as u64,
+                total: progress.total as u64,
+                failed: progress.failed as u64,
+            }));
+        });
+
+        items
+            .into_iter()
+            .zip(results)
+            .map(|(item, result)| match result {
+                Ok(result) => ItemOutcomeFrb {
+                    field_id: item.field,
+                    response: Some(result.into()),
+                    error: None,
+                },
+                Err(err) => ItemOutcomeFrb {
+                    field_id: item.field,
+                    response: None,
+                    error: Some(err.into()),
+                },
+            })
+            .collect()
+    }
+
+    // Pemeriksaan konsistensi antar-field (misal kota vs kode pos, tanggal lahir vs umur)
+    pub fn check_form(
+        &self,
+        fields: Vec<FormFieldFrb>,
+        relationships: Vec<RelationshipFrb>,
+        model: ModelSelectorFrb,
+    ) -> Result<FormReportFrb, ValidationErrorFrb> {
+        let mut form = FormInput::new();
+        for field in fields {
+            form = form.field(field.name, field.value, field.input_type);
+        }
+        for relationship in relationships {
+            let kind = RelationshipKind::from_name(&relationship.kind).ok_or_else(|| ValidationErrorFrb::Config {
+                message: format!("Jenis hubungan tidak dikenal: {}", relationship.kind),
+            })?;
+            form = form.relationship(Relationship {
+                kind,
+                fields: relationship.fields,
+                description: relationship.description,
+            });
+        }
+
+        let report = self.inner.check_form_with_model_sync(&form, model.into())?;
+        Ok(FormReportFrb {
+            consistent: report.consistent,
+            findings: report
+                .findings
+                .into_iter()
+                .map(|finding| FindingFrb {
+                    fields: finding.fields,
+                    relationship: finding.relationship.map(|kind| kind.as_str().to_string()),
+                    message: finding.message,
+                    source: finding.source.as_str().to_string(),
+                })
+                .collect(),
+            field_findings: report.field_findings.into_iter().collect(),
+            model: report.model,
         })
     }
+
+    // Menebak tipe sebuah nilai; `label` opsional untuk menandai nilai yang salah tempat
+    pub fn classify_input(
+        &self,
+        value: String,
+        label: Option<String>,
+        model: ModelSelectorFrb,
+    ) -> Result<ClassificationFrb, ValidationErrorFrb> {
+        let classification = self.inner.classify_input_with_model_sync(&value, label.as_deref(), model.into())?;
+        Ok(ClassificationFrb {
+            candidates: classification
+                .candidates
+                .into_iter()
+                .map(|candidate| TypeCandidateFrb {
+                    input_type: candidate.input_type.as_str().to_string(),
+                    score: candidate.score,
+                    source: candidate.source.as_str().to_string(),
+                })
+                .collect(),
+            label_mismatch: classification.label_mismatch.map(|mismatch| LabelMismatchFrb {
+                label: mismatch.label,
+                expected: mismatch.expected.as_str().to_string(),
+                detected: mismatch.detected.as_str().to_string(),
+                message: mismatch.message,
+            }),
+            model: classification.model,
+        })
+    }
+
+    // Batas requests-per-minute per model (0 = tanpa batas)
+    pub fn set_rate_limit(&self, model: ModelSelectorFrb, requests_per_minute: u32) {
+        self.inner.set_rate_limit(model.into(), requests_per_minute);
+    }
+
+    // Cache hasil: capacity 0 = nonaktif, ttl_secs None = tanpa kedaluwarsa
+    pub fn configure_cache(&self, capacity: u64, ttl_secs: Option<u64>) {
+        self.inner
+            .configure_cache(capacity as usize, ttl_secs.map(Duration::from_secs));
+    }
+
+    pub fn cache_stats(&self) -> CacheStatsFrb {
+        let stats = self.inner.cache_stats();
+        CacheStatsFrb {
+            hits: stats.hits,
+            misses: stats.misses,
+            entries: stats.entries as u64,
+            capacity: stats.capacity as u64,
+        }
+    }
+
+    pub fn clear_cache(&self) {
+        self.inner.clear_cache();
+    }
 }
 
 // ---------------------------------------------------------
@@ -89,4 +264,194 @@ impl From<ModelSelectorFrb> for SupportedModel {
 pub struct ResponseDataFrb {
     pub valid: bool,
     pub message: String,
-}
\ No newline at end of file
+    /// Model yang menghasilkan putusan (kosong jika ditolak validasi sintaksis).
+    pub model: Option<String>,
+    /// Tahap yang memutuskan: "syntax", "cache", atau "llm".
+    pub stage: String,
+    /// Kode alasan stabil, mis. "valid", "syntax_invalid", "placeholder".
+    pub code: String,
+    pub confidence: Option<f64>,
+    pub suggestion: Option<String>,
+    pub latency_ms: u64,
+}
+
+impl From<ValidationResponse> for ResponseDataFrb {
+    fn from(response: ValidationResponse) -> Self {
+        ResponseDataFrb {
+            valid: response.valid,
+            message: response.message,
+            model: response.model,
+            stage: response.stage.as_str().to_string(),
+            code: response.code.as_str().to_string(),
+            confidence: response.confidence,
+            suggestion: response.suggestion,
+            latency_ms: response.latency_ms,
+        }
+    }
+}
+
+pub struct BatchItemFrb {
+    pub field_id: String,
+    pub value: String,
+    pub input_type: String,
+}
+
+// Tepat salah satu dari `response` atau `error` terisi.
+pub struct ItemOutcomeFrb {
+    pub field_id: String,
+    pub response: Option<ResponseDataFrb>,
+    pub error: Option<ValidationErrorFrb>,
+}
+
+pub struct ProgressFrb {
+    pub completed: u64,
+    pub total: u64,
+    pub failed: u64,
+}
+
+pub struct FormFieldFrb {
+    pub name: String,
+    pub value: String,
+    pub input_type: String,
+}
+
+// `kind`: "birth_date_age", "city_postal_code", "email_domain_company", atau "custom"
+pub struct RelationshipFrb {
+    pub kind: String,
+    pub fields: Vec<String>,
+    pub description: Option<String>,
+}
+
+pub struct FindingFrb {
+    pub fields: Vec<String>,
+    pub relationship: Option<String>,
+    pub message: String,
+    /// "local" atau "llm"
+    pub source: String,
+}
+
+pub struct FormReportFrb {
+    pub consistent: bool,
+    pub findings: Vec<FindingFrb>,
+    pub field_findings: HashMap<String, Vec<String>>,
+    pub model: Option<String>,
+}
+
+pub struct TypeCandidateFrb {
+    /// Id tipe kanonis, misalnya "email" atau "person_name"
+    pub input_type: String,
+    pub score: f64,
+    /// "local" atau "llm"
+    pub source: String,
+}
+
+pub struct LabelMismatchFrb {
+    pub label: String,
+    pub expected: String,
+    pub detected: String,
+    pub message: String,
+}
+
+pub struct ClassificationFrb {
+    pub candidates: Vec<TypeCandidateFrb>,
+    pub label_mismatch: Option<LabelMismatchFrb>,
+    pub model: Option<String>,
+}
+
+pub struct CacheStatsFrb {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: u64,
+    pub capacity: u64,
+}
+
+// Tipe input bawaan untuk dropdown UI; `id` stabil, `label` dapat dikirim ke validator.
+pub struct InputTypeFrb {
+    pub id: String,
+    pub label: String,
+    pub description: String,
+    pub aliases: Vec<String>,
+}
+
+impl From<InputTypeInfo> for InputTypeFrb {
+    fn from(info: InputTypeInfo) -> Self {
+        InputTypeFrb {
+            id: info.id,
+            label: info.label,
+            description: info.description,
+            aliases: info.aliases,
+        }
+    }
+}
+
+// Daftar semua tipe input bawaan
+pub fn list_input_types() -> Vec<InputTypeFrb> {
+    InputType::ALL.iter().map(|input_type| input_type.info().into()).collect()
+}
+
+// Tipe kanonis untuk sebuah label, atau null jika label tidak dikenal
+pub fn resolve_input_type(label: String) -> Option<InputTypeFrb> {
+    InputType::resolve(&label).map(|input_type| input_type.info().into())
+}
+
+/// Hasil pemetaan label formulir: id tipe kanonis, confidence 0-1, dan metode pencocokan.
+pub struct LabelResolutionFrb {
+    pub input_type: String,
+    pub confidence: f64,
+    pub method: String,
+    pub matched: String,
+}
+
+impl From<LabelResolution> for LabelResolutionFrb {
+    fn from(resolution: LabelResolution) -> Self {
+        LabelResolutionFrb {
+            input_type: resolution.input_type.as_str().to_string(),
+            confidence: resolution.confidence,
+            method: resolution.method.as_str().to_string(),
+            matched: resolution.matched,
+        }
+    }
+}
+
+// Pemetaan longgar untuk label formulir ("No. WA", "bday", "nama_lengkap"), atau null
+pub fn resolve_label(label: String) -> Option<LabelResolutionFrb> {
+    label_resolver::resolve_label(&label).map(Into::into)
+}
+
+// ---------------------------------------------------------
+// 4. ERROR
+// ---------------------------------------------------------
+
+// Enum error biasa; FRB mengubahnya menjadi exception bertipe di Dart.
+#[derive(Debug)]
+pub enum ValidationErrorFrb {
+    RateLimited { message: String, retry_after_secs: Option<u64> },
+    Unauthorized { message: String, status: u16 },
+    Http { message: String, status: u16 },
+    Transport { message: String },
+    Timeout { message: String },
+    ModelOutputMalformed { message: String, raw: String },
+    SchemaViolation { message: String, raw: String },
+    Blocked { message: String, reason: String },
+    Config { message: String },
+}
+
+impl From<ValidationError> for ValidationErrorFrb {
+    fn from(err: ValidationError) -> Self {
+        let message = err.to_string();
+        match err {
+            ValidationError::RateLimited { retry_after, .. } => ValidationErrorFrb::RateLimited {
+                message,
+                retry_after_secs: retry_after.map(|d| d.as_secs()),
+            },
+            ValidationError::Unauthorized { status, .. } => ValidationErrorFrb::Unauthorized { message, status },
+            ValidationError::Http { status, .. } => ValidationErrorFrb::Http { message, status },
+            ValidationError::Transport(_) => ValidationErrorFrb::Transport { message },
+            ValidationError::Timeout(_) => ValidationErrorFrb::Timeout { message },
+            ValidationError::ModelOutputMalformed { raw, .. } => ValidationErrorFrb::ModelOutputMalformed { message, raw },
+            ValidationError::SchemaViolation { raw, .. } => ValidationErrorFrb::SchemaViolation { message, raw },
+            ValidationError::Blocked { reason } => ValidationErrorFrb::Blocked { message, reason },
+            ValidationError::Config(_) => ValidationErrorFrb::Config { message },
+        }
+    }
+}
@@ -1,29 +1,89 @@
 // src/bindings/kotlin/uniffi.rs
 
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::core::label_resolver::{self, LabelResolution};
 use crate::core::{
-   validate_input_with_llm_sync
+    BatchInput, CacheStats, Classification, FormInput, FormReport, Locale, OpenAiCompatibleProvider, Progress, Relationship, RelationshipKind,
+    SemanticValidator as CoreValidator, TypeRules, ValidationError,
 };
 
-use crate::config::ApiConfig;
-
 use crate::models::{
+    InputType,
+    InputTypeInfo,
     SupportedModel,
+    ValidationResponse,
 };
 // 1. Definisikan ulang Enum/Struct agar UniFFI bisa membacanya
 // (Kita melakukan wrapping agar tidak mengganggu core_logic yang dipakai WASM)
 #[derive(uniffi::Object)] // Menandakan ini adalah Object/Class
 pub struct SemanticValidator {
-    config: ApiConfig, // Kita simpan config di sini
+    inner: Arc<CoreValidator>, // Validator inti (config + HTTP client bersama)
 }
 #[uniffi::export]
 impl SemanticValidator {
     
     // Constructor (Dipanggil sekali saat inisialisasi)
     #[uniffi::constructor]
-    pub fn new(api_key: String) -> Self {
-        Self {
-            config: ApiConfig { api_key },
+    pub fn new(api_key: String) -> Result<Self, AppError> {
+        let inner = CoreValidator::builder()
+            .api_key(api_key)
+            .build()
+            .map_err(AppError::from)?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    // Constructor dengan opsi tambahan (model bawaan, timeout, base URL, bahasa pesan "id"/"en",
+    // dan path file aturan tipe input .toml/.yaml)
+    #[uniffi::constructor]
+    pub fn with_options(
+        api_key: String,
+        default_model: ModelSelector,
+        timeout_secs: Option<u64>,
+        base_url: Option<String>,
+        locale: Option<String>,
+        rules_file: Option<String>,
+    ) -> Result<Self, AppError> {
+        let mut builder = CoreValidator::builder()
+            .api_key(api_key)
+            .default_model(default_model.into());
+        if let Some(code) = locale {
+            builder = builder.locale(code.parse::<Locale>().map_err(AppError::from)?);
+        }
+        if let Some(path) = rules_file {
+            builder = builder.type_rules(Arc::new(TypeRules::from_path(path).map_err(AppError::from)?));
+        }
+        if let Some(secs) = timeout_secs {
+            builder = builder.timeout(Duration::from_secs(secs));
+        }
+        if let Some(url) = base_url {
+            builder = builder.base_url(url);
+        }
+        let inner = builder
+            .build()
+            .map_err(AppError::from)?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    // Constructor untuk server self-hosted kompatibel OpenAI (Ollama, vLLM, llama.cpp)
+    #[uniffi::constructor]
+    pub fn with_openai_compatible(
+        base_url: String,
+        model: String,
+        api_key: Option<String>,
+        json_mode: bool,
+    ) -> Result<Self, AppError> {
+        let provider = OpenAiCompatibleProvider::new(base_url, model).json_mode(json_mode);
+        let mut builder = CoreValidator::builder().provider(Arc::new(provider));
+        if let Some(key) = api_key {
+            builder = builder.api_key(key);
         }
+        let inner = builder
+            .build()
+            .map_err(AppError::from)?;
+        Ok(Self { inner: Arc::new(inner) })
     }
 
     // Fungsi Validasi (TIDAK PERLU parameter api_key lagi!)
@@ -36,18 +96,131 @@ impl SemanticValidator {
         
         let model_core: SupportedModel = model.into();
 
-        // Kita pakai config yang disimpan di memory (self.config)
-        let result = validate_input_with_llm_sync(
-            &text, 
-            model_core.as_str(), 
-            &label, 
-            &self.config // <--- Ambil dari memory struct
-        ).map_err(|e| AppError::Generic { msg: e.to_string() })?;
+        // Kita pakai validator yang disimpan di memory (self.inner)
+        let result = self.inner
+            .validate_with_model_sync(&text, model_core, &label)
+            .map_err(AppError::from)?;
+
+        Ok(result.into())
+    }
+
+    // Validasi banyak field dalam satu request; hasil dikunci dengan field_id
+    pub fn validate_batch(
+        &self,
+        items: Vec<BatchItem>,
+        model: ModelSelector,
+    ) -> Result<HashMap<String, ResponseData>, AppError> {
+        let items: Vec<BatchInput> = items
+            .into_iter()
+            .map(|item| BatchInput::new(item.field_id, item.value, item.input_type))
+            .collect();
+
+        let results = self.inner
+            .validate_batch_with_model_sync(&items, model.into())
+            .map_err(AppError::from)?;
+
+        Ok(results
+            .into_iter()
+            .map(|(field, response)| (field, response.into()))
+            .collect())
+    }
+
+    // Validasi paralel, satu request per item; hasil sesuai urutan input.
+    // `listener` (opsional) menerima progres setiap kali satu item selesai.
+    pub fn validate_many(
+        &self,
+        items: Vec<BatchItem>,
+        model: ModelSelector,
+        concurrency: u32,
+        listener: Option<Arc<dyn ProgressListener>>,
+    ) -> Vec<ItemOutcome> {
+        let items: Vec<BatchInput> = items
+            .into_iter()
+            .map(|item| BatchInput::new(item.field_id, item.value, item.input_type))
+            .collect();
+
+        let results = self.inner.validate_many_sync(&items, model.into(), concurrency as usize, |progress| {
+            if let Some(listener) = &listener {
+                listener.on_progress(progress.into());
+            }
+        });
+
+        items
+            .into_iter()
+            .zip(results)
+            .map(|(item, result)| match result {
+                Ok(response) => ItemOutcome {
+                    field_id: item.field,
+                    response: Some(response.into()),
+                    error: None,
+                },
+                Err(err) => ItemOutcome {
+                    field_id: item.field,
+                    response: None,
+                    error: Some(AppError::from(err)),
+                },
+            })
+            .collect()
+    }
+
+    // Pemeriksaan konsistensi antar-field (misal kota vs kode pos, tanggal lahir vs umur)
+    pub fn check_form(
+        &self,
+        fields: Vec<FormFieldData>,
+        relationships: Vec<RelationshipData>,
+        model: ModelSelector,
+    ) -> Result<FormReportData, AppError> {
+        let mut form = FormInput::new();
+        for field in fields {
+            form = form.field(field.name, field.value, field.input_type);
+        }
+        for relationship in relationships {
+            let kind = RelationshipKind::from_name(&relationship.kind).ok_or_else(|| AppError::Config {
+                msg: format!("Jenis hubungan tidak dikenal: {}", relationship.kind),
+            })?;
+            form = form.relationship(Relationship {
+                kind,
+                fields: relationship.fields,
+                description: relationship.description,
+            });
+        }
+
+        let report = self.inner
+            .check_form_with_model_sync(&form, model.into())
+            .map_err(AppError::from)?;
+        Ok(report.into())
+    }
+
+    // Menebak tipe sebuah nilai; `label` opsional untuk menandai nilai yang salah tempat
+    pub fn classify_input(
+        &self,
+        value: String,
+        label: Option<String>,
+        model: ModelSelector,
+    ) -> Result<ClassificationData, AppError> {
+        let classification = self.inner
+            .classify_input_with_model_sync(&value, label.as_deref(), model.into())
+            .map_err(AppError::from)?;
+        Ok(classification.into())
+    }
+
+    // Batas requests-per-minute per model (0 = tanpa batas)
+    pub fn set_rate_limit(&self, model: ModelSelector, requests_per_minute: u32) {
+        self.inner.set_rate_limit(model.into(), requests_per_minute);
+    }
+
+    // Mengaktifkan cache hasil (capacity 0 = nonaktif); ttl_secs None = tanpa kedaluwarsa
+    pub fn configure_cache(&self, capacity: u64, ttl_secs: Option<u64>) {
+        self.inner
+            .configure_cache(capacity as usize, ttl_secs.map(Duration::from_secs));
+    }
+
+    pub fn cache_stats(&self) -> CacheStatsData {
+        self.inner.cache_stats().into()
+    }
 
-        Ok(ResponseData {
-            valid: result.valid,
-            message: result.message,
-        })
+    pub fn clear_cache(&self) {
+        self.inner.clear_cache();
     }
 }
 #[derive(uniffi::Enum)]
@@ -74,12 +247,289 @@ impl From<ModelSelector> for SupportedModel {
 pub struct ResponseData {
     pub valid: bool,
     pub message: String,
+    /// Model yang menghasilkan putusan (kosong jika ditolak validasi sintaksis).
+    pub model: Option<String>,
+    /// Tahap yang memutuskan: "syntax", "cache", atau "llm".
+    pub stage: String,
+    /// Kode alasan stabil, mis. "valid", "syntax_invalid", "placeholder".
+    pub code: String,
+    pub confidence: Option<f64>,
+    pub suggestion: Option<String>,
+    pub latency_ms: u64,
+}
+
+impl From<ValidationResponse> for ResponseData {
+    fn from(response: ValidationResponse) -> Self {
+        ResponseData {
+            valid: response.valid,
+            message: response.message,
+            model: response.model,
+            stage: response.stage.as_str().to_string(),
+            code: response.code.as_str().to_string(),
+            confidence: response.confidence,
+            suggestion: response.suggestion,
+            latency_ms: response.latency_ms,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct BatchItem {
+    pub field_id: String,
+    pub value: String,
+    pub input_type: String,
+}
+
+// Hasil satu item dari `validate_many`: tepat salah satu dari `response` atau `error` terisi.
+#[derive(uniffi::Record)]
+pub struct ItemOutcome {
+    pub field_id: String,
+    pub response: Option<ResponseData>,
+    pub error: Option<AppError>,
+}
+
+#[derive(uniffi::Record)]
+pub struct ProgressData {
+    pub completed: u64,
+    pub total: u64,
+    pub failed: u64,
+}
+
+impl From<Progress> for ProgressData {
+    fn from(progress: Progress) -> Self {
+        ProgressData {
+            completed: progress.completed as u64,
+            total: progress.total as u64,
+            failed: progress.failed as u64,
+        }
+    }
+}
+
+// Diimplementasikan di sisi Kotlin untuk menerima progres `validate_many`.
+#[uniffi::export(with_foreign)]
+pub trait ProgressListener: Send + Sync {
+    fn on_progress(&self, progress: ProgressData);
+}
+
+#[derive(uniffi::Record)]
+pub struct FormFieldData {
+    pub name: String,
+    pub value: String,
+    pub input_type: String,
+}
+
+// `kind`: "birth_date_age", "city_postal_code", "email_domain_company", atau "custom"
+#[derive(uniffi::Record)]
+pub struct RelationshipData {
+    pub kind: String,
+    pub fields: Vec<String>,
+    pub description: Option<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct FindingData {
+    pub fields: Vec<String>,
+    pub relationship: Option<String>,
+    pub message: String,
+    /// "local" atau "llm"
+    pub source: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct FormReportData {
+    pub consistent: bool,
+    pub findings: Vec<FindingData>,
+    pub field_findings: HashMap<String, Vec<String>>,
+    pub model: Option<String>,
+}
+
+impl From<FormReport> for FormReportData {
+    fn from(report: FormReport) -> Self {
+        FormReportData {
+            consistent: report.consistent,
+            findings: report
+                .findings
+                .into_iter()
+                .map(|finding| FindingData {
+                    fields: finding.fields,
+                    relationship: finding.relationship.map(|kind| kind.as_str().to_string()),
+                    message: finding.message,
+                    source: finding.source.as_str().to_string(),
+                })
+                .collect(),
+            field_findings: report.field_findings.into_iter().collect(),
+            model: report.model,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct TypeCandidateData {
+    /// Id tipe kanonis, misalnya "email" atau "person_name"
+    pub input_type: String,
+    pub score: f64,
+    /// "local" atau "llm"
+    pub source: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct LabelMismatchData {
+    pub label: String,
+    pub expected: String,
+    pub detected: String,
+    pub message: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct ClassificationData {
+    pub candidates: Vec<TypeCandidateData>,
+    pub label_mismatch: Option<LabelMismatchData>,
+    pub model: Option<String>,
+}
+
+impl From<Classification> for ClassificationData {
+    fn from(classification: Classification) -> Self {
+        ClassificationData {
+            candidates: classification
+                .candidates
+                .into_iter()
+                .map(|candidate| TypeCandidateData {
+                    input_type: candidate.input_type.as_str().to_string(),
+                    score: candidate.score,
+                    source: candidate.source.as_str().to_string(),
+                })
+                .collect(),
+            label_mismatch: classification.label_mismatch.map(|mismatch| LabelMismatchData {
+                label: mismatch.label,
+                expected: mismatch.expected.as_str().to_string(),
+                detected: mismatch.detected.as_str().to_string(),
+                message: mismatch.message,
+            }),
+            model: classification.model,
+        }
+    }
+}
+
+/// Tipe input bawaan untuk dropdown UI; `id` stabil, `label` dapat dikirim ke validator.
+#[derive(uniffi::Record)]
+pub struct InputTypeData {
+    pub id: String,
+    pub label: String,
+    pub description: String,
+    pub aliases: Vec<String>,
+}
+
+impl From<InputTypeInfo> for InputTypeData {
+    fn from(info: InputTypeInfo) -> Self {
+        InputTypeData {
+            id: info.id,
+            label: info.label,
+            description: info.description,
+            aliases: info.aliases,
+        }
+    }
+}
+
+// Daftar semua tipe input bawaan
+#[uniffi::export]
+pub fn list_input_types() -> Vec<InputTypeData> {
+    InputType::ALL.iter().map(|input_type| input_type.info().into()).collect()
+}
+
+// Tipe kanonis untuk sebuah label, atau null jika label tidak dikenal
+#[uniffi::export]
+pub fn resolve_input_type(label: String) -> Option<InputTypeData> {
+    InputType::resolve(&label).map(|input_type| input_type.info().into())
+}
+
+/// Hasil pemetaan label formulir: id tipe kanonis, confidence 0-1, dan metode pencocokan.
+#[derive(uniffi::Record)]
+pub struct LabelResolutionData {
+    pub input_type: String,
+    pub confidence: f64,
+    pub method: String,
+    pub matched: String,
+}
+
+impl From<LabelResolution> for LabelResolutionData {
+    fn from(resolution: LabelResolution) -> Self {
+        LabelResolutionData {
+            input_type: resolution.input_type.as_str().to_string(),
+            confidence: resolution.confidence,
+            method: resolution.method.as_str().to_string(),
+            matched: resolution.matched,
+        }
+    }
+}
+
+// Pemetaan longgar untuk label formulir ("No. WA", "bday", "nama_lengkap"), atau null
+#[uniffi::export]
+pub fn resolve_label(label: String) -> Option<LabelResolutionData> {
+    label_resolver::resolve_label(&label).map(Into::into)
+}
+
+#[derive(uniffi::Record)]
+pub struct CacheStatsData {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: u64,
+    pub capacity: u64,
+}
+
+impl From<CacheStats> for CacheStatsData {
+    fn from(stats: CacheStats) -> Self {
+        CacheStatsData {
+            hits: stats.hits,
+            misses: stats.misses,
+            entries: stats.entries as u64,
+            capacity: stats.capacity as u64,
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum AppError {
     #[error("{msg}")] // <--- INI PENTING! Ini format pesan errornya nanti
     Generic { msg: String },
+    #[error("{msg}")]
+    RateLimited { msg: String, retry_after_secs: Option<u64> },
+    #[error("{msg}")]
+    Unauthorized { msg: String, status: u16 },
+    #[error("{msg}")]
+    Http { msg: String, status: u16 },
+    #[error("{msg}")]
+    Transport { msg: String },
+    #[error("{msg}")]
+    Timeout { msg: String },
+    #[error("{msg}")]
+    ModelOutputMalformed { msg: String, raw: String },
+    #[error("{msg}")]
+    SchemaViolation { msg: String, raw: String },
+    #[error("{msg}")]
+    Blocked { msg: String, reason: String },
+    #[error("{msg}")]
+    Config { msg: String },
+}
+
+// Setiap varian ValidationError punya pasangan sendiri di Kotlin/Swift
+impl From<ValidationError> for AppError {
+    fn from(err: ValidationError) -> Self {
+        let msg = err.to_string();
+        match err {
+            ValidationError::RateLimited { retry_after, .. } => AppError::RateLimited {
+                msg,
+                retry_after_secs: retry_after.map(|d| d.as_secs()),
+            },
+            ValidationError::Unauthorized { status, .. } => AppError::Unauthorized { msg, status },
+            ValidationError::Http { status, .. } => AppError::Http { msg, status },
+            ValidationError::Transport(_) => AppError::Transport { msg },
+            ValidationError::Timeout(_) => AppError::Timeout { msg },
+            ValidationError::ModelOutputMalformed { raw, .. } => AppError::ModelOutputMalformed { msg, raw },
+            ValidationError::SchemaViolation { raw, .. } => AppError::SchemaViolation { msg, raw },
+            ValidationError::Blocked { reason } => AppError::Blocked { msg, reason },
+            ValidationError::Config(_) => AppError::Config { msg },
+        }
+    }
 }
 // 2. Fungsi Export yang akan dipanggil Kotlin
 // #[uniffi::export]
@@ -1,15 +1,65 @@
 #![cfg(feature = "python_bindings_feature")]
 
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::core::cache::DEFAULT_CACHE_CAPACITY;
+use crate::core::label_resolver::{self, LabelResolution};
 use crate::core::{
-    validate_input_with_llm_sync,
+    ApiKeyPool, KeySelection, BatchInput, BatchResponse, CacheStats, FormInput, ItemResult, Locale, Progress, DEFAULT_CONCURRENCY, FileCache, OpenAiCompatibleProvider, RetryPolicy, SemanticValidator, TypeRules, ValidationError, DEFAULT_CACHE,
+    configure_default_api_key, default_validator, reset_default_validator,
 };
 
-use crate::config::API_CONFIG;
-
+use crate::models::{InputType, InputTypeInfo, ValidationResponse};
 
 use crate::models::SupportedModel as RustSupportedModel;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
+
+// ----------------------------
+// Exception Python (satu kelas per varian ValidationError)
+// ----------------------------
+create_exception!(validation_semantic, ValidationSemanticError, PyException, "Base class semua error validation_semantic.");
+create_exception!(validation_semantic, RateLimitedError, ValidationSemanticError, "Kuota/rate limit model tercapai (HTTP 429).");
+create_exception!(validation_semantic, UnauthorizedError, ValidationSemanticError, "API key ditolak server.");
+create_exception!(validation_semantic, HttpError, ValidationSemanticError, "Server merespons dengan status HTTP gagal.");
+create_exception!(validation_semantic, TransportError, ValidationSemanticError, "Gagal menghubungi server LLM.");
+create_exception!(validation_semantic, RequestTimeoutError, ValidationSemanticError, "Request ke server LLM melewati batas waktu.");
+create_exception!(validation_semantic, ModelOutputError, ValidationSemanticError, "Keluaran model tidak dapat di-parse.");
+create_exception!(validation_semantic, SchemaViolationError, ModelOutputError, "Keluaran model melanggar skema respons.");
+create_exception!(validation_semantic, BlockedError, ValidationSemanticError, "Respons diblokir filter keamanan.");
+create_exception!(validation_semantic, ConfigError, ValidationSemanticError, "Konfigurasi tidak valid.");
+
+// Mengubah ValidationError menjadi exception Python yang sesuai, lengkap dengan atribut `code`.
+fn to_py_err(py: Python, err: ValidationError) -> PyErr {
+    let message = err.to_string();
+    let py_err = match &err {
+        ValidationError::RateLimited { .. } => RateLimitedError::new_err(message),
+        ValidationError::Unauthorized { .. } => UnauthorizedError::new_err(message),
+        ValidationError::Http { .. } => HttpError::new_err(message),
+        ValidationError::Transport(_) => TransportError::new_err(message),
+        ValidationError::Timeout(_) => RequestTimeoutError::new_err(message),
+        ValidationError::ModelOutputMalformed { .. } => ModelOutputError::new_err(message),
+        ValidationError::SchemaViolation { .. } => SchemaViolationError::new_err(message),
+        ValidationError::Blocked { .. } => BlockedError::new_err(message),
+        ValidationError::Config(_) => ConfigError::new_err(message),
+    };
+
+    let value = py_err.value(py);
+    let _ = value.setattr("code", err.code());
+    let _ = value.setattr("status", err.status());
+    let _ = value.setattr("retry_after", err.retry_after().map(|d| d.as_secs_f64()));
+    if let ValidationError::ModelOutputMalformed { raw, .. } | ValidationError::SchemaViolation { raw, .. } = &err {
+        let _ = value.setattr("raw", raw.as_str());
+    }
+    if let ValidationError::Blocked { reason } = &err {
+        let _ = value.setattr("reason", reason.as_str());
+    }
+    py_err
+}
 
 // ----------------------------
 // PyClass: SupportedModel
@@ -42,6 +92,296 @@ impl PySupportedModel {
     }
 }
 
+// ----------------------------
+// PyClass: FileCache
+// ----------------------------
+/// Cache putusan persisten (file JSONL) yang bertahan antar run dan aman dibagi antar proses.
+#[pyclass(name = "FileCache")]
+pub struct PyFileCache {
+    inner: Arc<FileCache>,
+}
+
+#[pymethods]
+impl PyFileCache {
+    #[new]
+    #[pyo3(signature = (path, ttl_secs=None))]
+    fn new(path: PathBuf, ttl_secs: Option<u64>) -> PyResult<Self> {
+        let inner = FileCache::open(path, ttl_secs.map(Duration::from_secs))?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.inner.path().to_path_buf()
+    }
+
+    fn stats(&self, py: Python) -> PyResult<PyObject> {
+        stats_to_dict(py, self.inner.stats())
+    }
+
+    /// Membuang entri kedaluwarsa dan entri lama yang tertimpa; mengembalikan jumlah entri tersisa.
+    fn compact(&self, py: Python) -> PyResult<usize> {
+        Ok(py.allow_threads(|| self.inner.compact())?)
+    }
+
+    fn clear(&self) -> PyResult<()> {
+        Ok(self.inner.clear()?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<FileCache path='{}'>", self.inner.path().display())
+    }
+}
+
+// ----------------------------
+// PyClass: SemanticValidator
+// ----------------------------
+#[pyclass(name = "SemanticValidator")]
+pub struct PySemanticValidator {
+    inner: Arc<SemanticValidator>,
+}
+
+#[pymethods]
+impl PySemanticValidator {
+    #[new]
+    #[pyo3(signature = (api_key, default_model=None, timeout_secs=None, base_url=None, max_attempts=None, fallback_models=None, file_cache=None, locale=None, rules_file=None, extra_api_keys=None, key_selection=None, key_cooldown_secs=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        py: Python,
+        api_key: String,
+        default_model: Option<PySupportedModel>,
+        timeout_secs: Option<u64>,
+        base_url: Option<String>,
+        max_attempts: Option<u32>,
+        fallback_models: Option<Vec<PySupportedModel>>,
+        file_cache: Option<PyRef<PyFileCache>>,
+        locale: Option<String>,
+        rules_file: Option<String>,
+        extra_api_keys: Option<Vec<String>>,
+        key_selection: Option<String>,
+        key_cooldown_secs: Option<u64>,
+    ) -> PyResult<Self> {
+        let mut builder = SemanticValidator::builder();
+        if extra_api_keys.is_some() || key_selection.is_some() || key_cooldown_secs.is_some() {
+            let keys = std::iter::once(api_key).chain(extra_api_keys.unwrap_or_default());
+            let mut pool = ApiKeyPool::new(keys).map_err(|e| to_py_err(py, e))?;
+            if let Some(name) = key_selection {
+                pool = pool.selection(name.parse::<KeySelection>().map_err(|e| to_py_err(py, e))?);
+            }
+            if let Some(secs) = key_cooldown_secs {
+                pool = pool.cooldown(Duration::from_secs(secs));
+            }
+            builder = builder.api_key_pool(Arc::new(pool));
+        } else {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(code) = locale {
+            builder = builder.locale(code.parse::<Locale>().map_err(|e| to_py_err(py, e))?);
+        }
+        if let Some(path) = rules_file {
+            let rules = TypeRules::from_path(path).map_err(|e| to_py_err(py, e))?;
+            builder = builder.type_rules(Arc::new(rules));
+        }
+        if let Some(model) = default_model {
+            builder = builder.default_model(model.variant);
+        }
+        if let Some(secs) = timeout_secs {
+            builder = builder.timeout(Duration::from_secs(secs));
+        }
+        if let Some(url) = base_url {
+            builder = builder.base_url(url);
+        }
+        if let Some(attempts) = max_attempts {
+            builder = builder.retry_policy(RetryPolicy::default().max_attempts(attempts));
+        }
+        if let Some(models) = fallback_models {
+            builder = builder.fallback_models(models.into_iter().map(|m| m.variant));
+        }
+        if let Some(file_cache) = file_cache {
+            builder = builder.file_cache(Arc::clone(&file_cache.inner));
+        }
+        let inner = builder.build().map_err(|e| to_py_err(py, e))?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    /// Validator yang memakai server `/v1/chat/completions` kompatibel OpenAI (Ollama, vLLM, llama.cpp).
+    #[staticmethod]
+    #[pyo3(signature = (base_url, model, api_key=None, json_mode=true, timeout_secs=None, max_attempts=None, file_cache=None, locale=None, rules_file=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn openai_compatible(
+        py: Python,
+        base_url: String,
+        model: String,
+        api_key: Option<String>,
+        json_mode: bool,
+        timeout_secs: Option<u64>,
+        max_attempts: Option<u32>,
+        file_cache: Option<PyRef<PyFileCache>>,
+        locale: Option<String>,
+        rules_file: Option<String>,
+    ) -> PyResult<Self> {
+        let provider = OpenAiCompatibleProvider::new(base_url, model).json_mode(json_mode);
+        let mut builder = SemanticValidator::builder().provider(Arc::new(provider));
+        if let Some(code) = locale {
+            builder = builder.locale(code.parse::<Locale>().map_err(|e| to_py_err(py, e))?);
+        }
+        if let Some(path) = rules_file {
+            let rules = TypeRules::from_path(path).map_err(|e| to_py_err(py, e))?;
+            builder = builder.type_rules(Arc::new(rules));
+        }
+        if let Some(key) = api_key {
+            builder = builder.api_key(key);
+        }
+        if let Some(secs) = timeout_secs {
+            builder = builder.timeout(Duration::from_secs(secs));
+        }
+        if let Some(attempts) = max_attempts {
+            builder = builder.retry_policy(RetryPolicy::default().max_attempts(attempts));
+        }
+        if let Some(file_cache) = file_cache {
+            builder = builder.file_cache(Arc::clone(&file_cache.inner));
+        }
+        let inner = builder.build().map_err(|e| to_py_err(py, e))?;
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    /// Kode bahasa pesan validator ini ("id" atau "en").
+    #[getter]
+    fn locale(&self) -> &'static str {
+        self.inner.locale().as_str()
+    }
+
+    #[pyo3(signature = (text, label, model=None))]
+    fn validate(
+        &self,
+        py: Python,
+        text: String,
+        label: String,
+        model: Option<PySupportedModel>,
+    ) -> PyResult<PyObject> {
+        let model = model.map(|m| m.variant).unwrap_or(self.inner.default_model());
+        run_validation(py, &self.inner, &text, model, &label)
+    }
+
+    /// Validasi banyak field sekaligus. `items` berisi tuple (field_id, value, input_type);
+    /// hasilnya dict {field_id: {"valid", "message", "model"}}.
+    #[pyo3(signature = (items, model=None))]
+    fn validate_batch(
+        &self,
+        py: Python,
+        items: Vec<(String, String, String)>,
+        model: Option<PySupportedModel>,
+    ) -> PyResult<PyObject> {
+        let model = model.map(|m| m.variant).unwrap_or(self.inner.default_model());
+        run_batch(py, &self.inner, items, model)
+    }
+
+    /// Validasi paralel untuk banyak item, satu request per item. Hasilnya list sesuai
+    /// urutan input berisi dict {"field", "result", "error"}. `progress` (opsional)
+    /// dipanggil dengan dict {"completed", "total", "failed"} setiap item selesai.
+    #[pyo3(signature = (items, model=None, concurrency=DEFAULT_CONCURRENCY, progress=None))]
+    fn validate_many(
+        &self,
+        py: Python,
+        items: Vec<(String, String, String)>,
+        model: Option<PySupportedModel>,
+        concurrency: usize,
+        progress: Option<PyObject>,
+    ) -> PyResult<PyObject> {
+        let model = model.map(|m| m.variant).unwrap_or(self.inner.default_model());
+        let items: Vec<BatchInput> = items
+            .into_iter()
+            .map(|(field, value, input_type)| BatchInput::new(field, value, input_type))
+            .collect();
+
+        // GIL dilepas selama validasi dan hanya diambil kembali untuk memanggil callback.
+        let results = py.allow_threads(|| {
+            self.inner.validate_many_sync(&items, model, concurrency, |p| {
+                if let Some(callback) = &progress {
+                    Python::with_gil(|py| {
+                        let outcome = progress_to_dict(py, p).and_then(|dict| callback.call1(py, (dict,)));
+                        if let Err(e) = outcome {
+                            e.print(py);
+                        }
+                    });
+                }
+            })
+        });
+
+        let list = PyList::empty(py);
+        for (item, result) in items.into_iter().zip(results) {
+            list.append(outcome_to_dict(py, item.field, result)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Pemeriksaan konsistensi antar-field. `form` berupa dict
+    /// {"fields": {nama: {"value", "input_type"}}, "relationships": [{"kind", "fields", "description"?}]};
+    /// hasilnya dict {"consistent", "findings", "field_findings", "model"}.
+    #[pyo3(signature = (form, model=None))]
+    fn check_form(&self, py: Python, form: PyObject, model: Option<PySupportedModel>) -> PyResult<PyObject> {
+        let model = model.map(|m| m.variant).unwrap_or(self.inner.default_model());
+        let form: FormInput = from_py_json(py, &form)?;
+        let result = py.allow_threads(|| self.inner.check_form_with_model_sync(&form, model));
+        match result {
+            Ok(report) => to_py_json(py, &report),
+            Err(e) => Err(to_py_err(py, e)),
+        }
+    }
+
+    /// Menebak tipe `value` (detektor lokal dulu, lalu LLM bila ragu). Jika `label` diberikan,
+    /// nilai yang jelas bertipe lain ditandai di `label_mismatch`.
+    /// Hasilnya dict {"candidates": [{"input_type", "score", "source"}], "label_mismatch", "model"}.
+    #[pyo3(signature = (value, label=None, model=None))]
+    fn classify_input(
+        &self,
+        py: Python,
+        value: &str,
+        label: Option<&str>,
+        model: Option<PySupportedModel>,
+    ) -> PyResult<PyObject> {
+        let model = model.map(|m| m.variant).unwrap_or(self.inner.default_model());
+        let result = py.allow_threads(|| self.inner.classify_input_with_model_sync(value, label, model));
+        match result {
+            Ok(classification) => to_py_json(py, &classification),
+            Err(e) => Err(to_py_err(py, e)),
+        }
+    }
+
+    /// Membatasi request ke `model` maksimal `requests_per_minute` per menit (0 = tanpa batas).
+    fn set_rate_limit(&self, model: PySupportedModel, requests_per_minute: u32) {
+        self.inner.set_rate_limit(model.variant, requests_per_minute);
+    }
+
+    /// Mengaktifkan cache hasil (capacity=0 menonaktifkan). ttl_secs=None berarti tanpa kedaluwarsa.
+    #[pyo3(signature = (capacity=DEFAULT_CACHE_CAPACITY, ttl_secs=None))]
+    fn configure_cache(&self, capacity: usize, ttl_secs: Option<u64>) {
+        self.inner.configure_cache(capacity, ttl_secs.map(Duration::from_secs));
+    }
+
+    fn cache_stats(&self, py: Python) -> PyResult<PyObject> {
+        stats_to_dict(py, self.inner.cache_stats())
+    }
+
+    /// Pemakaian tiap API key: list dict `{index, key_hint, requests, successes,
+    /// rate_limited, rejected, errors, quarantine_remaining_ms, quarantined_models}`.
+    fn key_usage(&self, py: Python) -> PyResult<PyObject> {
+        to_py_json(py, &self.inner.key_usage())
+    }
+
+    fn clear_cache(&self) {
+        self.inner.clear_cache();
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<SemanticValidator provider='{}' default_model={:?}>",
+            self.inner.provider_name(),
+            self.inner.default_model()
+        )
+    }
+}
+
 // ----------------------------
 // Fungsi PyO3
 // ----------------------------
@@ -52,27 +392,194 @@ fn validate_input_py(
     model: &PySupportedModel,
     label: String,
 ) -> PyResult<PyObject> {
-    let config = match &*API_CONFIG {
-        Ok(cfg) => cfg,
-        Err(e) => {
-            return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
-                format!("ApiConfig init error: {}", e),
-            ))
-        }
-    };
+    let validator = default_validator().map_err(|e| to_py_err(py, e))?;
+
+    run_validation(py, &validator, &text, model.variant, &label)
+}
+
+#[pyfunction]
+fn validate_batch_py(
+    py: Python,
+    items: Vec<(String, String, String)>,
+    model: &PySupportedModel,
+) -> PyResult<PyObject> {
+    let validator = default_validator().map_err(|e| to_py_err(py, e))?;
+
+    run_batch(py, &validator, items, model.variant)
+}
+
+/// Mengatur atau merotasi API key untuk `validate_input_py`/`validate_batch_py`
+/// tanpa me-restart interpreter.
+#[pyfunction(name = "configure")]
+#[pyo3(signature = (api_key))]
+fn configure_default(py: Python, api_key: String) -> PyResult<()> {
+    configure_default_api_key(&api_key).map_err(|e| to_py_err(py, e.redacted(&api_key)))
+}
+
+/// Melupakan API key yang diatur lewat `configure`; panggilan berikutnya
+/// membaca ulang variabel lingkungan.
+#[pyfunction(name = "reset_configuration")]
+fn reset_default_configuration() {
+    reset_default_validator();
+}
+
+/// Mengatur cache hasil milik `validate_input_py`.
+#[pyfunction(name = "configure_cache")]
+#[pyo3(signature = (capacity=DEFAULT_CACHE_CAPACITY, ttl_secs=None))]
+fn configure_default_cache(capacity: usize, ttl_secs: Option<u64>) {
+    DEFAULT_CACHE.configure(capacity, ttl_secs.map(Duration::from_secs));
+}
+
+#[pyfunction(name = "cache_stats")]
+fn default_cache_stats(py: Python) -> PyResult<PyObject> {
+    stats_to_dict(py, DEFAULT_CACHE.stats())
+}
+
+#[pyfunction(name = "clear_cache")]
+fn clear_default_cache() {
+    DEFAULT_CACHE.clear();
+}
+
+/// Daftar tipe input bawaan: list dict `{id, label, description, aliases}`.
+#[pyfunction]
+fn list_input_types(py: Python) -> PyResult<PyObject> {
+    let list = PyList::empty(py);
+    for input_type in InputType::ALL {
+        list.append(input_type_to_dict(py, input_type.info())?)?;
+    }
+    Ok(list.into())
+}
+
+/// Tipe kanonis untuk sebuah label, atau `None` jika label tidak dikenal.
+#[pyfunction]
+fn resolve_input_type(py: Python, label: &str) -> PyResult<Option<PyObject>> {
+    InputType::resolve(label)
+        .map(|input_type| input_type_to_dict(py, input_type.info()))
+        .transpose()
+}
+
+/// Pemetaan longgar untuk label formulir ("No. WA", "bday", "nama_lengkap"):
+/// dict `{input_type, confidence, method, matched}`, atau `None` jika tidak ada yang cocok.
+#[pyfunction]
+fn resolve_label(py: Python, label: &str) -> PyResult<Option<PyObject>> {
+    label_resolver::resolve_label(label)
+        .map(|resolution| resolution_to_dict(py, resolution))
+        .transpose()
+}
+
+// Menjalankan validasi sinkron tanpa menahan GIL, lalu mengubah hasilnya menjadi dict.
+fn run_validation(
+    py: Python,
+    validator: &SemanticValidator,
+    text: &str,
+    model: RustSupportedModel,
+    label: &str,
+) -> PyResult<PyObject> {
+    let result = py.allow_threads(|| validator.validate_with_model_sync(text, model, label));
+    match result {
+        Ok(validation_response) => response_to_dict(py, validation_response),
+        Err(e) => Err(to_py_err(py, e)),
+    }
+}
+
+fn run_batch(
+    py: Python,
+    validator: &SemanticValidator,
+    items: Vec<(String, String, String)>,
+    model: RustSupportedModel,
+) -> PyResult<PyObject> {
+    let items: Vec<BatchInput> = items
+        .into_iter()
+        .map(|(field, value, input_type)| BatchInput::new(field, value, input_type))
+        .collect();
+    let result = py.allow_threads(|| validator.validate_batch_with_model_sync(&items, model));
+    match result {
+        Ok(responses) => batch_to_dict(py, responses),
+        Err(e) => Err(to_py_err(py, e)),
+    }
+}
+
+fn response_to_dict(py: Python, validation_response: ValidationResponse) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("valid", validation_response.valid)?;
+    dict.set_item("message", validation_response.message)?;
+    dict.set_item("model", validation_response.model)?;
+    dict.set_item("stage", validation_response.stage.as_str())?;
+    dict.set_item("code", validation_response.code.as_str())?;
+    dict.set_item("confidence", validation_response.confidence)?;
+    dict.set_item("suggestion", validation_response.suggestion)?;
+    dict.set_item("latency_ms", validation_response.latency_ms)?;
+    Ok(dict.into())
+}
 
-    let model_name = model.variant.as_str();
-    match validate_input_with_llm_sync(&text, model_name, &label, config) {
+fn batch_to_dict(py: Python, responses: BatchResponse) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    for (field, validation_response) in responses {
+        dict.set_item(field, response_to_dict(py, validation_response)?)?;
+    }
+    Ok(dict.into())
+}
+
+fn outcome_to_dict(py: Python, field: String, result: ItemResult) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("field", field)?;
+    match result {
         Ok(validation_response) => {
-            let dict = PyDict::new(py);
-            dict.set_item("valid", validation_response.valid)?;
-            dict.set_item("message", validation_response.message)?;
-            Ok(dict.into())
+            dict.set_item("result", response_to_dict(py, validation_response)?)?;
+            dict.set_item("error", py.None())?;
+        }
+        Err(e) => {
+            dict.set_item("result", py.None())?;
+            dict.set_item("error", to_py_err(py, e).into_value(py))?;
         }
-        Err(e) => Err(PyErr::new::<pyo3::exceptions::PyException, _>(
-            format!("Validation error with model '{}': {}", model_name, e),
-        )),
     }
+    Ok(dict.into())
+}
+
+fn progress_to_dict(py: Python, progress: Progress) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("completed", progress.completed)?;
+    dict.set_item("total", progress.total)?;
+    dict.set_item("failed", progress.failed)?;
+    Ok(dict.into())
+}
+
+// Konversi struktur bersarang lewat modul `json` Python agar bentuknya sama dengan serde.
+fn from_py_json<T: serde::de::DeserializeOwned>(py: Python, value: &PyObject) -> PyResult<T> {
+    let text: String = py.import("json")?.call_method1("dumps", (value,))?.extract()?;
+    serde_json::from_str(&text).map_err(|e| ConfigError::new_err(format!("Argumen tidak valid: {}", e)))
+}
+
+fn to_py_json<T: serde::Serialize>(py: Python, value: &T) -> PyResult<PyObject> {
+    let text = serde_json::to_string(value).map_err(|e| PyException::new_err(e.to_string()))?;
+    Ok(py.import("json")?.call_method1("loads", (text,))?.unbind())
+}
+
+fn input_type_to_dict(py: Python, info: InputTypeInfo) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("id", info.id)?;
+    dict.set_item("label", info.label)?;
+    dict.set_item("description", info.description)?;
+    dict.set_item("aliases", info.aliases)?;
+    Ok(dict.into())
+}
+
+fn resolution_to_dict(py: Python, resolution: LabelResolution) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("input_type", resolution.input_type.as_str())?;
+    dict.set_item("confidence", resolution.confidence)?;
+    dict.set_item("method", resolution.method.as_str())?;
+    dict.set_item("matched", resolution.matched)?;
+    Ok(dict.into())
+}
+
+fn stats_to_dict(py: Python, stats: CacheStats) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("hits", stats.hits)?;
+    dict.set_item("misses", stats.misses)?;
+    dict.set_item("entries", stats.entries)?;
+    dict.set_item("capacity", stats.capacity)?;
+    Ok(dict.into())
 }
 
 // ----------------------------
@@ -83,7 +590,30 @@ pub fn register_items_for_python_module(
     parent_module: &Bound<PyModule>,
 ) -> PyResult<()> {
     parent_module.add_wrapped(wrap_pyfunction!(validate_input_py))?;
+    parent_module.add_wrapped(wrap_pyfunction!(validate_batch_py))?;
+    parent_module.add_wrapped(wrap_pyfunction!(configure_default))?;
+    parent_module.add_wrapped(wrap_pyfunction!(reset_default_configuration))?;
+    parent_module.add_wrapped(wrap_pyfunction!(configure_default_cache))?;
+    parent_module.add_wrapped(wrap_pyfunction!(default_cache_stats))?;
+    parent_module.add_wrapped(wrap_pyfunction!(clear_default_cache))?;
+    parent_module.add_wrapped(wrap_pyfunction!(list_input_types))?;
+    parent_module.add_wrapped(wrap_pyfunction!(resolve_input_type))?;
+    parent_module.add_wrapped(wrap_pyfunction!(resolve_label))?;
     parent_module.add_class::<PySupportedModel>()?;
+    parent_module.add_class::<PySemanticValidator>()?;
+    parent_module.add_class::<PyFileCache>()?;
+
+    let py = parent_module.py();
+    parent_module.add("ValidationSemanticError", py.get_type::<ValidationSemanticError>())?;
+    parent_module.add("RateLimitedError", py.get_type::<RateLimitedError>())?;
+    parent_module.add("UnauthorizedError", py.get_type::<UnauthorizedError>())?;
+    parent_module.add("HttpError", py.get_type::<HttpError>())?;
+    parent_module.add("TransportError", py.get_type::<TransportError>())?;
+    parent_module.add("RequestTimeoutError", py.get_type::<RequestTimeoutError>())?;
+    parent_module.add("ModelOutputError", py.get_type::<ModelOutputError>())?;
+    parent_module.add("SchemaViolationError", py.get_type::<SchemaViolationError>())?;
+    parent_module.add("BlockedError", py.get_type::<BlockedError>())?;
+    parent_module.add("ConfigError", py.get_type::<ConfigError>())?;
 
     parent_module.add("GEMINI_FLASH", RustSupportedModel::GeminiFlash as i32)?;
     parent_module.add("GEMINI_FLASH_LITE", RustSupportedModel::GeminiFlashLite as i32)?;
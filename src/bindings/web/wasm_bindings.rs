@@ -1,30 +1,92 @@
 #![cfg(target_arch = "wasm32")]
 use std::cell::RefCell;
+use std::sync::Arc;
 use js_sys;
 use wasm_bindgen::prelude::*; // Pastikan js_sys diimpor
 
 use crate::core::{
-    validate_input_with_llm_async,
+    resolve_label, BatchInput, FormInput, Locale, ResultCache, SemanticValidator, TypeRules, ValidationError,
 };
 
-use crate::models::SupportedModel;
+use crate::models::{list_input_types, InputType, SupportedModel};
 
 thread_local! {
-    static CONFIG: RefCell<ModuleConfig> = RefCell::new(ModuleConfig::default());
+    static VALIDATOR: RefCell<Option<Arc<SemanticValidator>>> = const { RefCell::new(None) };
+    // Cache hasil dibagi antar pemanggilan `configure`, sehingga mengganti API key tidak mengosongkannya.
+    static CACHE: Arc<ResultCache> = Arc::new(ResultCache::disabled());
 }
 
-#[derive(Default)]
-pub struct ModuleConfig {
-    pub api_key: Option<String>,
+/// Menyiapkan validator dengan API key. `locale` opsional ("id" atau "en") mengatur
+/// bahasa penjelasan model dan pesan error lokal. `rules` opsional berisi teks file
+/// aturan tipe input (TOML atau YAML), karena browser tidak bisa membaca file lokal.
+#[wasm_bindgen]
+pub fn configure(api_key: String, locale: Option<String>, rules: Option<String>) -> Result<(), JsValue> {
+    let mut builder = SemanticValidator::builder()
+        .api_key(api_key)
+        .cache(CACHE.with(Arc::clone));
+    if let Some(code) = locale {
+        builder = builder.locale(code.parse::<Locale>().map_err(to_js_error)?);
+    }
+    if let Some(text) = rules {
+        builder = builder.type_rules(Arc::new(TypeRules::parse(&text).map_err(to_js_error)?));
+    }
+    let validator = builder.build().map_err(to_js_error)?;
+    VALIDATOR.with(|v| {
+        *v.borrow_mut() = Some(Arc::new(validator));
+    });
+    Ok(())
 }
 
-#[wasm_bindgen]
-pub fn configure(api_key: String) {
-    CONFIG.with(|c| {
-        c.borrow_mut().api_key = Some(api_key);
+/// Mengaktifkan cache hasil (capacity 0 = nonaktif). `ttlSecs` kosong berarti tanpa kedaluwarsa.
+#[wasm_bindgen(js_name = configureCache)]
+pub fn configure_cache(capacity: u32, ttl_secs: Option<u32>) {
+    CACHE.with(|cache| {
+        cache.configure(
+            capacity as usize,
+            ttl_secs.map(|secs| std::time::Duration::from_secs(secs.into())),
+        )
     });
 }
 
+/// Statistik cache: `{ hits, misses, entries, capacity }`.
+#[wasm_bindgen(js_name = cacheStats)]
+pub fn cache_stats() -> Result<JsValue, JsValue> {
+    let stats = CACHE.with(|cache| cache.stats());
+    serde_wasm_bindgen::to_value(&stats).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[wasm_bindgen(js_name = clearCache)]
+pub fn clear_cache() {
+    CACHE.with(|cache| cache.clear());
+}
+
+/// Daftar tipe input bawaan: `[{ id, label, description, aliases }]`.
+#[wasm_bindgen(js_name = listInputTypes)]
+pub fn list_input_types_js() -> Result<JsValue, JsValue> {
+    serde_wasm_bindgen::to_value(&list_input_types()).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Tipe kanonis untuk sebuah label (`{ id, label, description, aliases }`), atau `null` jika tidak dikenal.
+#[wasm_bindgen(js_name = resolveInputType)]
+pub fn resolve_input_type_js(label: String) -> Result<JsValue, JsValue> {
+    match InputType::resolve(&label) {
+        Some(input_type) => {
+            serde_wasm_bindgen::to_value(&input_type.info()).map_err(|e| JsValue::from_str(&e.to_string()))
+        }
+        None => Ok(JsValue::NULL),
+    }
+}
+
+/// Pemetaan longgar untuk label formulir: `{ input_type, confidence, method, matched }`, atau `null`.
+#[wasm_bindgen(js_name = resolveLabel)]
+pub fn resolve_label_js(label: String) -> Result<JsValue, JsValue> {
+    match resolve_label(&label) {
+        Some(resolution) => {
+            serde_wasm_bindgen::to_value(&resolution).map_err(|e| JsValue::from_str(&e.to_string()))
+        }
+        None => Ok(JsValue::NULL),
+    }
+}
 
 // Panic hook opsional
 #[wasm_bindgen]
@@ -40,7 +102,7 @@ pub async fn validate_text_js(
     label: String,
 ) -> Result<JsValue, JsValue> {
     // Mengembalikan Result<JsValue, JsValue> untuk error handling ke JS
-    let api_key = CONFIG.with(|c| c.borrow().api_key.clone())
+    let validator = VALIDATOR.with(|v| v.borrow().clone())
         .ok_or("API key not configured")?;
     // 1. Mapping angka ke enum model (seperti yang sudah Anda lakukan di React)
     let model_variant = match SupportedModel::from_int(model) {
@@ -54,12 +116,9 @@ pub async fn validate_text_js(
             return Err(JsValue::from_str(&error_message));
         }
     };
-    let model_name = model_variant.as_str();
 
-    // 2. Panggil fungsi inti dari core_logic
-    // Perhatikan path ke validate_input_with_llm_async mungkin perlu disesuaikan
-    // tergantung struktur modul Anda (misalnya, crate::core_logic::...)
-    match validate_input_with_llm_async(&text, model_name, &label, &api_key).await {//ubah biar parameter nya API_KEY gemini
+    // 2. Panggil validator inti (HTTP client dipakai ulang antar pemanggilan)
+    match validator.validate_with_model_async(&text, model_variant, &label).await {
         Ok(validation_response_rust) => {
             // 3. Serialisasi hasil Rust (ValidationResponse) ke JsValue
             match serde_wasm_bindgen::to_value(&validation_response_rust) {
@@ -72,11 +131,181 @@ pub async fn validate_text_js(
         }
         Err(err) => {
             // 4. Konversi error dari Rust ke JsValue
-            Err(JsValue::from_str(&err.to_string()))
+            Err(to_js_error(err))
         }
     }
 }
 
+/// Validasi banyak field sekaligus. `items` berupa array `{ field, value, inputType }`;
+/// hasilnya objek `{ [field]: { valid, message, model } }`.
+#[wasm_bindgen(js_name = validateBatch)]
+pub async fn validate_batch_js(items: JsValue, model: i32) -> Result<JsValue, JsValue> {
+    let validator = VALIDATOR.with(|v| v.borrow().clone())
+        .ok_or("API key not configured")?;
+    let model_variant = SupportedModel::from_int(model).ok_or_else(|| {
+        JsValue::from_str(&format!(
+            "Invalid model selector: {}. Valid options: [{}]",
+            model,
+            SupportedModel::valid_options_desc()
+        ))
+    })?;
+    let items: Vec<BatchInput> = serde_wasm_bindgen::from_value(items)
+        .map_err(|e| JsValue::from_str(&format!("Invalid batch items: {}", e)))?;
+
+    let results = validator
+        .validate_batch_with_model_async(&items, model_variant)
+        .await
+        .map_err(to_js_error)?;
+
+    // `json_compatible` menghasilkan objek JS biasa, bukan `Map`.
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    serde::Serialize::serialize(&results, &serializer).map_err(|e| {
+        JsValue::from_str(&format!("Failed to serialize response to JsValue: {}", e))
+    })
+}
+
+/// Validasi paralel untuk banyak item (satu request per item, paling banyak
+/// `concurrency` bersamaan). Hasil berupa array sesuai urutan input berisi
+/// `{ field, result }` atau `{ field, error }`. `onProgress` (opsional) dipanggil
+/// dengan `{ completed, total, failed }` setiap kali satu item selesai.
+#[wasm_bindgen(js_name = validateMany)]
+pub async fn validate_many_js(
+    items: JsValue,
+    model: i32,
+    concurrency: u32,
+    on_progress: Option<js_sys::Function>,
+) -> Result<JsValue, JsValue> {
+    let validator = VALIDATOR.with(|v| v.borrow().clone())
+        .ok_or("API key not configured")?;
+    let model_variant = SupportedModel::from_int(model).ok_or_else(|| {
+        JsValue::from_str(&format!(
+            "Invalid model selector: {}. Valid options: [{}]",
+            model,
+            SupportedModel::valid_options_desc()
+        ))
+    })?;
+    let items: Vec<BatchInput> = serde_wasm_bindgen::from_value(items)
+        .map_err(|e| JsValue::from_str(&format!("Invalid batch items: {}", e)))?;
+
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    let results = validator
+        .validate_many_async(&items, model_variant, concurrency as usize, |progress| {
+            if let Some(callback) = &on_progress {
+                let reported = serde::Serialize::serialize(&progress, &serializer)
+                    .map_err(|e| JsValue::from_str(&e.to_string()))
+                    .and_then(|value| callback.call1(&JsValue::NULL, &value));
+                if let Err(e) = reported {
+                    web_sys::console::error_1(&e);
+                }
+            }
+        })
+        .await;
+
+    let outcomes = js_sys::Array::new();
+    for (item, result) in items.into_iter().zip(results) {
+        let outcome = js_sys::Object::new();
+        js_sys::Reflect::set(&outcome, &JsValue::from_str("field"), &JsValue::from_str(&item.field))?;
+        match result {
+            Ok(response) => {
+                let value = serde::Serialize::serialize(&response, &serializer)
+                    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+                js_sys::Reflect::set(&outcome, &JsValue::from_str("result"), &value)?;
+            }
+            Err(err) => {
+                js_sys::Reflect::set(&outcome, &JsValue::from_str("error"), &to_js_error(err))?;
+            }
+        }
+        outcomes.push(&outcome);
+    }
+    Ok(outcomes.into())
+}
+
+/// Pemeriksaan konsistensi antar-field. `form` berupa
+/// `{ fields: { [nama]: { value, inputType } }, relationships: [{ kind, fields, description? }] }`.
+#[wasm_bindgen(js_name = checkForm)]
+pub async fn check_form_js(form: JsValue, model: i32) -> Result<JsValue, JsValue> {
+    let validator = VALIDATOR.with(|v| v.borrow().clone())
+        .ok_or("API key not configured")?;
+    let model_variant = SupportedModel::from_int(model).ok_or_else(|| {
+        JsValue::from_str(&format!(
+            "Invalid model selector: {}. Valid options: [{}]",
+            model,
+            SupportedModel::valid_options_desc()
+        ))
+    })?;
+    let form: FormInput = serde_wasm_bindgen::from_value(form)
+        .map_err(|e| JsValue::from_str(&format!("Invalid form: {}", e)))?;
+
+    let report = validator
+        .check_form_with_model_async(&form, model_variant)
+        .await
+        .map_err(to_js_error)?;
+
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    serde::Serialize::serialize(&report, &serializer).map_err(|e| {
+        JsValue::from_str(&format!("Failed to serialize response to JsValue: {}", e))
+    })
+}
+
+/// Menebak tipe sebuah nilai: `{ candidates: [{ input_type, score, source }], label_mismatch, model }`.
+/// `label` opsional; jika diisi, nilai yang jelas bertipe lain ditandai di `label_mismatch`.
+#[wasm_bindgen(js_name = classifyInput)]
+pub async fn classify_input_js(value: String, model: i32, label: Option<String>) -> Result<JsValue, JsValue> {
+    let validator = VALIDATOR.with(|v| v.borrow().clone())
+        .ok_or("API key not configured")?;
+    let model_variant = SupportedModel::from_int(model).ok_or_else(|| {
+        JsValue::from_str(&format!(
+            "Invalid model selector: {}. Valid options: [{}]",
+            model,
+            SupportedModel::valid_options_desc()
+        ))
+    })?;
+
+    let classification = validator
+        .classify_input_with_model_async(&value, label.as_deref(), model_variant)
+        .await
+        .map_err(to_js_error)?;
+
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    serde::Serialize::serialize(&classification, &serializer).map_err(|e| {
+        JsValue::from_str(&format!("Failed to serialize response to JsValue: {}", e))
+    })
+}
+
+// Mengubah ValidationError menjadi objek `Error` JS dengan properti `code`, `status`, dan `retryAfter`,
+// sehingga pemanggil bisa membedakan jenis error tanpa mencocokkan pesan.
+fn to_js_error(err: ValidationError) -> JsValue {
+    let js_error = js_sys::Error::new(&err.to_string());
+    js_error.set_name("ValidationSemanticError");
+
+    let set = |key: &str, value: JsValue| {
+        if let Err(e) = js_sys::Reflect::set(&js_error, &JsValue::from_str(key), &value) {
+            web_sys::console::error_1(&e);
+        }
+    };
+
+    set("code", JsValue::from_str(err.code()));
+    set(
+        "status",
+        err.status().map(|s| JsValue::from_f64(s as f64)).unwrap_or(JsValue::NULL),
+    );
+    set(
+        "retryAfter",
+        err.retry_after()
+            .map(|d| JsValue::from_f64(d.as_secs_f64()))
+            .unwrap_or(JsValue::NULL),
+    );
+    match &err {
+        ValidationError::ModelOutputMalformed { raw, .. } | ValidationError::SchemaViolation { raw, .. } => {
+            set("raw", JsValue::from_str(raw))
+        }
+        ValidationError::Blocked { reason } => set("reason", JsValue::from_str(reason)),
+        _ => {}
+    }
+
+    js_error.into()
+}
+
 #[wasm_bindgen(js_name = getSupportedModelSelectors)]
 pub fn get_supported_model_selectors() -> JsValue {
     let models = js_sys::Object::new();
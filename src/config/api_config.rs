@@ -1,6 +1,22 @@
 use std::env;
+use std::fmt;
+use std::sync::Once;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::time::Duration;
+
 use once_cell::sync::Lazy;
 use dotenv::dotenv;
+use serde::Deserialize;
+
+use crate::core::error::{ValidationError, REDACTED};
+use crate::core::key_pool::{KeySelection, DEFAULT_KEY_COOLDOWN};
+use crate::core::locale::Locale;
+use crate::core::provider::{DEFAULT_USER_AGENT, GEMINI_BASE_URL};
+use crate::core::validator::DEFAULT_TIMEOUT;
+use crate::models::SupportedModel;
 
 // Refactored API Config Module
 // Konfigurasi API untuk menyimpan API key
@@ -19,17 +35,436 @@ use dotenv::dotenv;
 
 // Green
 // Konfigurasi API
+
+/// Prefix bawaan variabel lingkungan (`VALIDATION_API_KEY`, `VALIDATION_BASE_URL`, ...).
+pub const DEFAULT_ENV_PREFIX: &str = "VALIDATION_";
+
+/// Variabel lingkungan API key lama yang tetap dibaca jika `{prefix}API_KEY` kosong.
+pub const GOOGLE_API_KEY_ENV: &str = "GOOGLE_API_KEY";
+
+/// Konfigurasi lengkap validator bawaan.
+///
+/// Dibangun lewat [`ApiConfig::builder`] dari tiga sumber dengan urutan prioritas
+/// tetap, terlepas dari urutan pemanggilan method builder:
+///
+/// 1. nilai yang diatur langsung di builder,
+/// 2. variabel lingkungan (`{prefix}API_KEY`, dst.),
+/// 3. file TOML.
+///
+/// Nilai bawaan bukan sumber tersendiri: nilai itu hanya dipakai untuk pengaturan
+/// yang tidak diisi oleh ketiga sumber tersebut.
+///
+/// Daftar `root_certificates` tidak digabung antarsumber: sumber tertinggi yang
+/// mengaturnya dipakai apa adanya.
+#[derive(Clone)]
 pub struct ApiConfig {
     pub api_key: String,
+    /// Key tambahan; bersama `api_key` membentuk pool yang dirotasi (lihat `ApiKeyPool`).
+    pub api_keys: Vec<String>,
+    /// Strategi pemilihan key dari pool (bawaan: giliran).
+    pub key_selection: KeySelection,
+    /// Lama karantina key yang terkena 429 atau ditolak (bawaan: 60 detik).
+    pub key_cooldown: Duration,
+    /// Bahasa pesan validator bawaan, dari `VALIDATION_LOCALE` (bawaan: `id`).
+    pub locale: Locale,
+    /// File aturan tipe input buatan pengguna, dari `VALIDATION_RULES_FILE` (opsional).
+    pub rules_file: Option<String>,
+    /// Host API, misalnya gateway privat atau mock server (bawaan: endpoint Gemini).
+    pub base_url: String,
+    /// Batas waktu total satu request, termasuk membaca respons (bawaan: 60 detik).
+    pub timeout: Duration,
+    /// Batas waktu membuka koneksi; `None` memakai bawaan reqwest.
+    pub connect_timeout: Option<Duration>,
+    /// URL proxy HTTP/HTTPS untuk semua request.
+    pub proxy: Option<String>,
+    /// Path file PEM berisi root CA tambahan.
+    pub root_certificates: Vec<String>,
+    pub default_model: SupportedModel,
+    pub user_agent: String,
 }
 
-pub static API_CONFIG: Lazy<Result<ApiConfig, String>> = Lazy::new(|| {
-    if dotenv().is_err() {
-        // Warning jika .env tidak ketemu (wajar di CI/CD atau production env variabel)
+// API key tidak pernah ikut dicetak.
+impl fmt::Debug for ApiConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiConfig")
+            .field("api_key", &REDACTED)
+            .field("api_keys", &vec![REDACTED; self.api_keys.len()])
+            .field("key_selection", &self.key_selection)
+            .field("key_cooldown", &self.key_cooldown)
+            .field("locale", &self.locale)
+            .field("rules_file", &self.rules_file)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("proxy", &self.proxy.as_ref().map(|_| REDACTED))
+            .field("root_certificates", &self.root_certificates)
+            .field("default_model", &self.default_model)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
+impl ApiConfig {
+    pub fn builder() -> ApiConfigBuilder {
+        ApiConfigBuilder::default()
+    }
+
+    /// Konfigurasi dari variabel lingkungan berprefix `VALIDATION_`.
+    pub fn from_env() -> Result<Self, ValidationError> {
+        Self::from_env_prefixed(DEFAULT_ENV_PREFIX)
+    }
+
+    /// Konfigurasi dari variabel lingkungan berprefix `prefix` (lihat [`ApiConfigBuilder::env_prefixed`]).
+    pub fn from_env_prefixed(prefix: &str) -> Result<Self, ValidationError> {
+        Self::builder().env_prefixed(prefix)?.build()
+    }
+
+    /// Konfigurasi dari isi file TOML saja.
+    pub fn from_toml_str(text: &str) -> Result<Self, ValidationError> {
+        Self::builder().toml_str(text)?.build()
+    }
+}
+
+/// Isi satu sumber konfigurasi apa adanya (format file TOML).
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawSource {
+    api_key: Option<String>,
+    api_keys: Option<Vec<String>>,
+    key_selection: Option<String>,
+    key_cooldown_secs: Option<f64>,
+    locale: Option<String>,
+    rules_file: Option<String>,
+    base_url: Option<String>,
+    timeout_secs: Option<f64>,
+    connect_timeout_secs: Option<f64>,
+    proxy: Option<String>,
+    root_certificates: Option<Vec<String>>,
+    default_model: Option<String>,
+    user_agent: Option<String>,
+}
+
+/// Satu sumber konfigurasi yang sudah diperiksa; `None` berarti tidak diatur.
+#[derive(Debug, Clone, Default)]
+struct Source {
+    api_key: Option<String>,
+    api_keys: Option<Vec<String>>,
+    key_selection: Option<KeySelection>,
+    key_cooldown: Option<Duration>,
+    locale: Option<Locale>,
+    rules_file: Option<String>,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    root_certificates: Option<Vec<String>>,
+    default_model: Option<SupportedModel>,
+    user_agent: Option<String>,
+}
+
+impl Source {
+    // `origin` menyebut asal nilai (file TOML atau nama variabel) di pesan error.
+    fn parse(raw: RawSource, origin: &dyn Fn(&str) -> String) -> Result<Self, ValidationError> {
+        let locale = raw
+            .locale
+            .map(|code| {
+                code.parse::<Locale>()
+                    .map_err(|e| ValidationError::Config(format!("{}: {}", origin("locale"), e)))
+            })
+            .transpose()?;
+        let key_selection = raw
+            .key_selection
+            .map(|name| {
+                name.parse::<KeySelection>()
+                    .map_err(|e| ValidationError::Config(format!("{}: {}", origin("key_selection"), e)))
+            })
+            .transpose()?;
+        let default_model = raw
+            .default_model
+            .map(|name| {
+                SupportedModel::from_name(&name).ok_or_else(|| {
+                    ValidationError::Config(format!("{}: model '{}' tidak dikenal.", origin("default_model"), name))
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            api_key: raw.api_key,
+            api_keys: raw.api_keys,
+            key_selection,
+            key_cooldown: seconds(raw.key_cooldown_secs, &origin("key_cooldown_secs"))?,
+            locale,
+            rules_file: raw.rules_file,
+            base_url: raw.base_url,
+            timeout: seconds(raw.timeout_secs, &origin("timeout_secs"))?,
+            connect_timeout: seconds(raw.connect_timeout_secs, &origin("connect_timeout_secs"))?,
+            proxy: raw.proxy,
+            root_certificates: raw.root_certificates,
+            default_model,
+            user_agent: raw.user_agent,
+        })
+    }
+
+    // Nilai dari `self` menang atas `lower`.
+    fn or(self, lower: Source) -> Source {
+        Source {
+            api_key: self.api_key.or(lower.api_key),
+            api_keys: self.api_keys.or(lower.api_keys),
+            key_selection: self.key_selection.or(lower.key_selection),
+            key_cooldown: self.key_cooldown.or(lower.key_cooldown),
+            locale: self.locale.or(lower.locale),
+            rules_file: self.rules_file.or(lower.rules_file),
+            base_url: self.base_url.or(lower.base_url),
+            timeout: self.timeout.or(lower.timeout),
+            connect_timeout: self.connect_timeout.or(lower.connect_timeout),
+            proxy: self.proxy.or(lower.proxy),
+            root_certificates: self.root_certificates.or(lower.root_certificates),
+            default_model: self.default_model.or(lower.default_model),
+            user_agent: self.user_agent.or(lower.user_agent),
+        }
+    }
+}
+
+fn seconds(value: Option<f64>, origin: &str) -> Result<Option<Duration>, ValidationError> {
+    value
+        .map(|secs| {
+            Duration::try_from_secs_f64(secs)
+                .ok()
+                .filter(|duration| !duration.is_zero())
+                .ok_or_else(|| {
+                    ValidationError::Config(format!("{}: durasi harus berupa detik positif, bukan {}.", origin, secs))
+                })
+        })
+        .transpose()
+}
+
+/// Builder untuk [`ApiConfig`]. Lihat dokumentasi `ApiConfig` untuk urutan prioritas sumber.
+#[derive(Debug, Clone, Default)]
+pub struct ApiConfigBuilder {
+    file: Source,
+    env: Source,
+    explicit: Source,
+}
+
+impl ApiConfigBuilder {
+    /// Memuat sumber file dari isi TOML. Kunci yang dikenal: `api_key`, `api_keys`,
+    /// `key_selection`, `key_cooldown_secs`, `locale`,
+    /// `rules_file`, `base_url`, `timeout_secs`, `connect_timeout_secs`, `proxy`,
+    /// `root_certificates`, `default_model`, dan `user_agent`.
+    pub fn toml_str(mut self, text: &str) -> Result<Self, ValidationError> {
+        let raw: RawSource = toml::from_str(text)
+            .map_err(|e| ValidationError::Config(format!("File konfigurasi TOML tidak valid: {}", e)))?;
+        self.file = Source::parse(raw, &|key| format!("file konfigurasi, kunci `{}`", key))?;
+        Ok(self)
+    }
+
+    /// Memuat sumber file dari path file TOML.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn toml_file(self, path: impl AsRef<Path>) -> Result<Self, ValidationError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| {
+            ValidationError::Config(format!("Gagal membaca file konfigurasi '{}': {}", path.display(), e))
+        })?;
+        self.toml_str(&text)
+    }
+
+    /// Sama dengan `env_prefixed(DEFAULT_ENV_PREFIX)`.
+    pub fn env(self) -> Result<Self, ValidationError> {
+        self.env_prefixed(DEFAULT_ENV_PREFIX)
+    }
+
+    /// Memuat sumber variabel lingkungan `{prefix}API_KEY`, `{prefix}API_KEYS`
+    /// (dipisah koma), `{prefix}KEY_SELECTION`, `{prefix}KEY_COOLDOWN_SECS`, `{prefix}LOCALE`,
+    /// `{prefix}RULES_FILE`, `{prefix}BASE_URL`, `{prefix}TIMEOUT_SECS`,
+    /// `{prefix}CONNECT_TIMEOUT_SECS`, `{prefix}PROXY`, `{prefix}CA_CERTS`
+    /// (daftar path dengan pemisah seperti `PATH`), `{prefix}MODEL`, dan
+    /// `{prefix}USER_AGENT`. Nilai kosong dianggap tidak diatur.
+    ///
+    /// `GOOGLE_API_KEY` dibaca jika `{prefix}API_KEY` tidak ada. Jika
+    /// `{prefix}CONFIG_FILE` diatur, file TOML tersebut dimuat sebagai sumber file.
+    pub fn env_prefixed(mut self, prefix: &str) -> Result<Self, ValidationError> {
+        let var = |name: &str| {
+            env::var(format!("{}{}", prefix, name))
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+        };
+        let secs = |name: &str| -> Result<Option<f64>, ValidationError> {
+            var(name)
+                .map(|value| {
+                    value.trim().parse::<f64>().map_err(|_| {
+                        ValidationError::Config(format!("{}{}: '{}' bukan angka detik.", prefix, name, value))
+                    })
+                })
+                .transpose()
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(path) = var("CONFIG_FILE") {
+            self = self.toml_file(path)?;
+        }
+
+        let raw = RawSource {
+            api_key: var("API_KEY").or_else(|| env::var(GOOGLE_API_KEY_ENV).ok().filter(|key| !key.is_empty())),
+            api_keys: var("API_KEYS").map(|keys| {
+                keys.split(',')
+                    .map(str::trim)
+                    .filter(|key| !key.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            }),
+            key_selection: var("KEY_SELECTION"),
+            key_cooldown_secs: secs("KEY_COOLDOWN_SECS")?,
+            locale: var("LOCALE"),
+            rules_file: var("RULES_FILE"),
+            base_url: var("BASE_URL"),
+            timeout_secs: secs("TIMEOUT_SECS")?,
+            connect_timeout_secs: secs("CONNECT_TIMEOUT_SECS")?,
+            proxy: var("PROXY"),
+            root_certificates: var("CA_CERTS").map(|paths| {
+                env::split_paths(&paths)
+                    .map(|path| path.to_string_lossy().into_owned())
+                    .collect()
+            }),
+            default_model: var("MODEL"),
+            user_agent: var("USER_AGENT"),
+        };
+        let env_name = |key: &str| {
+            let suffix = match key {
+                "default_model" => "MODEL".to_string(),
+                other => other.to_ascii_uppercase(),
+            };
+            format!("{}{}", prefix, suffix)
+        };
+        self.env = Source::parse(raw, &env_name)?;
+        Ok(self)
+    }
+
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.explicit.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Key tambahan untuk pool (lihat `ApiConfig::api_keys`).
+    pub fn api_keys<I, S>(mut self, api_keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.explicit.api_keys = Some(api_keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn key_selection(mut self, selection: KeySelection) -> Self {
+        self.explicit.key_selection = Some(selection);
+        self
+    }
+
+    pub fn key_cooldown(mut self, cooldown: Duration) -> Self {
+        self.explicit.key_cooldown = Some(cooldown);
+        self
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.explicit.locale = Some(locale);
+        self
+    }
+
+    pub fn rules_file(mut self, path: impl Into<String>) -> Self {
+        self.explicit.rules_file = Some(path.into());
+        self
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.explicit.base_url = Some(base_url.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.explicit.timeout = Some(timeout);
+        self
     }
-    let api_key = env::var("GOOGLE_API_KEY")
-        .map_err(|e| format!("GOOGLE_API_KEY not set: {}", e))?;
-    Ok(ApiConfig { api_key })
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.explicit.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.explicit.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Menambah path file PEM root CA pada sumber builder.
+    pub fn root_certificate(mut self, path: impl Into<String>) -> Self {
+        self.explicit
+            .root_certificates
+            .get_or_insert_with(Vec::new)
+            .push(path.into());
+        self
+    }
+
+    pub fn default_model(mut self, model: SupportedModel) -> Self {
+        self.explicit.default_model = Some(model);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.explicit.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn build(self) -> Result<ApiConfig, ValidationError> {
+        let merged = self.explicit.or(self.env).or(self.file);
+        let mut api_keys = merged.api_keys.unwrap_or_default();
+        // Tanpa `api_key`, key pertama dari `api_keys` menjadi key utama.
+        let api_key = merged.api_key.filter(|key| !key.is_empty());
+        let api_key = match api_key {
+            None if !api_keys.is_empty() => Some(api_keys.remove(0)),
+            api_key => api_key,
+        };
+        let api_key = api_key.ok_or_else(|| {
+            ValidationError::Config(format!(
+                "API key belum diatur. Isi lewat builder, kunci `api_key` di file TOML, atau variabel lingkungan {}API_KEY / {}.",
+                DEFAULT_ENV_PREFIX, GOOGLE_API_KEY_ENV
+            ))
+        })?;
+
+        Ok(ApiConfig {
+            api_key,
+            api_keys,
+            key_selection: merged.key_selection.unwrap_or_default(),
+            key_cooldown: merged.key_cooldown.unwrap_or(DEFAULT_KEY_COOLDOWN),
+            locale: merged.locale.unwrap_or_default(),
+            rules_file: merged.rules_file.filter(|path| !path.trim().is_empty()),
+            base_url: merged.base_url.unwrap_or_else(|| GEMINI_BASE_URL.to_string()),
+            timeout: merged.timeout.unwrap_or(DEFAULT_TIMEOUT),
+            connect_timeout: merged.connect_timeout,
+            proxy: merged.proxy,
+            root_certificates: merged.root_certificates.unwrap_or_default(),
+            default_model: merged.default_model.unwrap_or(SupportedModel::GeminiFlash),
+            user_agent: merged.user_agent.unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+        })
+    }
+}
+
+/// Memuat `.env` ke variabel lingkungan, sekali per proses.
+pub fn load_dotenv() {
+    static LOADED: Once = Once::new();
+    LOADED.call_once(|| {
+        if dotenv().is_err() {
+            // Warning jika .env tidak ketemu (wajar di CI/CD atau production env variabel)
+        }
+    });
+}
+
+/// Konfigurasi dari lingkungan yang dibaca sekali saat pertama dipakai.
+///
+/// Nilainya (termasuk error) tidak berubah selama proses hidup. Validator bawaan
+/// tidak memakainya; lihat `core::default_validator` untuk konfigurasi yang bisa
+/// diatur ulang saat runtime.
+pub static API_CONFIG: Lazy<Result<ApiConfig, String>> = Lazy::new(|| {
+    load_dotenv();
+    ApiConfig::from_env().map_err(|e| e.to_string())
 });
 
 
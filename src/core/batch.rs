@@ -0,0 +1,390 @@
+// src/core/batch.rs
+// Validasi banyak field sekaligus dalam satu request LLM.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use tracing::Instrument;
+
+use crate::core::cache::now_millis;
+use crate::core::core_logic::{clean_json_markdown, task_prompt, verdict_metadata_note};
+use crate::core::error::ValidationError;
+use crate::core::locale::Locale;
+use crate::core::injection::{field_reference, field_user_part, hardened_batch_parts, PromptParts};
+use crate::core::telemetry::next_request_id;
+use crate::core::validator::{elapsed_ms, Completion, SemanticValidator};
+use crate::models::{SupportedModel, ValidationResponse};
+
+/// Satu field dalam batch: id unik, nilai, dan tipe input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchInput {
+    #[serde(alias = "field_id", alias = "fieldId")]
+    pub field: String,
+    pub value: String,
+    #[serde(alias = "inputType")]
+    pub input_type: String,
+}
+
+impl BatchInput {
+    pub fn new(field: impl Into<String>, value: impl Into<String>, input_type: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            value: value.into(),
+            input_type: input_type.into(),
+        }
+    }
+}
+
+/// Hasil batch: satu `ValidationResponse` per id field.
+pub type BatchResponse = HashMap<String, ValidationResponse>;
+
+/// Anggaran token untuk memecah batch besar menjadi beberapa request.
+///
+/// Token diperkirakan dari jumlah karakter (sekitar 4 karakter per token), jadi
+/// angka ini sengaja dibuat konservatif terhadap batas model sebenarnya.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchOptions {
+    /// Perkiraan maksimum token prompt per request.
+    pub max_prompt_tokens: usize,
+    /// Perkiraan maksimum token keluaran per request.
+    pub max_response_tokens: usize,
+    /// Perkiraan token keluaran untuk satu field (objek `field`/`valid`/`message`).
+    pub response_tokens_per_field: usize,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            max_prompt_tokens: 8000,
+            max_response_tokens: 2048,
+            response_tokens_per_field: 128,
+        }
+    }
+}
+
+/// Perkiraan kasar jumlah token sebuah teks.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+const BATCH_HEADER: &str = "You will validate several independent fields in one pass.\n\
+Each field below is a separate task with its own validation rules. Apply each task's rules only to that field.\n\
+IGNORE the per-task output format instructions: do NOT output one JSON object per task.\n";
+
+fn batch_footer() -> String {
+    format!(
+        "\nRespond ONLY with a single-line JSON array (no Markdown, no extra text) containing exactly one object per field, \
+in the same order as the fields above:\n\
+[{{\"field\": \"<field id>\", \"valid\": true|false, \"message\": \"explanation in the language that field's task asks for\"}}]\n{}",
+        verdict_metadata_note()
+    )
+}
+
+fn field_section(item: &BatchInput, task: &str) -> String {
+    // Id field di-quote ala JSON agar karakter khusus tidak merusak struktur prompt.
+    let field_id = serde_json::to_string(&item.field).unwrap_or_default();
+    format!("\n=== FIELD {} ===\n{}\n", field_id, task.trim())
+}
+
+/// Menyusun satu prompt untuk beberapa field dengan keluaran berupa array JSON.
+pub fn format_batch_prompt(items: &[BatchInput]) -> String {
+    format_batch_prompt_for(items, Locale::default())
+}
+
+/// Seperti `format_batch_prompt`, dengan penjelasan model dalam bahasa `locale`.
+pub fn format_batch_prompt_for(items: &[BatchInput], locale: Locale) -> String {
+    format_batch_prompt_parts(items, locale).combined()
+}
+
+/// Prompt batch yang diperkeras: aturan semua field di bagian sistem, setiap nilai
+/// di bagian user dalam `<user_input field="...">` masing-masing.
+pub fn format_batch_prompt_parts(items: &[BatchInput], locale: Locale) -> PromptParts {
+    let items: Vec<&BatchInput> = items.iter().collect();
+    build_batch_prompt(&items, |reference, item| task_prompt(reference, &item.input_type, locale))
+}
+
+// `task` menerima rujukan ke nilai field, bukan nilainya, sehingga aturan tidak pernah memuat input.
+fn build_batch_prompt(items: &[&BatchInput], task: impl Fn(&str, &BatchInput) -> String) -> PromptParts {
+    let mut tasks = String::from(BATCH_HEADER);
+    for item in items {
+        tasks.push_str(&field_section(item, &task(&field_reference(&item.field), item)));
+    }
+    tasks.push_str(&batch_footer());
+    let fields: Vec<(&str, &str)> = items
+        .iter()
+        .map(|item| (item.field.as_str(), item.value.as_str()))
+        .collect();
+    hardened_batch_parts(&tasks, &fields)
+}
+
+#[derive(Deserialize)]
+struct BatchVerdict {
+    field: String,
+    #[serde(flatten)]
+    verdict: ValidationResponse,
+}
+
+/// Mem-parse keluaran batch menjadi putusan per field.
+///
+/// Menerima array langsung atau objek `{"results": [...]}`. Semua field di
+/// `expected_fields` wajib ada; jika tidak, error `ModelOutputMalformed`.
+pub fn parse_batch_output(text: &str, expected_fields: &[&str]) -> Result<BatchResponse, ValidationError> {
+    let json: serde_json::Value = serde_json::from_str(clean_json_markdown(text))
+        .map_err(|e| ValidationError::malformed(text, format!("Gagal parse string ke JSON Value. Error: {}", e)))?;
+
+    let array = match json {
+        serde_json::Value::Array(array) => array,
+        serde_json::Value::Object(mut object) => match object.remove("results") {
+            Some(serde_json::Value::Array(array)) => array,
+            _ => return Err(ValidationError::malformed(text, "Keluaran batch bukan array JSON")),
+        },
+        _ => return Err(ValidationError::malformed(text, "Keluaran batch bukan array JSON")),
+    };
+
+    let mut results = BatchResponse::new();
+    for value in array {
+        let verdict: BatchVerdict = serde_json::from_value(value).map_err(|e| {
+            ValidationError::malformed(text, format!("Elemen batch tidak sesuai format. Error: {}", e))
+        })?;
+        let BatchVerdict { field, mut verdict } = verdict;
+        if expected_fields.contains(&field.as_str()) {
+            verdict.model = None;
+            verdict.normalize_llm_verdict();
+            results.insert(field, verdict);
+        }
+    }
+
+    let missing: Vec<&str> = expected_fields
+        .iter()
+        .copied()
+        .filter(|field| !results.contains_key(*field))
+        .collect();
+    if !missing.is_empty() {
+        return Err(ValidationError::malformed(
+            text,
+            format!("Model tidak mengembalikan hasil untuk field: {}", missing.join(", ")),
+        ));
+    }
+    Ok(results)
+}
+
+/// Membagi item menjadi kelompok yang muat dalam anggaran token. Setiap kelompok
+/// berisi minimal satu item, walaupun item itu sendiri melebihi anggaran.
+pub fn plan_batches<'a>(items: &[&'a BatchInput], options: &BatchOptions) -> Vec<Vec<&'a BatchInput>> {
+    plan_batches_with(items, options, |reference, item| {
+        task_prompt(reference, &item.input_type, Locale::default())
+    })
+}
+
+fn plan_batches_with<'a>(
+    items: &[&'a BatchInput],
+    options: &BatchOptions,
+    task: impl Fn(&str, &BatchInput) -> String,
+) -> Vec<Vec<&'a BatchInput>> {
+    let envelope_tokens = estimate_tokens(&hardened_batch_parts(BATCH_HEADER, &[]).system)
+        + estimate_tokens(&batch_footer());
+    let mut batches: Vec<Vec<&BatchInput>> = Vec::new();
+    let mut current: Vec<&BatchInput> = Vec::new();
+    let mut prompt_tokens = envelope_tokens;
+
+    for item in items {
+        let section = field_section(item, &task(&field_reference(&item.field), item));
+        let item_tokens = estimate_tokens(&section) + estimate_tokens(&field_user_part(&item.field, &item.value));
+        let response_tokens = (current.len() + 1) * options.response_tokens_per_field;
+        let fits = prompt_tokens + item_tokens <= options.max_prompt_tokens
+            && response_tokens <= options.max_response_tokens;
+
+        if !current.is_empty() && !fits {
+            batches.push(std::mem::take(&mut current));
+            prompt_tokens = envelope_tokens;
+        }
+        prompt_tokens += item_tokens;
+        current.push(item);
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+    batches
+}
+
+// Hasil tahap lokal: putusan yang sudah pasti (sintaksis/cache) dan item yang perlu LLM.
+struct BatchPlan<'a> {
+    results: BatchResponse,
+    pending: Vec<&'a BatchInput>,
+}
+
+// Span untuk satu pemanggilan batch, dengan ID korelasi sendiri.
+fn batch_span(model_name: &str, fields: usize) -> tracing::Span {
+    tracing::info_span!("validate_batch", request_id = next_request_id(), model = model_name, fields)
+}
+
+// Prompt untuk satu kelompok: prompt tunggal atau prompt batch gabungan, keduanya diperkeras.
+enum BatchPrompt {
+    Single(PromptParts),
+    Multi(PromptParts),
+}
+
+impl BatchPrompt {
+    fn completion(&self) -> Completion<'_> {
+        match self {
+            BatchPrompt::Single(parts) => Completion::verdict(parts),
+            BatchPrompt::Multi(parts) => Completion::parts(parts),
+        }
+    }
+}
+
+impl SemanticValidator {
+    /// Validasi banyak field memakai model bawaan validator.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn validate_batch_sync(&self, items: &[BatchInput]) -> Result<BatchResponse, ValidationError> {
+        self.validate_batch_with_model_sync(items, self.default_model())
+    }
+
+    /// Validasi banyak field: sintaksis dicek lokal per field, sisanya dikirim dalam
+    /// sesedikit mungkin request sesuai [`BatchOptions`].
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn validate_batch_with_model_sync(
+        &self,
+        items: &[BatchInput],
+        model: SupportedModel,
+    ) -> Result<BatchResponse, ValidationError> {
+        let model_name = model.as_str();
+        let _entered = batch_span(model_name, items.len()).entered();
+        let BatchPlan { mut results, pending } = self.prepare_batch(items, model_name)?;
+
+        for batch in self.plan_batches(&pending) {
+            let prompt = self.batch_prompt(&batch);
+            let started = now_millis();
+            let (text, answered_by) = self.complete_with_fallback_sync(prompt.completion(), model_name)?;
+            self.collect_batch(&batch, &text, model_name, answered_by, elapsed_ms(started), &mut results)?;
+        }
+        Ok(results)
+    }
+
+    /// Versi asinkron dari `validate_batch_sync`.
+    pub async fn validate_batch_async(&self, items: &[BatchInput]) -> Result<BatchResponse, ValidationError> {
+        self.validate_batch_with_model_async(items, self.default_model())
+            .await
+    }
+
+    /// Versi asinkron dari `validate_batch_with_model_sync`.
+    pub async fn validate_batch_with_model_async(
+        &self,
+        items: &[BatchInput],
+        model: SupportedModel,
+    ) -> Result<BatchResponse, ValidationError> {
+        let model_name = model.as_str();
+        async {
+            let BatchPlan { mut results, pending } = self.prepare_batch(items, model_name)?;
+
+            for batch in self.plan_batches(&pending) {
+                let prompt = self.batch_prompt(&batch);
+                let started = now_millis();
+                let (text, answered_by) = self
+                    .complete_with_fallback_async(prompt.completion(), model_name)
+                    .await?;
+                self.collect_batch(&batch, &text, model_name, answered_by, elapsed_ms(started), &mut results)?;
+            }
+            Ok(results)
+        }
+        .instrument(batch_span(model_name, items.len()))
+        .await
+    }
+
+    fn prepare_batch<'a>(&self, items: &'a [BatchInput], model_name: &str) -> Result<BatchPlan<'a>, ValidationError> {
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = items.iter().find(|item| !seen.insert(item.field.as_str())) {
+            return Err(ValidationError::Config(format!(
+                "Id field '{}' muncul lebih dari sekali dalam batch.",
+                duplicate.field
+            )));
+        }
+
+        for item in items {
+            self.ensure_known_type(&item.input_type)?;
+        }
+
+        let mut results = BatchResponse::new();
+        let mut pending = Vec::new();
+        for item in items {
+            let started = now_millis();
+            if let Some(mut response) = self.local_verdict(&item.value, &item.input_type) {
+                response.latency_ms = elapsed_ms(started);
+                results.insert(item.field.clone(), response);
+                continue;
+            }
+
+            let cached = self
+                .has_cache()
+                .then(|| self.cache_key(&item.value, &item.input_type, model_name))
+                .and_then(|key| self.cached_verdict(&key));
+            match cached {
+                Some(mut response) => {
+                    response.latency_ms = elapsed_ms(started);
+                    results.insert(item.field.clone(), response);
+                }
+                None => pending.push(item),
+            }
+        }
+
+        tracing::debug!(fields = items.len(), pending = pending.len(), "batch siap, sebagian perlu validasi LLM");
+        Ok(BatchPlan { results, pending })
+    }
+
+    fn collect_batch(
+        &self,
+        batch: &[&BatchInput],
+        text: &str,
+        model_name: &str,
+        answered_by: &str,
+        latency_ms: u64,
+        results: &mut BatchResponse,
+    ) -> Result<(), ValidationError> {
+        let verdicts = if let [single] = batch {
+            // Satu field memakai prompt tunggal biasa, jadi keluarannya objek tunggal.
+            let verdict = self.judge(&single.value, &single.input_type, text, answered_by)?;
+            HashMap::from([(single.field.clone(), verdict)])
+        } else {
+            let fields: Vec<&str> = batch.iter().map(|item| item.field.as_str()).collect();
+            let mut verdicts = parse_batch_output(text, &fields)?;
+            for item in batch {
+                if let Some(verdict) = verdicts.remove(&item.field) {
+                    // Setiap putusan field diperiksa terhadap nilainya sendiri, seperti prompt tunggal.
+                    let output = serde_json::json!({ "valid": verdict.valid, "message": verdict.message }).to_string();
+                    let verdict = self.unless_echoed(&item.value, &item.input_type, &output, verdict);
+                    verdicts.insert(item.field.clone(), verdict);
+                }
+            }
+            verdicts
+        };
+
+        for item in batch {
+            if let Some(mut response) = verdicts.get(&item.field).cloned() {
+                response.model = Some(answered_by.to_string());
+                // Semua field dalam kelompok ini berbagi latensi request yang sama.
+                response.latency_ms = latency_ms;
+                if self.has_cache() {
+                    self.store_verdict(self.cache_key(&item.value, &item.input_type, model_name), &response);
+                }
+                results.insert(item.field.clone(), response);
+            }
+        }
+        Ok(())
+    }
+
+    fn plan_batches<'a>(&self, items: &[&'a BatchInput]) -> Vec<Vec<&'a BatchInput>> {
+        plan_batches_with(items, self.batch_options(), |reference, item| {
+            self.task_prompt_for(reference, &item.input_type)
+        })
+    }
+
+    // Kelompok berisi satu field memakai prompt tunggal agar hasilnya identik dengan validasi biasa.
+    fn batch_prompt(&self, batch: &[&BatchInput]) -> BatchPrompt {
+        match batch {
+            [single] => BatchPrompt::Single(self.prompt_parts_for(&single.value, &single.input_type)),
+            _ => BatchPrompt::Multi(build_batch_prompt(batch, |reference, item| {
+                self.task_prompt_for(reference, &item.input_type)
+            })),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+// src/core/cache.rs
+// Cache hasil validasi (LRU + TTL) di depan pemanggilan LLM.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::injection::format_prompt_parts;
+use crate::core::label_resolver::input_type_for;
+use crate::core::locale::Locale;
+use crate::models::ValidationResponse;
+
+/// Kapasitas bawaan saat cache diaktifkan tanpa angka eksplisit.
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+// Input pengganti untuk menghitung sidik jari template prompt per tipe input.
+pub(crate) const PROMPT_PLACEHOLDER: &str = "\u{0}__INPUT__\u{0}";
+
+/// Kunci cache: input yang sudah di-trim, tipe input kanonis, model, dan hash template prompt.
+///
+/// Tipe input kanonis adalah id tipe bawaan (`"email"` untuk "email", "alamat email",
+/// maupun "E-mail Kantor"), nama aturan untuk tipe buatan pengguna, atau label yang
+/// dinormalisasi untuk tipe yang tidak dikenal.
+///
+/// Hash template membuat entri lama otomatis tidak terpakai ketika isi `format_prompt` berubah,
+/// dan memisahkan putusan untuk bahasa yang berbeda.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub input: String,
+    pub input_type: String,
+    pub model: String,
+    pub prompt_version: u64,
+}
+
+impl CacheKey {
+    pub fn new(user_input: &str, input_type_str: &str, model_name: &str) -> Self {
+        Self::for_locale(user_input, input_type_str, model_name, Locale::default())
+    }
+
+    /// Kunci untuk putusan dalam bahasa `locale`; prompt tiap bahasa berbeda, jadi
+    /// hash templatnya juga berbeda.
+    pub fn for_locale(user_input: &str, input_type_str: &str, model_name: &str, locale: Locale) -> Self {
+        let template = format_prompt_parts(PROMPT_PLACEHOLDER, &canonical_input_type(input_type_str), locale).combined();
+        let input_type = match input_type_for(input_type_str) {
+            Some(input_type) => input_type.as_str().to_string(),
+            None => canonical_input_type(input_type_str),
+        };
+        Self::for_template(user_input, input_type, model_name, &template)
+    }
+
+    /// Kunci dengan sidik jari dari `template`, yaitu prompt lengkap untuk `PROMPT_PLACEHOLDER`.
+    /// `input_type` sudah berupa tipe kanonis (misalnya nama aturan buatan pengguna).
+    pub(crate) fn for_template(user_input: &str, input_type: String, model_name: &str, template: &str) -> Self {
+        Self {
+            input: user_input.trim().to_string(),
+            input_type,
+            model: model_name.to_string(),
+            prompt_version: fnv1a(template.as_bytes()),
+        }
+    }
+}
+
+/// Statistik pemakaian cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+    pub capacity: usize,
+}
+
+/// Cache hasil validasi dengan kebijakan LRU dan TTL opsional.
+///
+/// Urutan pemakaian disimpan di indeks terurut, sehingga pencarian, penyisipan,
+/// dan pembuangan entri terlama tetap O(log n) untuk kapasitas besar.
+///
+/// Kapasitas `0` berarti cache nonaktif: semua pencarian dilewati tanpa dihitung.
+/// Konfigurasi bisa diubah saat runtime, sehingga satu instance dapat dibagi
+/// beberapa validator (lewat `Arc`) dan diatur dari binding.
+pub struct ResultCache {
+    inner: Mutex<CacheInner>,
+}
+
+struct CacheInner {
+    capacity: usize,
+    ttl: Option<Duration>,
+    entries: HashMap<CacheKey, CacheEntry>,
+    // Indeks keterpakaian: `last_used` tiap entri -> kuncinya; entri pertama paling lama tidak dipakai.
+    recency: BTreeMap<u64, CacheKey>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+struct CacheEntry {
+    response: ValidationResponse,
+    expires_at_ms: Option<u64>,
+    last_used: u64,
+}
+
+impl ResultCache {
+    pub fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            inner: Mutex::new(CacheInner {
+                capacity,
+                ttl,
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+                hits: 0,
+                misses: 0,
+            }),
+        }
+    }
+
+    /// Cache nonaktif (kapasitas 0); dapat diaktifkan kemudian dengan [`ResultCache::configure`].
+    pub fn disabled() -> Self {
+        Self::new(0, None)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.lock().capacity > 0
+    }
+
+    /// Mengubah kapasitas dan TTL. Entri berlebih dibuang mulai dari yang paling lama tidak dipakai.
+    pub fn configure(&self, capacity: usize, ttl: Option<Duration>) {
+        let mut inner = self.lock();
+        inner.capacity = capacity;
+        inner.ttl = ttl;
+        while inner.entries.len() > inner.capacity {
+            inner.evict_least_recently_used();
+        }
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<ValidationResponse> {
+        let mut guard = self.lock();
+        let inner = &mut *guard;
+        if inner.capacity == 0 {
+            return None;
+        }
+
+        let now = now_millis();
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        let expired = match inner.entries.get_mut(key) {
+            Some(entry) if entry.expires_at_ms.is_some_and(|at| now >= at) => true,
+            Some(entry) => {
+                let previous = std::mem::replace(&mut entry.last_used, tick);
+                let response = entry.response.clone();
+                if let Some(key) = inner.recency.remove(&previous) {
+                    inner.recency.insert(tick, key);
+                }
+                inner.hits += 1;
+                return Some(response);
+            }
+            None => false,
+        };
+        if expired {
+            inner.remove(key);
+        }
+        inner.misses += 1;
+        None
+    }
+
+    pub fn insert(&self, key: CacheKey, response: ValidationResponse) {
+        let mut inner = self.lock();
+        if inner.capacity == 0 {
+            return;
+        }
+
+        inner.tick += 1;
+        let entry = CacheEntry {
+            response,
+            expires_at_ms: inner.ttl.map(|ttl| now_millis().saturating_add(ttl.as_millis() as u64)),
+            last_used: inner.tick,
+        };
+        inner.remove(&key);
+        inner.recency.insert(entry.last_used, key.clone());
+        inner.entries.insert(key, entry);
+        while inner.entries.len() > inner.capacity {
+            inner.evict_least_recently_used();
+        }
+    }
+
+    /// Menghapus semua entri dan mengatur ulang statistik.
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        inner.entries.clear();
+        inner.recency.clear();
+        inner.hits = 0;
+        inner.misses = 0;
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let inner = self.lock();
+        CacheStats {
+            hits: inner.hits,
+            misses: inner.misses,
+            entries: inner.entries.len(),
+            capacity: inner.capacity,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheInner> {
+        // Cache hanya berisi data turunan; jika mutex "poisoned", isinya tetap aman dipakai.
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+impl CacheInner {
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, key)) = self.recency.pop_first() {
+            self.entries.remove(&key);
+        }
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
+
+/// Bentuk kanonis tipe input: huruf kecil, tanpa spasi di tepi, spasi ganda dirapatkan.
+pub fn canonical_input_type(input_type_str: &str) -> String {
+    input_type_str
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// FNV-1a 64-bit: stabil antar proses dan versi Rust (berbeda dengan `DefaultHasher`).
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+// Waktu dalam milidetik untuk TTL. `Instant` tidak tersedia di wasm32-unknown-unknown.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn now_millis() -> u64 {
+    use once_cell::sync::Lazy;
+    use std::time::Instant;
+
+    static START: Lazy<Instant> = Lazy::new(Instant::now);
+    START.elapsed().as_millis() as u64
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm_bindings_setup"))]
+pub(crate) fn now_millis() -> u64 {
+    js_sys::Date::now() as u64
+}
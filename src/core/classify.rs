@@ -0,0 +1,371 @@
+// src/core/classify.rs
+// Menebak tipe input dari nilainya saja: detektor lokal dulu, lalu klasifikasi LLM bila ragu.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::core::core_logic::clean_json_markdown;
+use crate::core::error::ValidationError;
+use crate::core::injection::{hardened_data_parts, is_echoed_answer, PromptParts, INPUT_REFERENCE};
+use crate::core::label_resolver::input_type_for;
+use crate::core::locale::{LocalMessage, Locale};
+use crate::core::validator::{Completion, SemanticValidator};
+use crate::models::{InputType, SupportedModel};
+
+/// Skor detektor lokal yang dianggap cukup pasti sehingga LLM tidak perlu ditanya.
+pub const CONFIDENT_SCORE: f64 = 0.85;
+
+/// Jumlah kandidat maksimal dalam satu [`Classification`].
+pub const MAX_CANDIDATES: usize = 5;
+
+/// Asal sebuah kandidat tipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectionSource {
+    /// Detektor lokal (regex, pola digit, kata kunci).
+    Local,
+    /// Klasifikasi oleh LLM.
+    Llm,
+}
+
+impl DetectionSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DetectionSource::Local => "local",
+            DetectionSource::Llm => "llm",
+        }
+    }
+}
+
+/// Satu tebakan tipe beserta skornya (0.0 sampai 1.0).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeCandidate {
+    pub input_type: InputType,
+    pub score: f64,
+    pub source: DetectionSource,
+}
+
+/// Nilai yang tidak cocok dengan label field-nya (misalnya email di field nama).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelMismatch {
+    pub label: String,
+    /// Tipe kanonis label.
+    pub expected: InputType,
+    /// Tipe yang terdeteksi dari nilai.
+    pub detected: InputType,
+    pub message: String,
+}
+
+/// Hasil klasifikasi: kandidat diurutkan dari skor tertinggi.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Classification {
+    pub candidates: Vec<TypeCandidate>,
+    /// Terisi jika label diberikan dan nilainya jelas bertipe lain.
+    pub label_mismatch: Option<LabelMismatch>,
+    /// Model yang menjalankan klasifikasi LLM (`None` jika diputuskan secara lokal).
+    pub model: Option<String>,
+}
+
+impl Classification {
+    /// Kandidat dengan skor tertinggi.
+    pub fn best(&self) -> Option<&TypeCandidate> {
+        self.candidates.first()
+    }
+}
+
+static EMAIL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[A-Za-z]{2,}$").unwrap());
+static URL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^(https?://|www\.)\S+$").unwrap());
+static DOMAIN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^[a-z0-9-]+(\.[a-z0-9-]+)*\.[a-z]{2,}(/\S*)?$").unwrap());
+static PHONE_ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\+?62|0)8[0-9]{7,12}$").unwrap());
+static NPWP_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\d{2}\.\d{3}\.\d{3}\.\d-\d{3}\.\d{3}$").unwrap());
+static DATE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\d{4}[-/.]\d{1,2}[-/.]\d{1,2}|\d{1,2}[-/.]\d{1,2}[-/.]\d{2,4})$").unwrap());
+static NUMBER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(rp\.?\s*|\$\s*)?\d[\d.,]*(\s*(tahun|thn|th|juta|jt|ribu|rb|k|rupiah))?$").unwrap()
+});
+static USERNAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^@[A-Za-z0-9_.]{2,30}$").unwrap());
+static TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^#[\p{L}\p{N}_]+(\s+#[\p{L}\p{N}_]+)*$").unwrap());
+static COMPANY_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(^(pt|cv|ud)\.?\s)|(\s(tbk|inc|ltd|llc|corp|gmbh)\.?$)").unwrap());
+static INSTITUTION_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(universitas|university|institut|institute|sekolah|smp|sma|smk|kementerian|dinas|rumah sakit|yayasan|politeknik)\b").unwrap()
+});
+static ADDRESS_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(jl|jln|jalan|gg|gang|rt|rw|kel|kec|street|st|road|rd|ave)\b\.?").unwrap());
+
+/// Detektor lokal tanpa LLM. Hasilnya diurutkan dari skor tertinggi; kosong jika tidak ada
+/// pola yang dikenali.
+pub fn detect_types(value: &str) -> Vec<TypeCandidate> {
+    let input = value.trim();
+    let mut found: Vec<(InputType, f64)> = Vec::new();
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    let compact: String = input.chars().filter(|c| !matches!(c, ' ' | '-' | '(' | ')')).collect();
+    let digits = compact.trim_start_matches('+');
+    let all_digits = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+
+    if EMAIL_REGEX.is_match(input) {
+        found.push((InputType::Email, 0.98));
+    } else if URL_REGEX.is_match(input) {
+        found.push((InputType::Url, 0.95));
+    } else if NPWP_REGEX.is_match(input) {
+        found.push((InputType::IdentityNumber, 0.95));
+    } else if all_digits {
+        match digits.len() {
+            // NIK/KTP: tepat 16 digit.
+            16 if !compact.starts_with('+') => found.push((InputType::IdentityNumber, 0.9)),
+            _ if PHONE_ID_REGEX.is_match(&compact) => found.push((InputType::Phone, 0.9)),
+            7..=15 => {
+                found.push((InputType::Phone, 0.5));
+                found.push((InputType::Number, 0.4));
+            }
+            _ => found.push((InputType::Number, 0.85)),
+        }
+    } else if DATE_REGEX.is_match(input) {
+        found.push((InputType::Date, 0.9));
+    } else if NUMBER_REGEX.is_match(input) {
+        found.push((InputType::Number, 0.85));
+    } else if USERNAME_REGEX.is_match(input) {
+        found.push((InputType::Username, 0.9));
+    } else if TAG_REGEX.is_match(input) {
+        found.push((InputType::Tag, 0.9));
+    } else if DOMAIN_REGEX.is_match(input) {
+        found.push((InputType::Url, 0.8));
+    } else {
+        detect_text(input, &mut found);
+    }
+
+    found.sort_by(|a, b| b.1.total_cmp(&a.1));
+    found
+        .into_iter()
+        .map(|(input_type, score)| TypeCandidate { input_type, score, source: DetectionSource::Local })
+        .collect()
+}
+
+// Teks bebas: badan usaha, institusi, alamat, teks panjang, atau kemungkinan nama orang.
+fn detect_text(input: &str, found: &mut Vec<(InputType, f64)>) {
+    let words = input.split_whitespace().count();
+    let sentences = input.matches(['.', '!', '?']).count();
+
+    if COMPANY_REGEX.is_match(input) {
+        found.push((InputType::Company, 0.9));
+    } else if INSTITUTION_REGEX.is_match(input) && words <= 12 {
+        found.push((InputType::Institution, 0.85));
+    } else if ADDRESS_REGEX.is_match(input) && input.chars().any(|c| c.is_ascii_digit()) {
+        found.push((InputType::Address, 0.85));
+    } else if input.chars().count() > 200 || words > 25 || (sentences >= 2 && words > 10) {
+        found.push((InputType::Content, 0.85));
+    } else if words > 12 {
+        found.push((InputType::Content, 0.6));
+    } else if (2..=4).contains(&words)
+        && input
+            .split_whitespace()
+            .all(|word| word.chars().next().is_some_and(char::is_uppercase) && word.chars().all(char::is_alphabetic))
+    {
+        // Bisa juga judul, produk, atau lokasi; LLM yang memastikan.
+        found.push((InputType::PersonName, 0.5));
+    }
+}
+
+/// Tipe yang dianggap sejalan meski tidak sama persis (label "institusi" untuk "PT ...").
+fn compatible(expected: InputType, detected: InputType) -> bool {
+    use InputType::*;
+    expected == detected
+        || matches!(
+            (expected, detected),
+            (Institution, Company)
+                | (Company, Institution)
+                | (Number, Phone)
+                | (Number, IdentityNumber)
+                | (IdentityNumber, Number)
+                | (Phone, Number)
+                | (Url, Email)
+                | (Username, Email)
+                | (Location, Address)
+                | (Address, Location)
+        )
+        // Teks bebas dapat berisi apa saja.
+        || matches!(expected, Content | Title | Tag)
+}
+
+/// Membandingkan label field dengan hasil klasifikasi. Hanya kandidat yang cukup pasti
+/// (skor minimal [`CONFIDENT_SCORE`]) yang dapat memicu ketidakcocokan.
+pub fn find_label_mismatch(label: &str, candidates: &[TypeCandidate], locale: Locale) -> Option<LabelMismatch> {
+    let expected = input_type_for(label)?;
+    let best = candidates.first().filter(|candidate| candidate.score >= CONFIDENT_SCORE)?;
+    let supported = candidates
+        .iter()
+        .any(|candidate| candidate.score >= 0.5 && compatible(expected, candidate.input_type));
+    if supported {
+        return None;
+    }
+    let message = LocalMessage::LabelMismatch { label: label.trim().to_string(), detected: best.input_type };
+    Some(LabelMismatch {
+        label: label.trim().to_string(),
+        expected,
+        detected: best.input_type,
+        message: message.render(locale),
+    })
+}
+
+/// Prompt klasifikasi LLM: daftar tipe kanonis di instruksi sistem, nilai yang harus ditebak
+/// tipenya di bagian user.
+pub fn format_classify_prompt(value: &str) -> PromptParts {
+    let mut task = format!(
+        "You are classifying {} into the most likely input types.\n\nAvailable types (id: description):\n",
+        INPUT_REFERENCE
+    );
+    for input_type in InputType::ALL {
+        task.push_str(&format!("- {}: {}\n", input_type.as_str(), input_type.description()));
+    }
+    task.push_str(
+        "\nRespond ONLY with a single-line JSON object (no Markdown): \
+{\"candidates\": [{\"type\": \"<id>\", \"score\": <0.0-1.0>}]}. \
+List at most 3 types that fit, ordered from most to least likely, using only the ids above.",
+    );
+    hardened_data_parts(&task, value)
+}
+
+#[derive(Deserialize)]
+struct LlmClassifyOutput {
+    #[serde(default)]
+    candidates: Vec<LlmCandidate>,
+}
+
+#[derive(Deserialize)]
+struct LlmCandidate {
+    #[serde(rename = "type")]
+    input_type: String,
+    #[serde(default)]
+    score: f64,
+}
+
+/// Mem-parse keluaran LLM menjadi kandidat. Id yang tidak dikenal diabaikan.
+pub fn parse_classify_output(text: &str) -> Result<Vec<TypeCandidate>, ValidationError> {
+    let output: LlmClassifyOutput = serde_json::from_str(clean_json_markdown(text))
+        .map_err(|e| ValidationError::malformed(text, format!("Keluaran klasifikasi tidak valid. Error: {}", e)))?;
+
+    Ok(output
+        .candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let input_type = InputType::ALL
+                .into_iter()
+                .find(|input_type| input_type.as_str() == candidate.input_type)
+                .or_else(|| input_type_for(&candidate.input_type))?;
+            Some(TypeCandidate {
+                input_type,
+                score: candidate.score.clamp(0.0, 1.0),
+                source: DetectionSource::Llm,
+            })
+        })
+        .collect())
+}
+
+// Menggabungkan kandidat lokal dan LLM: satu entri per tipe dengan skor tertinggi.
+fn merge_candidates(local: Vec<TypeCandidate>, llm: Vec<TypeCandidate>) -> Vec<TypeCandidate> {
+    let mut merged: Vec<TypeCandidate> = Vec::new();
+    for candidate in local.into_iter().chain(llm) {
+        match merged.iter_mut().find(|existing| existing.input_type == candidate.input_type) {
+            Some(existing) if existing.score < candidate.score => *existing = candidate,
+            Some(_) => {}
+            None => merged.push(candidate),
+        }
+    }
+    merged.sort_by(|a, b| b.score.total_cmp(&a.score));
+    merged.truncate(MAX_CANDIDATES);
+    merged
+}
+
+fn build_classification(
+    candidates: Vec<TypeCandidate>,
+    label: Option<&str>,
+    locale: Locale,
+    model: Option<String>,
+) -> Classification {
+    let label_mismatch = label.and_then(|label| find_label_mismatch(label, &candidates, locale));
+    Classification { candidates, label_mismatch, model }
+}
+
+// Kandidat dari keluaran LLM; jawaban yang hanya disalin dari nilai itu sendiri diabaikan.
+fn llm_candidates(value: &str, text: &str) -> Result<Vec<TypeCandidate>, ValidationError> {
+    if is_echoed_answer(value, text, "candidates") {
+        tracing::warn!("model menyalin jawaban klasifikasi dari nilai, kandidat LLM diabaikan");
+        return Ok(Vec::new());
+    }
+    parse_classify_output(text)
+}
+
+fn is_confident(candidates: &[TypeCandidate]) -> bool {
+    candidates.first().is_some_and(|candidate| candidate.score >= CONFIDENT_SCORE)
+}
+
+fn ensure_value(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(ValidationError::Config("Nilai yang diklasifikasi tidak boleh kosong.".to_string()));
+    }
+    Ok(())
+}
+
+impl SemanticValidator {
+    /// Menebak tipe `value` memakai model bawaan validator. Jika `label` diberikan,
+    /// hasilnya juga menandai nilai yang jelas tidak cocok dengan label tersebut.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn classify_input_sync(&self, value: &str, label: Option<&str>) -> Result<Classification, ValidationError> {
+        self.classify_input_with_model_sync(value, label, self.default_model())
+    }
+
+    /// Detektor lokal dipakai lebih dulu; LLM hanya ditanya bila tidak ada kandidat
+    /// dengan skor minimal [`CONFIDENT_SCORE`].
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn classify_input_with_model_sync(
+        &self,
+        value: &str,
+        label: Option<&str>,
+        model: SupportedModel,
+    ) -> Result<Classification, ValidationError> {
+        ensure_value(value)?;
+        let local = detect_types(value);
+        if is_confident(&local) || self.is_injection(value) {
+            return Ok(build_classification(local, label, self.locale(), None));
+        }
+
+        let parts = format_classify_prompt(value.trim());
+        let (text, answered_by) = self.complete_with_fallback_sync(Completion::parts(&parts), model.as_str())?;
+        let candidates = merge_candidates(local, llm_candidates(value, &text)?);
+        Ok(build_classification(candidates, label, self.locale(), Some(answered_by.to_string())))
+    }
+
+    /// Versi asinkron dari `classify_input_sync`.
+    pub async fn classify_input_async(
+        &self,
+        value: &str,
+        label: Option<&str>,
+    ) -> Result<Classification, ValidationError> {
+        self.classify_input_with_model_async(value, label, self.default_model()).await
+    }
+
+    /// Versi asinkron dari `classify_input_with_model_sync`.
+    pub async fn classify_input_with_model_async(
+        &self,
+        value: &str,
+        label: Option<&str>,
+        model: SupportedModel,
+    ) -> Result<Classification, ValidationError> {
+        ensure_value(value)?;
+        let local = detect_types(value);
+        if is_confident(&local) || self.is_injection(value) {
+            return Ok(build_classification(local, label, self.locale(), None));
+        }
+
+        let parts = format_classify_prompt(value.trim());
+        let (text, answered_by) = self.complete_with_fallback_async(Completion::parts(&parts), model.as_str()).await?;
+        let candidates = merge_candidates(local, llm_candidates(value, &text)?);
+        Ok(build_classification(candidates, label, self.locale(), Some(answered_by.to_string())))
+    }
+}
@@ -0,0 +1,191 @@
+// src/core/error.rs
+// Tipe error terstruktur untuk seluruh API inti.
+
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Pengganti untuk nilai rahasia yang dihapus dari pesan error.
+pub const REDACTED: &str = "<disamarkan>";
+
+// Parameter query yang lazim membawa kredensial (`?key=...`, `&access_token=...`).
+static SECRET_QUERY_PARAM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)([?&](?:key|api_?key|access_token|token|sig|signature)=)[^&#\s'\x22)]+").unwrap()
+});
+
+/// Error yang dikembalikan oleh API validasi.
+///
+/// Setiap varian punya kode stabil (lihat [`ValidationError::code`]) yang dipakai
+/// binding untuk memetakan error ke kelas/varian yang sesuai di bahasa target.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ValidationError {
+    /// Kuota/rate limit model tercapai (HTTP 429).
+    #[error("Model '{model}' tidak dapat digunakan saat ini karena telah mencapai batas penggunaan (limit).{}", detail_suffix(message))]
+    RateLimited {
+        model: String,
+        retry_after: Option<Duration>,
+        message: String,
+    },
+
+    /// API key tidak valid atau tidak punya akses (HTTP 401/403, atau `API_KEY_INVALID`).
+    #[error("API key ditolak oleh server (kode {status}).{}", detail_suffix(message))]
+    Unauthorized { status: u16, message: String },
+
+    /// Status HTTP gagal lain yang tidak punya varian khusus.
+    #[error("Server merespons dengan kode: {status}.{}", detail_suffix(body))]
+    Http { status: u16, body: String },
+
+    /// Gagal mengirim request atau membaca respons (DNS, koneksi, TLS, dll).
+    #[error("Gagal menghubungi server LLM: {0}")]
+    Transport(String),
+
+    /// Request melewati batas waktu.
+    #[error("Request ke server LLM melewati batas waktu: {0}")]
+    Timeout(String),
+
+    /// Keluaran model tidak bisa dibaca sebagai `ValidationResponse`.
+    #[error("Keluaran model tidak valid: {reason}. Model output: '{raw}'")]
+    ModelOutputMalformed { raw: String, reason: String },
+
+    /// Keluaran model berupa JSON tetapi melanggar `responseSchema` yang diminta.
+    #[error("Keluaran model melanggar skema respons: {reason}. Model output: '{raw}'")]
+    SchemaViolation { raw: String, reason: String },
+
+    /// Respons diblokir oleh filter keamanan provider.
+    #[error("Respons diblokir oleh filter keamanan: {reason}")]
+    Blocked { reason: String },
+
+    /// Konfigurasi validator tidak lengkap atau tidak valid.
+    #[error("Konfigurasi tidak valid: {0}")]
+    Config(String),
+}
+
+impl ValidationError {
+    /// Kode error stabil yang dapat dicocokkan pemanggil tanpa membaca pesan.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationError::RateLimited { .. } => "rate_limited",
+            ValidationError::Unauthorized { .. } => "unauthorized",
+            ValidationError::Http { .. } => "http",
+            ValidationError::Transport(_) => "transport",
+            ValidationError::Timeout(_) => "timeout",
+            ValidationError::ModelOutputMalformed { .. } => "model_output_malformed",
+            ValidationError::SchemaViolation { .. } => "schema_violation",
+            ValidationError::Blocked { .. } => "blocked",
+            ValidationError::Config(_) => "config",
+        }
+    }
+
+    /// Status HTTP terkait error ini, jika ada.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ValidationError::RateLimited { .. } => Some(429),
+            ValidationError::Unauthorized { status, .. } | ValidationError::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Jeda yang disarankan server sebelum mencoba lagi, jika ada.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ValidationError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Apakah error menandakan model sedang tidak tersedia (kuota habis, overload,
+    /// atau model tidak ditemukan), sehingga layak berpindah ke model cadangan.
+    pub fn is_model_unavailable(&self) -> bool {
+        match self {
+            ValidationError::RateLimited { .. } => true,
+            ValidationError::Http { status, .. } => matches!(status, 404 | 429 | 500 | 502 | 503 | 504),
+            _ => false,
+        }
+    }
+
+    /// Salinan error dengan semua teks bebas (pesan, body, keluaran model) yang
+    /// sudah dibersihkan dari `secret` dan parameter query berisi kredensial.
+    ///
+    /// Validator memanggil ini untuk setiap error provider, sehingga API key
+    /// tidak pernah sampai ke pesan error, log, atau exception di binding.
+    pub fn redacted(self, secret: &str) -> Self {
+        let clean = |text: String| redact_secrets(&text, secret);
+        match self {
+            ValidationError::RateLimited { model, retry_after, message } => ValidationError::RateLimited {
+                model,
+                retry_after,
+                message: clean(message),
+            },
+            ValidationError::Unauthorized { status, message } => ValidationError::Unauthorized {
+                status,
+                message: clean(message),
+            },
+            ValidationError::Http { status, body } => ValidationError::Http { status, body: clean(body) },
+            ValidationError::Transport(message) => ValidationError::Transport(clean(message)),
+            ValidationError::Timeout(message) => ValidationError::Timeout(clean(message)),
+            ValidationError::ModelOutputMalformed { raw, reason } => ValidationError::ModelOutputMalformed {
+                raw: clean(raw),
+                reason: clean(reason),
+            },
+            ValidationError::SchemaViolation { raw, reason } => ValidationError::SchemaViolation {
+                raw: clean(raw),
+                reason: clean(reason),
+            },
+            ValidationError::Blocked { reason } => ValidationError::Blocked { reason: clean(reason) },
+            ValidationError::Config(message) => ValidationError::Config(clean(message)),
+        }
+    }
+
+    pub(crate) fn malformed(raw: &str, reason: impl Into<String>) -> Self {
+        ValidationError::ModelOutputMalformed {
+            raw: raw.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    pub(crate) fn schema_violation(raw: &str, reason: impl Into<String>) -> Self {
+        ValidationError::SchemaViolation {
+            raw: raw.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Menghapus `secret` (jika tidak kosong) dan nilai parameter query berisi
+/// kredensial dari `text`.
+pub fn redact_secrets(text: &str, secret: &str) -> String {
+    let text = SECRET_QUERY_PARAM.replace_all(text, format!("${{1}}{}", REDACTED));
+    if secret.is_empty() {
+        text.into_owned()
+    } else {
+        text.replace(secret, REDACTED)
+    }
+}
+
+impl From<reqwest::Error> for ValidationError {
+    fn from(mut e: reqwest::Error) -> Self {
+        // `Display` milik reqwest menyertakan URL lengkap; buang query dan
+        // userinfo agar kredensial di URL tidak ikut ke pesan error.
+        if let Some(url) = e.url_mut() {
+            url.set_query(None);
+            url.set_fragment(None);
+            let _ = url.set_username("");
+            let _ = url.set_password(None);
+        }
+        let message = redact_secrets(&e.to_string(), "");
+        if e.is_timeout() {
+            ValidationError::Timeout(message)
+        } else {
+            ValidationError::Transport(message)
+        }
+    }
+}
+
+fn detail_suffix(detail: &str) -> String {
+    if detail.is_empty() {
+        String::new()
+    } else {
+        format!(" Pesan: {}", detail)
+    }
+}
@@ -0,0 +1,445 @@
+// src/core/file_cache.rs
+// Cache hasil validasi persisten berbasis file JSONL append-only (khusus native).
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::cache::{CacheKey, CacheStats};
+use crate::models::ValidationResponse;
+
+// Versi format file; dinaikkan jika bentuk baris berubah.
+const FORMAT_VERSION: u32 = 1;
+// Batas tunggu untuk mendapatkan lockfile sebelum menyerah.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+// Lock yang pemegangnya tidak bisa diperiksa dianggap basi setelah selama ini: isinya
+// tidak terbaca (proses mati sebelum sempat menulis PID), atau OS-nya tidak mendukung
+// pemeriksaan PID (di luar Unix). Di Unix lock dengan PID hanya dibongkar jika prosesnya sudah tidak ada.
+const UNVERIFIABLE_LOCK_AGE: Duration = Duration::from_secs(30);
+
+/// Baris pertama file: menandai format dan "generasi" file.
+/// Generasi berganti setiap kali file ditulis ulang (kompaksi/clear), sehingga
+/// proses lain tahu indeksnya harus dimuat ulang dari awal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileHeader {
+    validation_semantic_cache: u32,
+    generation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileRecord {
+    key: CacheKey,
+    response: ValidationResponse,
+    /// Waktu UNIX (milidetik) saat entri kedaluwarsa; `None` berarti tidak pernah.
+    expires_at_ms: Option<u64>,
+}
+
+struct FileState {
+    generation: String,
+    // Posisi byte yang sudah dibaca; baris setelahnya ditulis proses lain.
+    offset: u64,
+    entries: HashMap<CacheKey, FileRecord>,
+    hits: u64,
+    misses: u64,
+}
+
+/// Cache hasil validasi yang bertahan antar proses dan antar run.
+///
+/// Disimpan sebagai satu file JSONL append-only memakai [`CacheKey`] yang sama dengan
+/// cache di memori. Penulisan dan kompaksi dilindungi lockfile (`<path>.lock`) berisi PID
+/// pemegangnya; lock hanya dibongkar jika proses itu sudah tidak ada. Selain itu
+/// setiap instance membaca baris baru dari proses lain saat terjadi cache miss, sehingga
+/// beberapa proses boleh berbagi file yang sama.
+pub struct FileCache {
+    path: PathBuf,
+    ttl: Option<Duration>,
+    state: Mutex<FileState>,
+}
+
+impl FileCache {
+    /// Membuka (atau membuat) file cache. `ttl` berlaku untuk entri yang ditulis instance ini.
+    pub fn open(path: impl AsRef<Path>, ttl: Option<Duration>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+
+        let cache = Self {
+            path,
+            ttl,
+            state: Mutex::new(FileState {
+                generation: String::new(),
+                offset: 0,
+                entries: HashMap::new(),
+                hits: 0,
+                misses: 0,
+            }),
+        };
+
+        {
+            let _lock = cache.acquire_lock()?;
+            let is_empty = fs::metadata(&cache.path).map(|m| m.len() == 0).unwrap_or(true);
+            if is_empty {
+                cache.rewrite(&[])?;
+            }
+        }
+        cache.refresh(&mut cache.lock_state())?;
+        Ok(cache)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<ValidationResponse> {
+        let mut state = self.lock_state();
+        let now = unix_now_ms();
+
+        let mut found = live_response(&state, key, now);
+        if found.is_none() {
+            // Mungkin sudah ditulis proses lain sejak pembacaan terakhir.
+            if let Err(e) = self.refresh(&mut state) {
+                tracing::warn!(path = %self.path.display(), error = %e, "gagal membaca file cache");
+            }
+            found = live_response(&state, key, now);
+        }
+
+        match found {
+            Some(response) => {
+                state.hits += 1;
+                Some(response)
+            }
+            None => {
+                state.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub fn insert(&self, key: CacheKey, response: ValidationResponse) -> io::Result<()> {
+        let record = FileRecord {
+            key,
+            response,
+            expires_at_ms: self.ttl.map(|ttl| unix_now_ms().saturating_add(ttl.as_millis() as u64)),
+        };
+        let mut line = serde_json::to_string(&record).map_err(io::Error::other)?;
+        line.push('\n');
+
+        {
+            let _lock = self.acquire_lock()?;
+            let mut file = OpenOptions::new().create(true).read(true).append(true).open(&self.path)?;
+            let len = file.metadata()?.len();
+            if len == 0 {
+                // File dihapus dari luar: mulai generasi baru.
+                file.write_all(header_line(&new_generation())?.as_bytes())?;
+            } else if !ends_with_newline(&mut file, len)? {
+                // Baris terakhir terpotong (proses mati saat menulis): tutup dulu agar
+                // baris baru tidak menempel pada baris rusak itu.
+                line.insert(0, '\n');
+            }
+            // Satu `write_all` per baris agar tidak terselip tulisan proses lain.
+            file.write_all(line.as_bytes())?;
+        }
+
+        self.lock_state().entries.insert(record.key.clone(), record);
+        Ok(())
+    }
+
+    /// Menulis ulang file hanya dengan entri terbaru yang belum kedaluwarsa.
+    /// Mengembalikan jumlah entri yang tersisa.
+    pub fn compact(&self) -> io::Result<usize> {
+        let mut state = self.lock_state();
+        let _lock = self.acquire_lock()?;
+
+        // Muat ulang penuh di bawah lock agar baris dari proses lain ikut terbawa.
+        state.generation.clear();
+        self.refresh(&mut state)?;
+
+        let now = unix_now_ms();
+        let live: Vec<FileRecord> = state
+            .entries
+            .values()
+            .filter(|record| !is_expired(record, now))
+            .cloned()
+            .collect();
+
+        self.rewrite(&live)?;
+        state.generation.clear();
+        self.refresh(&mut state)?;
+        Ok(live.len())
+    }
+
+    /// Menghapus semua entri dari file dan mengatur ulang statistik.
+    pub fn clear(&self) -> io::Result<()> {
+        let mut state = self.lock_state();
+        let _lock = self.acquire_lock()?;
+        self.rewrite(&[])?;
+
+        state.generation.clear();
+        state.hits = 0;
+        state.misses = 0;
+        self.refresh(&mut state)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let state = self.lock_state();
+        let now = unix_now_ms();
+        CacheStats {
+            hits: state.hits,
+            misses: state.misses,
+            entries: state.entries.values().filter(|r| !is_expired(r, now)).count(),
+            // File cache tidak dibatasi jumlah entri; ukurannya dikendalikan lewat TTL dan kompaksi.
+            capacity: 0,
+        }
+    }
+
+    // Membaca baris baru sejak `offset`. Jika generasi file berubah (ditulis ulang
+    // oleh proses lain), indeks dibuang dan file dibaca dari awal.
+    fn refresh(&self, state: &mut FileState) -> io::Result<()> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                state.entries.clear();
+                state.generation.clear();
+                state.offset = 0;
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        let len = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+
+        let mut first_line = String::new();
+        let header_len = reader.read_line(&mut first_line)? as u64;
+        if !first_line.ends_with('\n') {
+            // Header belum selesai ditulis; coba lagi di pemanggilan berikutnya.
+            return Ok(());
+        }
+        let header: FileHeader = serde_json::from_str(first_line.trim_end())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if header.validation_semantic_cache != FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Versi format file cache tidak didukung: {}", header.validation_semantic_cache),
+            ));
+        }
+
+        if header.generation != state.generation || len < state.offset {
+            state.entries.clear();
+            state.generation = header.generation;
+            state.offset = header_len;
+        }
+
+        reader.seek(SeekFrom::Start(state.offset))?;
+        let mut buffer = String::new();
+        reader.read_to_string(&mut buffer)?;
+
+        // Hanya baris lengkap yang diproses; baris terakhir bisa saja masih ditulis.
+        let complete = buffer.rfind('\n').map_or(0, |i| i + 1);
+        for line in buffer[..complete].lines().filter(|l| !l.trim().is_empty()) {
+            // Baris rusak (misalnya proses mati saat menulis) dilewati.
+            if let Ok(record) = serde_json::from_str::<FileRecord>(line) {
+                state.entries.insert(record.key.clone(), record);
+            }
+        }
+        state.offset += complete as u64;
+        Ok(())
+    }
+
+    // Menulis file baru (header + entri) ke file sementara lalu me-rename-nya
+    // secara atomik. Pemanggil wajib memegang lockfile.
+    fn rewrite(&self, records: &[FileRecord]) -> io::Result<()> {
+        let tmp_path = self.sibling_path(".tmp");
+        {
+            let mut tmp = File::create(&tmp_path)?;
+            let mut content = header_line(&new_generation())?;
+            for record in records {
+                content.push_str(&serde_json::to_string(record).map_err(io::Error::other)?);
+                content.push('\n');
+            }
+            tmp.write_all(content.as_bytes())?;
+            tmp.sync_all()?;
+        }
+        fs::rename(&tmp_path, &self.path)
+    }
+
+    fn acquire_lock(&self) -> io::Result<LockGuard> {
+        let lock_path = self.sibling_path(".lock");
+        let deadline = Instant::now() + LOCK_TIMEOUT;
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&lock_path) {
+                Ok(mut file) => {
+                    file.write_all(LockOwner::current().to_line().as_bytes())?;
+                    return Ok(LockGuard { path: lock_path });
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    if let Some(content) = stale_lock_content(&lock_path) {
+                        // Hapus hanya jika lock belum diambil alih proses lain sejak dibaca.
+                        if fs::read_to_string(&lock_path).is_ok_and(|now| now == content) {
+                            tracing::warn!(path = %lock_path.display(), "membongkar lock file cache milik proses yang sudah mati");
+                            let _ = fs::remove_file(&lock_path);
+                        }
+                        continue;
+                    }
+                    if Instant::now() >= deadline {
+                        let holder = fs::read_to_string(&lock_path)
+                            .ok()
+                            .and_then(|content| LockOwner::parse(&content))
+                            .map(|owner| format!(" (dipegang PID {} sejak {} ms UNIX)", owner.pid, owner.acquired_at_ms))
+                            .unwrap_or_default();
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            format!("Timeout menunggu lock file cache '{}'{}", lock_path.display(), holder),
+                        ));
+                    }
+                    thread::sleep(Duration::from_millis(5));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn sibling_path(&self, suffix: &str) -> PathBuf {
+        let mut name = OsString::from(self.path.as_os_str());
+        name.push(suffix);
+        PathBuf::from(name)
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, FileState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Isi lockfile: PID pemegang dan waktu lock diambil, dalam satu baris `"<pid> <ms>"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LockOwner {
+    pid: u32,
+    acquired_at_ms: u64,
+}
+
+impl LockOwner {
+    fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            acquired_at_ms: unix_now_ms(),
+        }
+    }
+
+    fn to_line(self) -> String {
+        format!("{} {}\n", self.pid, self.acquired_at_ms)
+    }
+
+    fn age(&self) -> Duration {
+        Duration::from_millis(unix_now_ms().saturating_sub(self.acquired_at_ms))
+    }
+
+    fn parse(content: &str) -> Option<Self> {
+        let mut fields = content.split_whitespace();
+        let pid = fields.next()?.parse().ok()?;
+        let acquired_at_ms = fields.next()?.parse().ok()?;
+        Some(Self { pid, acquired_at_ms })
+    }
+}
+
+struct LockGuard {
+    path: PathBuf,
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn live_response(state: &FileState, key: &CacheKey, now: u64) -> Option<ValidationResponse> {
+    state
+        .entries
+        .get(key)
+        .filter(|record| !is_expired(record, now))
+        .map(|record| record.response.clone())
+}
+
+fn is_expired(record: &FileRecord, now: u64) -> bool {
+    record.expires_at_ms.is_some_and(|at| now >= at)
+}
+
+// Isi lockfile jika lock boleh dibongkar: pemegangnya sudah tidak hidup, atau pemegangnya
+// tidak bisa diperiksa dan lock sudah cukup lama. Di Unix lock milik proses yang masih
+// berjalan (misalnya sedang kompaksi panjang) tidak pernah dibongkar, berapa pun umurnya.
+fn stale_lock_content(lock_path: &Path) -> Option<String> {
+    let content = fs::read_to_string(lock_path).ok()?;
+    let stale = match LockOwner::parse(&content) {
+        Some(owner) => match process_is_alive(owner.pid) {
+            Some(alive) => !alive,
+            None => owner.age() > UNVERIFIABLE_LOCK_AGE,
+        },
+        None => fs::metadata(lock_path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > UNVERIFIABLE_LOCK_AGE),
+    };
+    stale.then_some(content)
+}
+
+// `Some(true)` jika proses `pid` masih ada, `None` jika tidak bisa diperiksa.
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> Option<bool> {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return Some(false);
+    };
+    // Sinyal 0 hanya memeriksa keberadaan proses. EPERM berarti proses ada tetapi milik user lain.
+    let alive = unsafe { libc::kill(pid, 0) } == 0;
+    Some(alive || io::Error::last_os_error().raw_os_error() != Some(libc::ESRCH))
+}
+
+// Di luar Unix keberadaan proses tidak bisa diperiksa tanpa dependensi tambahan,
+// jadi lock dengan PID dinilai dari umurnya (lihat `UNVERIFIABLE_LOCK_AGE`).
+#[cfg(not(unix))]
+fn process_is_alive(_pid: u32) -> Option<bool> {
+    None
+}
+
+// `true` jika byte terakhir file adalah `\n`.
+fn ends_with_newline(file: &mut File, len: u64) -> io::Result<bool> {
+    let mut last = [0u8; 1];
+    file.seek(SeekFrom::Start(len - 1))?;
+    file.read_exact(&mut last)?;
+    Ok(last[0] == b'\n')
+}
+
+fn header_line(generation: &str) -> io::Result<String> {
+    let header = FileHeader {
+        validation_semantic_cache: FORMAT_VERSION,
+        generation: generation.to_string(),
+    };
+    let mut line = serde_json::to_string(&header).map_err(io::Error::other)?;
+    line.push('\n');
+    Ok(line)
+}
+
+fn new_generation() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("{:x}-{:x}", nanos, std::process::id())
+}
+
+fn unix_now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
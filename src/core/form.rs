@@ -0,0 +1,749 @@
+// src/core/form.rs
+// Pemeriksaan konsistensi antar-field pada satu formulir.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::core_logic::clean_json_markdown;
+use crate::core::error::ValidationError;
+use crate::core::injection::{field_reference, hardened_field_parts, is_echoed_answer, PromptParts};
+use crate::core::locale::{LocalMessage, Locale};
+use crate::core::validator::{Completion, SemanticValidator};
+use crate::models::SupportedModel;
+
+/// Satu field formulir: nilai dan tipe inputnya.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormField {
+    pub value: String,
+    #[serde(alias = "inputType")]
+    pub input_type: String,
+}
+
+/// Jenis hubungan antar-field yang diperiksa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationshipKind {
+    /// `[tanggal_lahir, umur]`: umur harus sesuai tanggal lahir.
+    BirthDateAge,
+    /// `[kota, kode_pos]`: kode pos harus berada di kota tersebut.
+    CityPostalCode,
+    /// `[email, nama_perusahaan]`: domain email harus milik perusahaan tersebut.
+    EmailDomainCompany,
+    /// Hubungan bebas yang dijelaskan lewat `description`; selalu diperiksa oleh LLM.
+    Custom,
+}
+
+impl RelationshipKind {
+    /// Kebalikan dari [`RelationshipKind::as_str`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "birth_date_age" => Some(RelationshipKind::BirthDateAge),
+            "city_postal_code" => Some(RelationshipKind::CityPostalCode),
+            "email_domain_company" => Some(RelationshipKind::EmailDomainCompany),
+            "custom" => Some(RelationshipKind::Custom),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationshipKind::BirthDateAge => "birth_date_age",
+            RelationshipKind::CityPostalCode => "city_postal_code",
+            RelationshipKind::EmailDomainCompany => "email_domain_company",
+            RelationshipKind::Custom => "custom",
+        }
+    }
+}
+
+/// Hubungan yang dideklarasikan antar field. Urutan `fields` bermakna untuk jenis bawaan
+/// (lihat [`RelationshipKind`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    pub kind: RelationshipKind,
+    pub fields: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Relationship {
+    pub fn birth_date_age(birth_date_field: impl Into<String>, age_field: impl Into<String>) -> Self {
+        Self::pair(RelationshipKind::BirthDateAge, birth_date_field.into(), age_field.into())
+    }
+
+    pub fn city_postal_code(city_field: impl Into<String>, postal_code_field: impl Into<String>) -> Self {
+        Self::pair(RelationshipKind::CityPostalCode, city_field.into(), postal_code_field.into())
+    }
+
+    pub fn email_domain_company(email_field: impl Into<String>, company_field: impl Into<String>) -> Self {
+        Self::pair(RelationshipKind::EmailDomainCompany, email_field.into(), company_field.into())
+    }
+
+    pub fn custom(fields: impl IntoIterator<Item = impl Into<String>>, description: impl Into<String>) -> Self {
+        Self {
+            kind: RelationshipKind::Custom,
+            fields: fields.into_iter().map(Into::into).collect(),
+            description: Some(description.into()),
+        }
+    }
+
+    fn pair(kind: RelationshipKind, first: String, second: String) -> Self {
+        Self {
+            kind,
+            fields: vec![first, second],
+            description: None,
+        }
+    }
+
+    // Penjelasan hubungan untuk prompt LLM.
+    fn prompt_description(&self, today: (i32, u32, u32)) -> String {
+        let field = |i: usize| self.fields.get(i).map(String::as_str).unwrap_or_default();
+        let base = match self.kind {
+            RelationshipKind::BirthDateAge => format!(
+                "the age in \"{}\" must match the birth date in \"{}\" as of today ({:04}-{:02}-{:02})",
+                field(1),
+                field(0),
+                today.0,
+                today.1,
+                today.2
+            ),
+            RelationshipKind::CityPostalCode => format!(
+                "the postal code in \"{}\" must belong to the city/area in \"{}\"",
+                field(1),
+                field(0)
+            ),
+            RelationshipKind::EmailDomainCompany => format!(
+                "the email in \"{}\" should be a work address of the company in \"{}\" (personal/free email providers or another company's domain are inconsistent)",
+                field(0),
+                field(1)
+            ),
+            RelationshipKind::Custom => format!("fields {:?} must be consistent", self.fields),
+        };
+        match &self.description {
+            Some(description) => format!("{}; {}", base, description),
+            None => base,
+        }
+    }
+}
+
+/// Formulir yang diperiksa: field bernama beserta hubungan antar field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormInput {
+    pub fields: BTreeMap<String, FormField>,
+    #[serde(default)]
+    pub relationships: Vec<Relationship>,
+}
+
+impl FormInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>, input_type: impl Into<String>) -> Self {
+        self.fields.insert(
+            name.into(),
+            FormField {
+                value: value.into(),
+                input_type: input_type.into(),
+            },
+        );
+        self
+    }
+
+    pub fn relationship(mut self, relationship: Relationship) -> Self {
+        self.relationships.push(relationship);
+        self
+    }
+}
+
+/// Asal sebuah temuan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingSource {
+    /// Pemeriksaan lokal tanpa LLM.
+    Local,
+    /// Pemeriksaan konsistensi oleh LLM.
+    Llm,
+}
+
+impl FindingSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FindingSource::Local => "local",
+            FindingSource::Llm => "llm",
+        }
+    }
+}
+
+/// Satu ketidakkonsistenan antara beberapa field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsistencyFinding {
+    pub fields: Vec<String>,
+    /// Jenis hubungan yang dilanggar, jika diketahui.
+    pub relationship: Option<RelationshipKind>,
+    pub message: String,
+    pub source: FindingSource,
+}
+
+/// Hasil pemeriksaan formulir.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormReport {
+    pub consistent: bool,
+    /// Semua temuan tingkat formulir.
+    pub findings: Vec<ConsistencyFinding>,
+    /// Pesan temuan per field; setiap field formulir punya entri (kosong jika tidak bermasalah).
+    pub field_findings: BTreeMap<String, Vec<String>>,
+    /// Model yang menjalankan pemeriksaan LLM (`None` jika semua diputuskan secara lokal).
+    pub model: Option<String>,
+}
+
+/// Hasil pemeriksaan lokal satu hubungan.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocalCheck {
+    Consistent,
+    Inconsistent(LocalMessage),
+    /// Tidak bisa diputuskan secara lokal; diteruskan ke LLM.
+    Undecided,
+}
+
+/// Tanggal hari ini (UTC) sebagai `(tahun, bulan, hari)`.
+pub fn today() -> (i32, u32, u32) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        civil_from_days((secs / 86_400) as i64)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let now = js_sys::Date::new_0();
+        (now.get_utc_full_year() as i32, now.get_utc_month() + 1, now.get_utc_date())
+    }
+}
+
+/// Menjalankan pemeriksaan lokal untuk satu hubungan pada formulir.
+pub fn check_locally(relationship: &Relationship, form: &FormInput, today: (i32, u32, u32)) -> LocalCheck {
+    let value = |i: usize| {
+        relationship
+            .fields
+            .get(i)
+            .and_then(|name| form.fields.get(name))
+            .map(|field| field.value.trim())
+            .unwrap_or_default()
+    };
+    match relationship.kind {
+        RelationshipKind::BirthDateAge => check_birth_date_age(value(0), value(1), today),
+        RelationshipKind::CityPostalCode => check_city_postal_code(value(0), value(1)),
+        RelationshipKind::EmailDomainCompany => check_email_domain_company(value(0), value(1)),
+        RelationshipKind::Custom => LocalCheck::Undecided,
+    }
+}
+
+fn check_birth_date_age(birth_date: &str, age: &str, today: (i32, u32, u32)) -> LocalCheck {
+    let (Some(birth), Some(stated_age)) = (parse_date(birth_date), parse_age(age)) else {
+        return LocalCheck::Undecided;
+    };
+    if birth > today {
+        return LocalCheck::Inconsistent(LocalMessage::BirthDateInFuture {
+            birth_date: birth_date.to_string(),
+        });
+    }
+
+    let mut actual_age = today.0 - birth.0;
+    if (today.1, today.2) < (birth.1, birth.2) {
+        actual_age -= 1;
+    }
+    // Toleransi satu tahun untuk perbedaan tanggal pengisian dan zona waktu.
+    if (actual_age - stated_age as i32).abs() > 1 {
+        return LocalCheck::Inconsistent(LocalMessage::AgeMismatch {
+            age: stated_age,
+            birth_date: birth_date.to_string(),
+            actual_age,
+        });
+    }
+    LocalCheck::Consistent
+}
+
+// Prefiks dua digit kode pos untuk kota-kota besar.
+const CITY_POSTAL_PREFIXES: &[(&str, &[&str])] = &[
+    ("jakarta", &["10", "11", "12", "13", "14"]),
+    ("bogor", &["16"]),
+    ("depok", &["16"]),
+    ("tangerang", &["15"]),
+    ("bekasi", &["17"]),
+    ("bandung", &["40"]),
+    ("cimahi", &["40"]),
+    ("semarang", &["50"]),
+    ("yogyakarta", &["55"]),
+    ("jogja", &["55"]),
+    ("surabaya", &["60"]),
+    ("sidoarjo", &["61"]),
+    ("malang", &["65"]),
+    ("denpasar", &["80"]),
+    ("medan", &["20"]),
+    ("palembang", &["30"]),
+    ("padang", &["25"]),
+    ("pekanbaru", &["28"]),
+    ("balikpapan", &["76"]),
+    ("makassar", &["90"]),
+];
+
+fn check_city_postal_code(city: &str, postal_code: &str) -> LocalCheck {
+    if city.is_empty() || postal_code.is_empty() {
+        return LocalCheck::Undecided;
+    }
+    let digits: String = postal_code.chars().filter(|c| !c.is_whitespace()).collect();
+    if digits.len() != 5 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return LocalCheck::Inconsistent(LocalMessage::PostalCodeFormat {
+            postal_code: postal_code.to_string(),
+        });
+    }
+
+    let city_lower = city.to_lowercase();
+    let words: Vec<&str> = city_lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let known: Vec<&(&str, &[&str])> = CITY_POSTAL_PREFIXES
+        .iter()
+        .filter(|(name, _)| words.contains(name))
+        .collect();
+
+    // Hanya diputuskan lokal jika tepat satu kota dikenal yang disebut.
+    match known.as_slice() {
+        [(_, prefixes)] if prefixes.iter().any(|p| digits.starts_with(p)) => LocalCheck::Consistent,
+        [(name, _)] => LocalCheck::Inconsistent(LocalMessage::PostalCodeRegion {
+            postal_code: digits,
+            city: capitalize(name),
+        }),
+        _ => LocalCheck::Undecided,
+    }
+}
+
+const FREE_MAIL_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "googlemail.com",
+    "yahoo.com",
+    "yahoo.co.id",
+    "ymail.com",
+    "hotmail.com",
+    "outlook.com",
+    "live.com",
+    "icloud.com",
+    "aol.com",
+    "proton.me",
+    "protonmail.com",
+    "mail.com",
+    "gmx.com",
+];
+
+// Bentuk badan usaha dan kata umum yang tidak membedakan satu perusahaan dari yang lain.
+const COMPANY_STOP_WORDS: &[&str] = &[
+    "pt", "cv", "tbk", "persero", "ud", "pd", "fa", "inc", "ltd", "llc", "corp", "co", "company", "the", "dan", "and",
+];
+const COMPANY_GENERIC_WORDS: &[&str] = &[
+    "bank", "group", "grup", "global", "indonesia", "international", "internasional", "teknologi", "technology",
+    "solusi", "solution", "solutions", "services", "jasa", "industri", "industries", "holding", "utama", "jaya",
+    "abadi", "sejahtera", "makmur", "sentosa", "nusantara",
+];
+// Label domain yang hanya bagian dari TLD.
+const DOMAIN_SUFFIX_LABELS: &[&str] = &[
+    "com", "co", "id", "net", "org", "or", "ac", "go", "sch", "web", "my", "biz", "info", "io",
+];
+
+fn check_email_domain_company(email: &str, company: &str) -> LocalCheck {
+    let Some((_, domain)) = email.rsplit_once('@') else {
+        return LocalCheck::Undecided;
+    };
+    if company.is_empty() {
+        return LocalCheck::Undecided;
+    }
+    let domain = domain.trim().to_lowercase();
+    if FREE_MAIL_DOMAINS.contains(&domain.as_str()) {
+        return LocalCheck::Inconsistent(LocalMessage::FreeMailDomain {
+            email: email.to_string(),
+            domain,
+            company: company.to_string(),
+        });
+    }
+
+    let labels: Vec<&str> = domain
+        .split('.')
+        .filter(|label| !DOMAIN_SUFFIX_LABELS.contains(label))
+        .collect();
+    let domain_text: String = labels.concat().replace('-', "");
+
+    let company_lower = company.to_lowercase();
+    let words: Vec<&str> = company_lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !COMPANY_STOP_WORDS.contains(w))
+        .collect();
+    let distinctive = words
+        .iter()
+        .any(|w| w.len() >= 3 && !COMPANY_GENERIC_WORDS.contains(w) && domain_text.contains(*w));
+    let acronym: String = words.iter().filter_map(|w| w.chars().next()).collect();
+    let acronym_match = acronym.len() >= 2 && labels.iter().any(|label| label.starts_with(&acronym));
+
+    if distinctive || acronym_match {
+        LocalCheck::Consistent
+    } else {
+        // Domain bisa saja milik grup/merek lain perusahaan; serahkan ke LLM.
+        LocalCheck::Undecided
+    }
+}
+
+fn parse_age(text: &str) -> Option<u32> {
+    let lower = text.trim().to_lowercase();
+    let digits_end = lower.find(|c: char| !c.is_ascii_digit()).unwrap_or(lower.len());
+    let (number, rest) = lower.split_at(digits_end);
+    let rest = rest.trim().trim_end_matches('.');
+    if number.is_empty() || !matches!(rest, "" | "tahun" | "thn" | "th" | "years" | "year" | "yo" | "y") {
+        return None;
+    }
+    number.parse().ok().filter(|age| *age <= 150)
+}
+
+const MONTH_NAMES: &[(&str, u32)] = &[
+    ("januari", 1), ("january", 1), ("jan", 1),
+    ("februari", 2), ("february", 2), ("feb", 2), ("pebruari", 2),
+    ("maret", 3), ("march", 3), ("mar", 3),
+    ("april", 4), ("apr", 4),
+    ("mei", 5), ("may", 5),
+    ("juni", 6), ("june", 6), ("jun", 6),
+    ("juli", 7), ("july", 7), ("jul", 7),
+    ("agustus", 8), ("august", 8), ("agu", 8), ("aug", 8), ("agt", 8),
+    ("september", 9), ("sep", 9), ("sept", 9),
+    ("oktober", 10), ("october", 10), ("okt", 10), ("oct", 10),
+    ("november", 11), ("nov", 11), ("nopember", 11),
+    ("desember", 12), ("december", 12), ("des", 12), ("dec", 12),
+];
+
+/// Mem-parse tanggal `YYYY-MM-DD`, `DD-MM-YYYY` (pemisah `-`, `/`, atau `.`),
+/// atau `17 Agustus 1945` menjadi `(tahun, bulan, hari)`.
+fn parse_date(text: &str) -> Option<(i32, u32, u32)> {
+    let lower = text.trim().to_lowercase();
+    let parts: Vec<&str> = lower
+        .split(|c: char| c == '-' || c == '/' || c == '.' || c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let [a, b, c] = parts.as_slice() else {
+        return None;
+    };
+
+    let (year, month, day) = if a.len() == 4 {
+        (a.parse().ok()?, b.parse().ok()?, c.parse().ok()?)
+    } else {
+        let month = b.parse().ok().or_else(|| {
+            MONTH_NAMES
+                .iter()
+                .find(|(name, _)| name == b)
+                .map(|(_, month)| *month)
+        })?;
+        (c.parse().ok()?, month, a.parse().ok()?)
+    };
+
+    let valid = (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) && year >= 1800;
+    valid.then_some((year, month, day))
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+// Konversi jumlah hari sejak 1970-01-01 ke tanggal kalender (algoritma Howard Hinnant).
+#[cfg(not(target_arch = "wasm32"))]
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+    (year, month, day)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn validate_form(form: &FormInput) -> Result<(), ValidationError> {
+    for relationship in &form.relationships {
+        let expected = match relationship.kind {
+            RelationshipKind::Custom => None,
+            _ => Some(2),
+        };
+        if expected.is_some_and(|n| relationship.fields.len() != n) {
+            return Err(ValidationError::Config(format!(
+                "Hubungan '{}' membutuhkan tepat 2 field.",
+                relationship.kind.as_str()
+            )));
+        }
+        if relationship.kind == RelationshipKind::Custom
+            && (relationship.fields.is_empty() || relationship.description.is_none())
+        {
+            return Err(ValidationError::Config(
+                "Hubungan 'custom' membutuhkan daftar field dan description.".to_string(),
+            ));
+        }
+        if let Some(unknown) = relationship.fields.iter().find(|f| !form.fields.contains_key(*f)) {
+            return Err(ValidationError::Config(format!(
+                "Field '{}' pada hubungan '{}' tidak ada di formulir.",
+                unknown,
+                relationship.kind.as_str()
+            )));
+        }
+    }
+    Ok(())
+}
+
+// Hubungan yang field-nya kosong dilewati: tidak ada yang bisa dibandingkan.
+fn has_values(relationship: &Relationship, form: &FormInput) -> bool {
+    relationship
+        .fields
+        .iter()
+        .all(|name| form.fields.get(name).is_some_and(|field| !field.value.trim().is_empty()))
+}
+
+/// Menyusun prompt pemeriksaan konsistensi untuk hubungan yang belum terputuskan. Aturan dan
+/// daftar hubungan masuk ke instruksi sistem; nilai field dikirim terpisah di bagian user.
+pub fn format_form_prompt(
+    form: &FormInput,
+    relationships: &[&Relationship],
+    today: (i32, u32, u32),
+    locale: Locale,
+) -> PromptParts {
+    let mut task = String::from(
+        "You are checking a form for CROSS-FIELD consistency. Each value may be plausible on its own; \
+report only contradictions BETWEEN fields for the relationships listed below.\n\nFields:\n",
+    );
+    for (name, field) in &form.fields {
+        task.push_str(&format!(
+            "- \"{}\": {} (type: {})\n",
+            name,
+            field_reference(name),
+            serde_json::Value::String(field.input_type.clone())
+        ));
+    }
+    task.push_str("\nRelationships to check:\n");
+    for (i, relationship) in relationships.iter().enumerate() {
+        task.push_str(&format!(
+            "{}. [{}] {}\n",
+            i + 1,
+            relationship.kind.as_str(),
+            relationship.prompt_description(today)
+        ));
+    }
+    task.push_str(&format!(
+        "\nRespond ONLY with a single-line JSON object (no Markdown): \
+{{\"consistent\": true|false, \"findings\": [{{\"relationship\": <number>, \"fields\": [\"<field name>\"], \"message\": \"explanation in {}\"}}]}}. \
+Use an empty findings array when everything is consistent.",
+        locale.language_name()
+    ));
+
+    let fields: Vec<(&str, &str)> =
+        form.fields.iter().map(|(name, field)| (name.as_str(), field.value.as_str())).collect();
+    hardened_field_parts(&task, &fields)
+}
+
+#[derive(Deserialize)]
+struct LlmFormOutput {
+    #[serde(default)]
+    findings: Vec<LlmFinding>,
+}
+
+#[derive(Deserialize)]
+struct LlmFinding {
+    #[serde(default)]
+    relationship: Option<usize>,
+    #[serde(default)]
+    fields: Vec<String>,
+    message: String,
+}
+
+/// Mem-parse keluaran LLM menjadi temuan. `relationships` adalah daftar yang dikirim di prompt.
+pub fn parse_form_output(
+    text: &str,
+    form: &FormInput,
+    relationships: &[&Relationship],
+) -> Result<Vec<ConsistencyFinding>, ValidationError> {
+    let output: LlmFormOutput = serde_json::from_str(clean_json_markdown(text))
+        .map_err(|e| ValidationError::malformed(text, format!("Keluaran pemeriksaan formulir tidak valid. Error: {}", e)))?;
+
+    Ok(output
+        .findings
+        .into_iter()
+        .map(|finding| {
+            let relationship = finding
+                .relationship
+                .and_then(|n| n.checked_sub(1))
+                .and_then(|i| relationships.get(i));
+            let mut fields: Vec<String> = finding
+                .fields
+                .into_iter()
+                .filter(|f| form.fields.contains_key(f))
+                .collect();
+            if fields.is_empty() {
+                fields = relationship.map(|r| r.fields.clone()).unwrap_or_default();
+            }
+            ConsistencyFinding {
+                fields,
+                relationship: relationship.map(|r| r.kind),
+                message: finding.message,
+                source: FindingSource::Llm,
+            }
+        })
+        .collect())
+}
+
+// Hasil tahap lokal: temuan yang sudah pasti, hubungan yang perlu LLM, dan field yang dikirim ke LLM
+// (semua field kecuali yang ditolak lokal sebagai prompt injection).
+struct FormPlan<'a> {
+    findings: Vec<ConsistencyFinding>,
+    pending: Vec<&'a Relationship>,
+    sent: FormInput,
+    today: (i32, u32, u32),
+}
+
+fn plan_form<'a>(form: &'a FormInput, validator: &SemanticValidator) -> Result<FormPlan<'a>, ValidationError> {
+    validate_form(form)?;
+    let locale = validator.locale();
+    let today = today();
+    let mut findings = Vec::new();
+    let mut pending = Vec::new();
+    let mut sent = FormInput::new();
+
+    for (name, field) in &form.fields {
+        if validator.is_injection(&field.value) {
+            findings.push(injection_finding(name, field, FindingSource::Local, locale));
+        } else {
+            sent.fields.insert(name.clone(), field.clone());
+        }
+    }
+
+    // Hubungan yang menyentuh field yang ditolak tidak diperiksa: nilainya bukan data.
+    let checkable = |r: &&Relationship| has_values(r, form) && r.fields.iter().all(|f| sent.fields.contains_key(f));
+    for relationship in form.relationships.iter().filter(checkable) {
+        match check_locally(relationship, form, today) {
+            LocalCheck::Consistent => {}
+            LocalCheck::Inconsistent(message) => findings.push(ConsistencyFinding {
+                fields: relationship.fields.clone(),
+                relationship: Some(relationship.kind),
+                message: message.render(locale),
+                source: FindingSource::Local,
+            }),
+            LocalCheck::Undecided => pending.push(relationship),
+        }
+    }
+    Ok(FormPlan { findings, pending, sent, today })
+}
+
+fn injection_finding(name: &str, field: &FormField, source: FindingSource, locale: Locale) -> ConsistencyFinding {
+    ConsistencyFinding {
+        fields: vec![name.to_string()],
+        relationship: None,
+        message: LocalMessage::PromptInjection { input_type: field.input_type.clone() }.render(locale),
+        source,
+    }
+}
+
+// Temuan dari keluaran LLM. Jawaban yang hanya menyalin putusan yang tertulis di salah satu field
+// diganti temuan prompt injection untuk field tersebut.
+fn llm_findings(
+    text: &str,
+    sent: &FormInput,
+    pending: &[&Relationship],
+    locale: Locale,
+) -> Result<Vec<ConsistencyFinding>, ValidationError> {
+    let echoed: Vec<ConsistencyFinding> = sent
+        .fields
+        .iter()
+        .filter(|(_, field)| is_echoed_answer(&field.value, text, "consistent"))
+        .map(|(name, field)| injection_finding(name, field, FindingSource::Llm, locale))
+        .collect();
+    if echoed.is_empty() {
+        return parse_form_output(text, sent, pending);
+    }
+    tracing::warn!("model menyalin putusan formulir dari nilai field, ditolak sebagai prompt injection");
+    Ok(echoed)
+}
+
+fn build_report(form: &FormInput, findings: Vec<ConsistencyFinding>, model: Option<String>) -> FormReport {
+    let mut field_findings: BTreeMap<String, Vec<String>> =
+        form.fields.keys().map(|name| (name.clone(), Vec::new())).collect();
+    for finding in &findings {
+        for field in &finding.fields {
+            if let Some(messages) = field_findings.get_mut(field) {
+                messages.push(finding.message.clone());
+            }
+        }
+    }
+    FormReport {
+        consistent: findings.is_empty(),
+        findings,
+        field_findings,
+        model,
+    }
+}
+
+impl SemanticValidator {
+    /// Memeriksa konsistensi antar-field memakai model bawaan validator.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn check_form_sync(&self, form: &FormInput) -> Result<FormReport, ValidationError> {
+        self.check_form_with_model_sync(form, self.default_model())
+    }
+
+    /// Memeriksa konsistensi antar-field: hubungan yang bisa diputuskan secara lokal
+    /// tidak dikirim ke LLM, sisanya diperiksa dalam satu request.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn check_form_with_model_sync(
+        &self,
+        form: &FormInput,
+        model: SupportedModel,
+    ) -> Result<FormReport, ValidationError> {
+        let FormPlan { mut findings, pending, sent, today } = plan_form(form, self)?;
+        if pending.is_empty() {
+            return Ok(build_report(form, findings, None));
+        }
+
+        let parts = format_form_prompt(&sent, &pending, today, self.locale());
+        let (text, answered_by) = self.complete_with_fallback_sync(Completion::parts(&parts), model.as_str())?;
+        findings.extend(llm_findings(&text, &sent, &pending, self.locale())?);
+        Ok(build_report(form, findings, Some(answered_by.to_string())))
+    }
+
+    /// Versi asinkron dari `check_form_sync`.
+    pub async fn check_form_async(&self, form: &FormInput) -> Result<FormReport, ValidationError> {
+        self.check_form_with_model_async(form, self.default_model()).await
+    }
+
+    /// Versi asinkron dari `check_form_with_model_sync`.
+    pub async fn check_form_with_model_async(
+        &self,
+        form: &FormInput,
+        model: SupportedModel,
+    ) -> Result<FormReport, ValidationError> {
+        let FormPlan { mut findings, pending, sent, today } = plan_form(form, self)?;
+        if pending.is_empty() {
+            return Ok(build_report(form, findings, None));
+        }
+
+        let parts = format_form_prompt(&sent, &pending, today, self.locale());
+        let (text, answered_by) = self.complete_with_fallback_async(Completion::parts(&parts), model.as_str()).await?;
+        findings.extend(llm_findings(&text, &sent, &pending, self.locale())?);
+        Ok(build_report(form, findings, Some(answered_by.to_string())))
+    }
+}
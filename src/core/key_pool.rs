@@ -0,0 +1,304 @@
+// src/core/key_pool.rs
+// Pool API key: memilih key per percobaan HTTP, mengkarantina key yang terkena
+// limit/ditolak, dan mencatat pemakaian per key.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::core::cache::now_millis;
+use crate::core::error::ValidationError;
+
+/// Lama karantina bawaan untuk key yang terkena 429 atau ditolak server.
+pub const DEFAULT_KEY_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Cara memilih key berikutnya dari pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeySelection {
+    /// Bergiliran sesuai urutan key.
+    #[default]
+    RoundRobin,
+    /// Key yang paling lama tidak terkena rate limit (key yang belum pernah terkena didahulukan).
+    LeastRecentlyRateLimited,
+}
+
+impl KeySelection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeySelection::RoundRobin => "round_robin",
+            KeySelection::LeastRecentlyRateLimited => "least_recently_rate_limited",
+        }
+    }
+}
+
+impl std::str::FromStr for KeySelection {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "round_robin" => Ok(KeySelection::RoundRobin),
+            "least_recently_rate_limited" => Ok(KeySelection::LeastRecentlyRateLimited),
+            other => Err(ValidationError::Config(format!(
+                "Strategi pemilihan key '{}' tidak dikenal (round_robin, least_recently_rate_limited).",
+                other
+            ))),
+        }
+    }
+}
+
+/// Pemakaian satu key. Isi key tidak disertakan, hanya 4 karakter terakhirnya.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct KeyUsage {
+    /// Posisi key di pool.
+    pub index: usize,
+    /// 4 karakter terakhir key (atau `****` untuk key yang sangat pendek).
+    pub key_hint: String,
+    /// Jumlah percobaan HTTP yang memakai key ini.
+    pub requests: u64,
+    pub successes: u64,
+    /// Respons 429.
+    pub rate_limited: u64,
+    /// Key ditolak server (401/403 atau `API_KEY_INVALID`).
+    pub rejected: u64,
+    /// Kegagalan lain (5xx, transport, keluaran tidak valid, ...).
+    pub errors: u64,
+    /// Sisa waktu karantina terlama dalam milidetik, untuk model mana pun
+    /// (`0` berarti key siap dipakai untuk semua model).
+    pub quarantine_remaining_ms: u64,
+    /// Model yang sedang dikarantina untuk key ini karena 429, urut menurut nama.
+    pub quarantined_models: Vec<String>,
+}
+
+/// Key yang dipilih untuk satu percobaan ke `model`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct KeyLease<'a> {
+    pub index: usize,
+    pub key: &'a str,
+    pub model: &'a str,
+}
+
+/// Pool API key yang dibagi semua pemanggilan satu validator (sinkron maupun asinkron).
+///
+/// Setiap percobaan HTTP (termasuk retry) meminjam satu key. Key yang menerima
+/// 429 dikarantina untuk model itu saja, karena kuota dihitung per model; key yang
+/// ditolak server dikarantina untuk semua model. Karantina berlaku selama `cooldown`
+/// (atau selama `Retry-After` dari server jika lebih lama) dan key dilewati selama
+/// masa itu. Jika semua key sedang dikarantina, key yang paling cepat bebas tetap dipakai.
+pub struct ApiKeyPool {
+    keys: Vec<String>,
+    selection: KeySelection,
+    cooldown: Duration,
+    state: Mutex<PoolState>,
+}
+
+#[derive(Default)]
+struct PoolState {
+    // Posisi awal pencarian berikutnya (untuk giliran).
+    next: usize,
+    slots: Vec<KeySlot>,
+}
+
+#[derive(Default, Clone)]
+struct KeySlot {
+    requests: u64,
+    successes: u64,
+    rate_limited: u64,
+    rejected: u64,
+    errors: u64,
+    // Karantina untuk semua model (key ditolak).
+    quarantined_until_ms: u64,
+    // Karantina per model karena 429; model lain tetap boleh memakai key ini.
+    model_quarantine: HashMap<String, u64>,
+    // 0 berarti belum pernah terkena rate limit.
+    last_rate_limited_ms: u64,
+}
+
+impl KeySlot {
+    /// Waktu (ms) saat key ini kembali boleh dipakai untuk `model`.
+    fn free_at(&self, model: &str) -> u64 {
+        let model_until = self.model_quarantine.get(model).copied().unwrap_or(0);
+        self.quarantined_until_ms.max(model_until)
+    }
+}
+
+impl ApiKeyPool {
+    /// Membuat pool dari daftar key. Daftar kosong atau key kosong ditolak.
+    pub fn new<I, S>(keys: I) -> Result<Self, ValidationError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let keys: Vec<String> = keys.into_iter().map(Into::into).collect();
+        if keys.is_empty() {
+            return Err(ValidationError::Config("Pool API key tidak boleh kosong.".to_string()));
+        }
+        if let Some(position) = keys.iter().position(|key| key.trim().is_empty()) {
+            return Err(ValidationError::Config(format!(
+                "API key ke-{} di pool kosong.",
+                position + 1
+            )));
+        }
+        Ok(Self::from_keys(keys))
+    }
+
+    /// Pool berisi satu key, boleh kosong (provider lokal tanpa autentikasi).
+    pub(crate) fn single(key: String) -> Self {
+        Self::from_keys(vec![key])
+    }
+
+    fn from_keys(keys: Vec<String>) -> Self {
+        let slots = vec![KeySlot::default(); keys.len()];
+        Self {
+            keys,
+            selection: KeySelection::default(),
+            cooldown: DEFAULT_KEY_COOLDOWN,
+            state: Mutex::new(PoolState { next: 0, slots }),
+        }
+    }
+
+    pub fn selection(mut self, selection: KeySelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Lama karantina minimal untuk key yang terkena 429 atau ditolak.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    pub fn selection_strategy(&self) -> KeySelection {
+        self.selection
+    }
+
+    pub fn cooldown_duration(&self) -> Duration {
+        self.cooldown
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Key pertama di pool.
+    pub(crate) fn primary(&self) -> &str {
+        &self.keys[0]
+    }
+
+    /// Memilih key untuk percobaan berikutnya ke `model` sesuai strategi.
+    pub(crate) fn acquire<'a>(&'a self, model: &'a str) -> KeyLease<'a> {
+        let now = now_millis();
+        let mut state = self.lock();
+        let len = self.keys.len();
+        let start = state.next % len;
+        let order = (0..len).map(|offset| (start + offset) % len);
+
+        let available = |index: &usize| state.slots[*index].free_at(model) <= now;
+        let index = match self.selection {
+            KeySelection::RoundRobin => order.clone().find(available),
+            // `min_by_key` mengambil yang pertama jika seri, jadi giliran tetap berlaku.
+            KeySelection::LeastRecentlyRateLimited => order
+                .clone()
+                .filter(available)
+                .min_by_key(|index| state.slots[*index].last_rate_limited_ms),
+        }
+        .unwrap_or_else(|| {
+            order
+                .min_by_key(|index| state.slots[*index].free_at(model))
+                .unwrap_or(start)
+        });
+
+        state.next = index + 1;
+        state.slots[index].requests += 1;
+        KeyLease {
+            index,
+            key: &self.keys[index],
+            model,
+        }
+    }
+
+    /// Mencatat hasil percobaan dengan `lease` dan mengkarantina key bila perlu.
+    pub(crate) fn report<T>(&self, lease: KeyLease<'_>, result: &Result<T, ValidationError>) {
+        let now = now_millis();
+        let mut state = self.lock();
+        let slot = &mut state.slots[lease.index];
+        match result {
+            Ok(_) => slot.successes += 1,
+            Err(err @ ValidationError::RateLimited { .. }) => {
+                slot.rate_limited += 1;
+                slot.last_rate_limited_ms = now;
+                let cooldown = err.retry_after().map_or(self.cooldown, |wait| wait.max(self.cooldown));
+                slot.model_quarantine.insert(lease.model.to_string(), now + cooldown.as_millis() as u64);
+            }
+            Err(ValidationError::Unauthorized { .. }) => {
+                slot.rejected += 1;
+                slot.quarantined_until_ms = now + self.cooldown.as_millis() as u64;
+            }
+            Err(_) => slot.errors += 1,
+        }
+    }
+
+    /// `true` jika `err` membuat key `lease` dikarantina dan ada key lain yang siap untuk
+    /// model yang sama, sehingga percobaan berikutnya bisa langsung dilakukan tanpa jeda backoff.
+    pub(crate) fn can_rotate(&self, lease: KeyLease<'_>, err: &ValidationError) -> bool {
+        if !matches!(err, ValidationError::RateLimited { .. } | ValidationError::Unauthorized { .. }) {
+            return false;
+        }
+        let now = now_millis();
+        self.lock()
+            .slots
+            .iter()
+            .enumerate()
+            .any(|(index, slot)| index != lease.index && slot.free_at(lease.model) <= now)
+    }
+
+    /// Statistik pemakaian semua key, sesuai urutan di pool.
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        let now = now_millis();
+        let state = self.lock();
+        self.keys
+            .iter()
+            .zip(&state.slots)
+            .enumerate()
+            .map(|(index, (key, slot))| {
+                let mut quarantined_models: Vec<String> = slot
+                    .model_quarantine
+                    .iter()
+                    .filter(|(_, until)| **until > now)
+                    .map(|(model, _)| model.clone())
+                    .collect();
+                quarantined_models.sort();
+                let until = slot.model_quarantine.values().fold(slot.quarantined_until_ms, |a, b| a.max(*b));
+                KeyUsage {
+                    index,
+                    key_hint: key_hint(key),
+                    requests: slot.requests,
+                    successes: slot.successes,
+                    rate_limited: slot.rate_limited,
+                    rejected: slot.rejected,
+                    errors: slot.errors,
+                    quarantine_remaining_ms: until.saturating_sub(now),
+                    quarantined_models,
+                }
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, PoolState> {
+        // Isinya hanya penghitung dan waktu; tetap aman dipakai walaupun mutex "poisoned".
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn key_hint(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+    chars[chars.len() - 4..].iter().collect()
+}
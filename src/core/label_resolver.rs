@@ -0,0 +1,282 @@
+// src/core/label_resolver.rs
+// Pemetaan label formulir dunia nyata ("E-mail Kantor", "No. WA", "bday") ke tipe input kanonis.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::InputType;
+
+/// Confidence minimal agar hasil resolver dipakai otomatis untuk pemeriksaan sintaksis dan prompt.
+pub const MIN_AUTO_CONFIDENCE: f64 = 0.7;
+
+/// Cara resolver menemukan tipe input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchMethod {
+    /// Label persis sama dengan alias.
+    Exact,
+    /// Sama dengan alias setelah tanda baca, underscore, huruf besar, dan petunjuk dalam kurung dirapikan.
+    Normalized,
+    /// Token `autocomplete` HTML seperti `email`, `tel`, atau `bday`.
+    Autocomplete,
+    /// Sebagian label cocok dengan alias atau singkatan umum ("No. WA", "E-mail Kantor").
+    Keyword,
+    /// Mirip dengan alias berdasarkan edit distance (salah ketik).
+    Fuzzy,
+}
+
+impl MatchMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchMethod::Exact => "exact",
+            MatchMethod::Normalized => "normalized",
+            MatchMethod::Autocomplete => "autocomplete",
+            MatchMethod::Keyword => "keyword",
+            MatchMethod::Fuzzy => "fuzzy",
+        }
+    }
+}
+
+/// Hasil resolver: tipe kanonis, seberapa yakin, dan bagian label yang cocok.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelResolution {
+    pub input_type: InputType,
+    /// 0.0 sampai 1.0.
+    pub confidence: f64,
+    pub method: MatchMethod,
+    /// Alias, token autocomplete, atau kata kunci yang menjadi dasar keputusan.
+    pub matched: String,
+}
+
+// Singkatan dan sinonim yang lazim di formulir Indonesia tetapi bukan alias resmi.
+const KEYWORDS: &[(&str, InputType)] = &[
+    ("surel", InputType::Email),
+    ("wa", InputType::Phone),
+    ("whatsapp", InputType::Phone),
+    ("hp", InputType::Phone),
+    ("handphone", InputType::Phone),
+    ("ponsel", InputType::Phone),
+    ("telp", InputType::Phone),
+    ("telepon", InputType::Phone),
+    ("telephone", InputType::Phone),
+    ("kota", InputType::Location),
+    ("city", InputType::Location),
+    ("kecamatan", InputType::Location),
+    ("provinsi", InputType::Location),
+    ("jalan", InputType::Address),
+    ("usia", InputType::Number),
+    ("instansi", InputType::Institution),
+    ("universitas", InputType::Institution),
+    ("sekolah", InputType::Institution),
+    ("kampus", InputType::Institution),
+];
+
+// Kata pelengkap yang tidak menentukan tipe ("No. WA", "E-mail Kantor"); tidak dihitung saat
+// menilai seberapa banyak label yang tercakup kata kunci.
+const FILLER_WORDS: &[&str] = &[
+    "no", "nomor", "nomer", "number", "kantor", "pribadi", "aktif", "utama", "anda", "kamu",
+    "your", "work", "office", "personal", "primary",
+];
+
+// Token `autocomplete` HTML (https://html.spec.whatwg.org/#autofill-field).
+const AUTOCOMPLETE_TOKENS: &[(&str, InputType)] = &[
+    ("email", InputType::Email),
+    ("url", InputType::Url),
+    ("tel", InputType::Phone),
+    ("tel-national", InputType::Phone),
+    ("tel-local", InputType::Phone),
+    ("username", InputType::Username),
+    ("name", InputType::PersonName),
+    ("given-name", InputType::PersonName),
+    ("additional-name", InputType::PersonName),
+    ("family-name", InputType::PersonName),
+    ("nickname", InputType::PersonName),
+    ("organization", InputType::Company),
+    ("organization-title", InputType::Occupation),
+    ("street-address", InputType::Address),
+    ("address-line1", InputType::Address),
+    ("address-line2", InputType::Address),
+    ("address-line3", InputType::Address),
+    ("address-level1", InputType::Location),
+    ("address-level2", InputType::Location),
+    ("address-level3", InputType::Location),
+    ("address-level4", InputType::Location),
+    ("country-name", InputType::Location),
+    ("bday", InputType::Date),
+    ("bday-day", InputType::Date),
+    ("bday-month", InputType::Date),
+    ("bday-year", InputType::Date),
+    ("transaction-amount", InputType::Number),
+];
+
+// Token pengubah autocomplete yang boleh mendahului token utama.
+const AUTOCOMPLETE_MODIFIERS: &[&str] = &["shipping", "billing", "home", "work", "mobile", "fax", "pager"];
+
+/// Memetakan label formulir ke tipe kanonis, dari cara yang paling pasti ke yang paling longgar:
+/// alias persis, alias setelah normalisasi, token `autocomplete` HTML, kata kunci, lalu edit distance.
+pub fn resolve_label(label: &str) -> Option<LabelResolution> {
+    if let Some(input_type) = InputType::resolve(label) {
+        return Some(resolution(input_type, 1.0, MatchMethod::Exact, label.trim().to_lowercase()));
+    }
+    if let Some(resolution) = match_autocomplete(label) {
+        return Some(resolution);
+    }
+
+    let normalized = normalize_label(label);
+    if normalized.is_empty() {
+        return None;
+    }
+    // Id kanonis ("person_name") juga diterima sebagai label.
+    let by_id = InputType::ALL.into_iter().find(|input_type| input_type.as_str().replace('_', " ") == normalized);
+    if let Some(input_type) = InputType::resolve(&normalized).or(by_id) {
+        return Some(resolution(input_type, 0.95, MatchMethod::Normalized, normalized));
+    }
+
+    let words: Vec<&str> = normalized.split(' ').collect();
+    match_keywords(&words).or_else(|| match_fuzzy(&normalized, &words))
+}
+
+/// Tipe yang dipakai validator untuk `label`: hasil [`resolve_label`] dengan confidence
+/// minimal [`MIN_AUTO_CONFIDENCE`].
+pub fn input_type_for(label: &str) -> Option<InputType> {
+    resolve_label(label)
+        .filter(|resolution| resolution.confidence >= MIN_AUTO_CONFIDENCE)
+        .map(|resolution| resolution.input_type)
+}
+
+/// Huruf kecil, petunjuk dalam kurung dibuang, camelCase dipisah, tanda baca dan underscore
+/// menjadi spasi. "E-mail" dan "e mail" disatukan menjadi "email".
+pub fn normalize_label(label: &str) -> String {
+    let mut cleaned = String::with_capacity(label.len());
+    let mut depth = 0usize;
+    let mut previous: Option<char> = None;
+    for c in label.chars() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            _ if depth > 0 => {}
+            _ if c.is_alphanumeric() => {
+                // camelCase: "namaLengkap" -> "nama lengkap"
+                if c.is_uppercase() && previous.is_some_and(char::is_lowercase) {
+                    cleaned.push(' ');
+                }
+                cleaned.extend(c.to_lowercase());
+            }
+            _ => cleaned.push(' '),
+        }
+        if depth == 0 {
+            previous = Some(c);
+        }
+    }
+
+    let words: Vec<&str> = cleaned.split_whitespace().collect();
+    let mut merged: Vec<String> = Vec::with_capacity(words.len());
+    let mut index = 0;
+    while index < words.len() {
+        if words[index] == "e" && words.get(index + 1) == Some(&"mail") {
+            merged.push("email".to_string());
+            index += 2;
+        } else {
+            merged.push(words[index].to_string());
+            index += 1;
+        }
+    }
+    merged.join(" ")
+}
+
+fn resolution(input_type: InputType, confidence: f64, method: MatchMethod, matched: String) -> LabelResolution {
+    LabelResolution { input_type, confidence, method, matched }
+}
+
+fn match_autocomplete(label: &str) -> Option<LabelResolution> {
+    let tokens: Vec<String> = label.split_whitespace().map(str::to_lowercase).collect();
+    let (last, prefix) = tokens.split_last()?;
+    let prefix_ok = prefix
+        .iter()
+        .all(|token| token.starts_with("section-") || AUTOCOMPLETE_MODIFIERS.contains(&token.as_str()));
+    if !prefix_ok {
+        return None;
+    }
+    AUTOCOMPLETE_TOKENS
+        .iter()
+        .find(|(token, _)| token == last)
+        .map(|(token, input_type)| resolution(*input_type, 0.95, MatchMethod::Autocomplete, token.to_string()))
+}
+
+// Mencari potongan label (hingga 3 kata) yang merupakan alias atau kata kunci. Potongan
+// terpanjang menang; confidence naik sesuai porsi label (di luar kata pelengkap) yang tercakup.
+fn match_keywords(words: &[&str]) -> Option<LabelResolution> {
+    let mut best: Option<(usize, InputType, String)> = None;
+    for size in (1..=words.len().min(3)).rev() {
+        for window in words.windows(size) {
+            let phrase = window.join(" ");
+            let found = InputType::resolve(&phrase).or_else(|| {
+                KEYWORDS
+                    .iter()
+                    .find(|(keyword, _)| *keyword == phrase)
+                    .map(|(_, input_type)| *input_type)
+            });
+            if let Some(input_type) = found {
+                best = Some((size, input_type, phrase));
+                break;
+            }
+        }
+        if best.is_some() {
+            break;
+        }
+    }
+
+    let (size, input_type, phrase) = best?;
+    let meaningful = words.iter().filter(|word| !FILLER_WORDS.contains(word)).count().max(size);
+    let coverage = size as f64 / meaningful as f64;
+    Some(resolution(input_type, 0.5 + 0.4 * coverage, MatchMethod::Keyword, phrase))
+}
+
+// Alias terdekat menurut edit distance, untuk label utuh maupun per kata (minimal 4 huruf).
+fn match_fuzzy(normalized: &str, words: &[&str]) -> Option<LabelResolution> {
+    let candidates = std::iter::once(normalized).chain(words.iter().copied().filter(|word| word.chars().count() >= 4));
+
+    let mut best: Option<(f64, InputType, &'static str)> = None;
+    for candidate in candidates {
+        for input_type in InputType::ALL {
+            for alias in input_type.aliases() {
+                let length = candidate.chars().count().max(alias.chars().count());
+                let distance = edit_distance(candidate, alias);
+                // Toleransi: 1 kesalahan per 4 huruf, maksimal 2.
+                if distance == 0 || distance > (length / 4).clamp(1, 2) {
+                    continue;
+                }
+                let similarity = 1.0 - distance as f64 / length as f64;
+                if best.is_none_or(|(score, _, _)| similarity > score) {
+                    best = Some((similarity, input_type, alias));
+                }
+            }
+        }
+    }
+
+    let (similarity, input_type, alias) = best?;
+    Some(resolution(input_type, (similarity * 0.9).min(0.85), MatchMethod::Fuzzy, alias.to_string()))
+}
+
+/// Jarak Damerau-Levenshtein (optimal string alignment): sisip, hapus, ganti, dan tukar dua huruf bersebelahan.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut value = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                value = value.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = value;
+        }
+    }
+    rows[a.len()][b.len()]
+}
@@ -0,0 +1,285 @@
+// src/core/locale.rs
+// Bahasa pesan: arahan bahasa untuk LLM dan katalog pesan pemeriksaan lokal.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::ValidationError;
+use crate::core::rules::CharClass;
+use crate::models::InputType;
+
+/// Bahasa penjelasan (`message`) dan pesan error lokal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// Bahasa Indonesia (bawaan).
+    #[default]
+    Id,
+    /// English.
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Id, Locale::En];
+
+    /// Menerima kode bahasa seperti "id", "en", "en-US", atau "id_ID" (tanpa membedakan huruf besar/kecil).
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.trim().split(['-', '_']).next().unwrap_or_default();
+        match language.to_lowercase().as_str() {
+            "id" | "in" | "ind" | "indonesia" | "indonesian" => Some(Locale::Id),
+            "en" | "eng" | "english" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::Id => "id",
+            Locale::En => "en",
+        }
+    }
+
+    /// Nama bahasa dalam bahasa Inggris, untuk instruksi prompt.
+    pub(crate) fn language_name(&self) -> &'static str {
+        match self {
+            Locale::Id => "Indonesian (Bahasa Indonesia)",
+            Locale::En => "English",
+        }
+    }
+
+    /// Deskripsi pilihan untuk pesan error binding.
+    pub fn valid_options_desc() -> String {
+        Locale::ALL.iter().map(Locale::as_str).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ValidationError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Locale::from_code(code).ok_or_else(|| {
+            ValidationError::Config(format!(
+                "Locale '{}' tidak didukung. Pilihan: [{}].",
+                code,
+                Locale::valid_options_desc()
+            ))
+        })
+    }
+}
+
+/// Katalog pesan pemeriksaan lokal (sintaksis dan konsistensi formulir), dikunci
+/// dengan kode stabil sehingga teksnya bisa dirender dalam setiap [`Locale`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalMessage {
+    EmptyInput,
+    InputTooLong { input_type: String },
+    EmailTooLong,
+    EmailFormat,
+    UrlFormat,
+    UrlHasSpaces,
+    PhoneEmpty,
+    PhoneLength,
+    PhoneCharacters,
+    UsernameTooShort,
+    NameTooShort,
+    DoubleSpaces { input_type: String },
+    DigitsOnly { input_type: String },
+    IdentityTooShort,
+    IdentityWithoutDigits,
+    DateWithoutDigits,
+    NumberWithoutDigits,
+    TooShort,
+    ContentTooShort,
+    LengthBelow { input_type: String, min: usize },
+    LengthAbove { input_type: String, max: usize },
+    PatternMismatch { input_type: String },
+    MissingCharClass { input_type: String, class: CharClass },
+    BirthDateInFuture { birth_date: String },
+    AgeMismatch { age: u32, birth_date: String, actual_age: i32 },
+    PostalCodeFormat { postal_code: String },
+    PostalCodeRegion { postal_code: String, city: String },
+    FreeMailDomain { email: String, domain: String, company: String },
+    LabelMismatch { label: String, detected: InputType },
+    PromptInjection { input_type: String },
+}
+
+impl LocalMessage {
+    /// Kode stabil untuk dibaca mesin.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LocalMessage::EmptyInput => "empty_input",
+            LocalMessage::InputTooLong { .. } => "input_too_long",
+            LocalMessage::EmailTooLong => "email_too_long",
+            LocalMessage::EmailFormat => "email_format",
+            LocalMessage::UrlFormat => "url_format",
+            LocalMessage::UrlHasSpaces => "url_has_spaces",
+            LocalMessage::PhoneEmpty => "phone_empty",
+            LocalMessage::PhoneLength => "phone_length",
+            LocalMessage::PhoneCharacters => "phone_characters",
+            LocalMessage::UsernameTooShort => "username_too_short",
+            LocalMessage::NameTooShort => "name_too_short",
+            LocalMessage::DoubleSpaces { .. } => "double_spaces",
+            LocalMessage::DigitsOnly { .. } => "digits_only",
+            LocalMessage::IdentityTooShort => "identity_too_short",
+            LocalMessage::IdentityWithoutDigits => "identity_without_digits",
+            LocalMessage::DateWithoutDigits => "date_without_digits",
+            LocalMessage::NumberWithoutDigits => "number_without_digits",
+            LocalMessage::TooShort => "too_short",
+            LocalMessage::ContentTooShort => "content_too_short",
+            LocalMessage::LengthBelow { .. } => "length_below",
+            LocalMessage::LengthAbove { .. } => "length_above",
+            LocalMessage::PatternMismatch { .. } => "pattern_mismatch",
+            LocalMessage::MissingCharClass { .. } => "missing_char_class",
+            LocalMessage::BirthDateInFuture { .. } => "birth_date_in_future",
+            LocalMessage::AgeMismatch { .. } => "age_mismatch",
+            LocalMessage::PostalCodeFormat { .. } => "postal_code_format",
+            LocalMessage::PostalCodeRegion { .. } => "postal_code_region",
+            LocalMessage::FreeMailDomain { .. } => "free_mail_domain",
+            LocalMessage::LabelMismatch { .. } => "label_mismatch",
+            LocalMessage::PromptInjection { .. } => "prompt_injection",
+        }
+    }
+
+    /// Teks pesan dalam `locale`.
+    pub fn render(&self, locale: Locale) -> String {
+        match locale {
+            Locale::Id => self.render_id(),
+            Locale::En => self.render_en(),
+        }
+    }
+
+    fn render_id(&self) -> String {
+        match self {
+            LocalMessage::EmptyInput => "Input tidak boleh kosong.".to_string(),
+            LocalMessage::InputTooLong { input_type } => {
+                format!("Input terlalu panjang untuk kategori '{}'.", input_type)
+            }
+            LocalMessage::EmailTooLong => "Email terlalu panjang.".to_string(),
+            LocalMessage::EmailFormat => "Format email tidak valid (contoh: user@domain.com).".to_string(),
+            LocalMessage::UrlFormat => {
+                "Format URL tidak valid (harus mengandung domain, misal: example.com).".to_string()
+            }
+            LocalMessage::UrlHasSpaces => "URL tidak boleh mengandung spasi.".to_string(),
+            LocalMessage::PhoneEmpty => "Nomor telepon tidak boleh kosong.".to_string(),
+            LocalMessage::PhoneLength => "Panjang nomor telepon tidak valid (Global: 7-15 digit).".to_string(),
+            LocalMessage::PhoneCharacters => "Nomor telepon mengandung karakter yang tidak valid.".to_string(),
+            LocalMessage::UsernameTooShort => "Username terlalu pendek (minimal 3 karakter).".to_string(),
+            LocalMessage::NameTooShort => "Nama terlalu pendek (minimal 3 karakter).".to_string(),
+            LocalMessage::DoubleSpaces { input_type } => {
+                format!("'{}' tidak boleh mengandung double spasi.", input_type)
+            }
+            LocalMessage::DigitsOnly { input_type } => {
+                format!("'{}' tidak boleh hanya terdiri dari angka.", input_type)
+            }
+            LocalMessage::IdentityTooShort => "Nomor identitas terlalu pendek.".to_string(),
+            LocalMessage::IdentityWithoutDigits => "Nomor identitas harus mengandung angka.".to_string(),
+            LocalMessage::DateWithoutDigits => "Format tanggal/waktu harus mengandung angka.".to_string(),
+            LocalMessage::NumberWithoutDigits => "Input harus mengandung nilai angka.".to_string(),
+            LocalMessage::TooShort => "Input terlalu pendek (minimal 2 karakter).".to_string(),
+            LocalMessage::ContentTooShort => "Konten terlalu pendek (minimal 10 karakter).".to_string(),
+            LocalMessage::LengthBelow { input_type, min } => {
+                format!("'{}' minimal {} karakter.", input_type, min)
+            }
+            LocalMessage::LengthAbove { input_type, max } => {
+                format!("'{}' maksimal {} karakter.", input_type, max)
+            }
+            LocalMessage::PatternMismatch { input_type } => {
+                format!("Format '{}' tidak sesuai aturan.", input_type)
+            }
+            LocalMessage::MissingCharClass { input_type, class } => {
+                format!("'{}' harus mengandung {}.", input_type, class.describe(Locale::Id))
+            }
+            LocalMessage::BirthDateInFuture { birth_date } => {
+                format!("Tanggal lahir '{}' berada di masa depan.", birth_date)
+            }
+            LocalMessage::AgeMismatch { age, birth_date, actual_age } => format!(
+                "Umur {} tidak sesuai dengan tanggal lahir '{}' (seharusnya sekitar {} tahun).",
+                age, birth_date, actual_age
+            ),
+            LocalMessage::PostalCodeFormat { postal_code } => {
+                format!("Kode pos '{}' harus terdiri dari 5 digit.", postal_code)
+            }
+            LocalMessage::PostalCodeRegion { postal_code, city } => {
+                format!("Kode pos {} bukan kode pos wilayah {}.", postal_code, city)
+            }
+            LocalMessage::FreeMailDomain { email, domain, company } => format!(
+                "Email '{}' memakai penyedia email pribadi ({}), bukan domain milik '{}'.",
+                email, domain, company
+            ),
+            LocalMessage::LabelMismatch { label, detected } => {
+                format!("Nilai untuk '{}' terlihat seperti tipe '{}'.", label, detected.label())
+            }
+            LocalMessage::PromptInjection { input_type } => format!(
+                "Input berisi instruksi untuk sistem validasi, bukan data '{}' yang wajar.",
+                input_type
+            ),
+        }
+    }
+
+    fn render_en(&self) -> String {
+        match self {
+            LocalMessage::EmptyInput => "Input must not be empty.".to_string(),
+            LocalMessage::InputTooLong { input_type } => {
+                format!("Input is too long for category '{}'.", input_type)
+            }
+            LocalMessage::EmailTooLong => "Email address is too long.".to_string(),
+            LocalMessage::EmailFormat => "Invalid email format (example: user@domain.com).".to_string(),
+            LocalMessage::UrlFormat => "Invalid URL format (must contain a domain, e.g. example.com).".to_string(),
+            LocalMessage::UrlHasSpaces => "URL must not contain spaces.".to_string(),
+            LocalMessage::PhoneEmpty => "Phone number must not be empty.".to_string(),
+            LocalMessage::PhoneLength => "Invalid phone number length (global: 7-15 digits).".to_string(),
+            LocalMessage::PhoneCharacters => "Phone number contains invalid characters.".to_string(),
+            LocalMessage::UsernameTooShort => "Username is too short (minimum 3 characters).".to_string(),
+            LocalMessage::NameTooShort => "Name is too short (minimum 3 characters).".to_string(),
+            LocalMessage::DoubleSpaces { input_type } => {
+                format!("'{}' must not contain double spaces.", input_type)
+            }
+            LocalMessage::DigitsOnly { input_type } => {
+                format!("'{}' must not consist of digits only.", input_type)
+            }
+            LocalMessage::IdentityTooShort => "Identity number is too short.".to_string(),
+            LocalMessage::IdentityWithoutDigits => "Identity number must contain digits.".to_string(),
+            LocalMessage::DateWithoutDigits => "Date/time must contain digits.".to_string(),
+            LocalMessage::NumberWithoutDigits => "Input must contain a numeric value.".to_string(),
+            LocalMessage::TooShort => "Input is too short (minimum 2 characters).".to_string(),
+            LocalMessage::ContentTooShort => "Content is too short (minimum 10 characters).".to_string(),
+            LocalMessage::LengthBelow { input_type, min } => {
+                format!("'{}' must be at least {} characters long.", input_type, min)
+            }
+            LocalMessage::LengthAbove { input_type, max } => {
+                format!("'{}' must be at most {} characters long.", input_type, max)
+            }
+            LocalMessage::PatternMismatch { input_type } => {
+                format!("'{}' does not match the required format.", input_type)
+            }
+            LocalMessage::MissingCharClass { input_type, class } => {
+                format!("'{}' must contain {}.", input_type, class.describe(Locale::En))
+            }
+            LocalMessage::BirthDateInFuture { birth_date } => {
+                format!("Birth date '{}' is in the future.", birth_date)
+            }
+            LocalMessage::AgeMismatch { age, birth_date, actual_age } => format!(
+                "Age {} does not match birth date '{}' (should be about {} years).",
+                age, birth_date, actual_age
+            ),
+            LocalMessage::PostalCodeFormat { postal_code } => {
+                format!("Postal code '{}' must have 5 digits.", postal_code)
+            }
+            LocalMessage::PostalCodeRegion { postal_code, city } => {
+                format!("Postal code {} does not belong to {}.", postal_code, city)
+            }
+            LocalMessage::FreeMailDomain { email, domain, company } => format!(
+                "Email '{}' uses a personal email provider ({}), not a domain owned by '{}'.",
+                email, domain, company
+            ),
+            LocalMessage::LabelMismatch { label, detected } => {
+                format!("The value for '{}' looks like type '{}'.", label, detected.as_str())
+            }
+            LocalMessage::PromptInjection { input_type } => format!(
+                "Input contains instructions for the validator instead of a genuine '{}' value.",
+                input_type
+            ),
+        }
+    }
+}
@@ -1,14 +1,65 @@
+pub mod batch;
+pub mod cache;
+pub mod classify;
 pub mod core_logic;
+pub mod error;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file_cache;
+pub mod form;
+pub mod injection;
+pub mod key_pool;
+pub mod label_resolver;
+pub mod locale;
+pub mod provider;
+pub mod rate_limit;
+pub mod retry;
+pub mod rules;
+pub mod telemetry;
+pub mod runner;
+pub mod validator;
 
 pub use core_logic::{
     validate_input_with_llm_async, 
 
+    check_syntax,
     common_body_generation, 
+    generation_body,
     format_prompt, 
+    format_prompt_for,
     parse_gemini_response, 
+    parse_structured_output,
+    parse_validation_output,
+    validation_response_schema,
     pre_validate_syntactically
 };
 
+pub use batch::{BatchInput, BatchOptions, BatchResponse};
+pub use cache::{CacheKey, CacheStats, ResultCache};
+pub use classify::{detect_types, Classification, DetectionSource, LabelMismatch, TypeCandidate};
+pub use error::{redact_secrets, ValidationError, REDACTED};
+#[cfg(not(target_arch = "wasm32"))]
+pub use file_cache::FileCache;
+pub use form::{
+    ConsistencyFinding, FindingSource, FormField, FormInput, FormReport, Relationship, RelationshipKind,
+};
+pub use injection::{
+    detect_injection, format_prompt_parts, injection_signals, is_echoed_answer, is_echoed_verdict, PromptParts,
+};
+pub use key_pool::{ApiKeyPool, KeySelection, KeyUsage, DEFAULT_KEY_COOLDOWN};
+pub use label_resolver::{resolve_label, LabelResolution, MatchMethod};
+pub use locale::{LocalMessage, Locale};
+pub use provider::{
+    GeminiProvider, HttpRequest, HttpResponse, HttpTransport, LlmProvider, LlmRequest, OpenAiCompatibleProvider,
+    TransportOptions, DEFAULT_USER_AGENT,
+};
+pub use rate_limit::RateLimiter;
+pub use retry::RetryPolicy;
+pub use rules::{CharClass, TypeRule, TypeRules};
+pub use runner::{ItemResult, Progress, DEFAULT_CONCURRENCY};
+pub use validator::{
+    configure_default, configure_default_api_key, default_validator, reset_default_validator, SemanticValidator,
+    SemanticValidatorBuilder, DEFAULT_CACHE,
+};
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
-pub use core_logic::validate_input_with_llm_sync;
\ No newline at end of file
+pub use core_logic::validate_input_with_llm_sync;
@@ -0,0 +1,176 @@
+// src/core/provider/gemini.rs
+// Implementasi LlmProvider untuk endpoint `generateContent` milik Gemini API.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::core::core_logic::{first_candidate_text, generation_body};
+use crate::core::error::ValidationError;
+use crate::models::{GeminiApiResponse, ModelCapabilities};
+
+use super::{HttpRequest, HttpResponse, LlmProvider, LlmRequest, ProviderResult};
+
+/// Endpoint bawaan Gemini API (tanpa garis miring di akhir).
+pub const GEMINI_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+
+/// Provider Gemini (Google AI Studio).
+#[derive(Debug, Clone)]
+pub struct GeminiProvider {
+    base_url: String,
+    // Model kustom (misalnya model tuned) beserta kemampuannya.
+    custom_models: HashMap<String, ModelCapabilities>,
+}
+
+impl Default for GeminiProvider {
+    fn default() -> Self {
+        Self::new(GEMINI_BASE_URL)
+    }
+}
+
+impl GeminiProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            custom_models: HashMap::new(),
+        }
+    }
+
+    /// Mendaftarkan ID model kustom beserta kemampuannya. Juga dapat menimpa
+    /// kemampuan model bawaan dengan ID yang sama.
+    pub fn with_model(mut self, model_id: impl Into<String>, capabilities: ModelCapabilities) -> Self {
+        self.custom_models.insert(model_id.into(), capabilities);
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Kemampuan `model_id`: model kustom, lalu model bawaan. ID yang tidak dikenal
+    /// diperlakukan sebagai model teks biasa (`ModelCapabilities::default()`).
+    pub fn capabilities(&self, model_id: &str) -> ModelCapabilities {
+        self.custom_models
+            .get(model_id)
+            .copied()
+            .or_else(|| ModelCapabilities::for_model_id(model_id))
+            .unwrap_or_default()
+    }
+}
+
+impl LlmProvider for GeminiProvider {
+    fn name(&self) -> &str {
+        "gemini"
+    }
+
+    fn build_request(&self, request: &LlmRequest<'_>) -> ProviderResult<HttpRequest> {
+        // Kredensial dikirim lewat header, bukan query, agar tidak tercatat di
+        // log proxy maupun ikut dalam pesan error yang memuat URL.
+        let url = format!("{}/models/{}:generateContent", self.base_url, request.model);
+        let mut headers = Vec::new();
+        if !request.api_key.is_empty() {
+            headers.push(("x-goog-api-key".to_string(), request.api_key.to_string()));
+        }
+
+        let body = generation_body(
+            request.prompt,
+            request.system,
+            request.response_schema,
+            &self.capabilities(request.model),
+        );
+
+        Ok(HttpRequest { url, headers, body })
+    }
+
+    fn supports_response_schema(&self, model: &str) -> bool {
+        self.capabilities(model).enforces_schema()
+    }
+
+    fn decode_response(&self, _request: &LlmRequest<'_>, response: &HttpResponse) -> ProviderResult<String> {
+        // Parse JSON dari teks (karena response sudah diambil)
+        let json: serde_json::Value = serde_json::from_str(&response.body)
+            .map_err(|e| ValidationError::malformed(&response.body, format!("respons Gemini bukan JSON: {}", e)))?;
+
+        // Prompt atau jawaban yang diblokir filter keamanan tidak memiliki teks
+        if let Some(reason) = blocked_reason(&json) {
+            return Err(ValidationError::Blocked { reason });
+        }
+
+        let gemini_api_response: GeminiApiResponse = serde_json::from_value(json)
+            .map_err(|e| ValidationError::malformed(&response.body, e.to_string()))?;
+        first_candidate_text(gemini_api_response)
+    }
+
+    fn map_error(&self, request: &LlmRequest<'_>, response: &HttpResponse) -> ValidationError {
+        let json = serde_json::from_str::<serde_json::Value>(&response.body).ok();
+
+        // Coba ambil pesan error dari body JSON (kalau ada)
+        let message = match &json {
+            Some(json) => json["error"]["message"].as_str().unwrap_or_default().to_string(),
+            None => response.body.clone(),
+        };
+
+        // Gemini menjawab key yang salah dengan 400 + reason `API_KEY_INVALID`
+        let api_key_invalid = json
+            .as_ref()
+            .and_then(|json| json["error"]["details"].as_array())
+            .is_some_and(|details| {
+                details
+                    .iter()
+                    .any(|detail| detail["reason"].as_str() == Some("API_KEY_INVALID"))
+            });
+
+        match response.status {
+            // Tangani kasus limit API
+            429 => ValidationError::RateLimited {
+                model: request.model.to_string(),
+                retry_after: response
+                    .retry_after()
+                    .or_else(|| json.as_ref().and_then(retry_info_delay)),
+                message,
+            },
+            401 | 403 => ValidationError::Unauthorized {
+                status: response.status,
+                message,
+            },
+            status if api_key_invalid => ValidationError::Unauthorized { status, message },
+            status => ValidationError::Http {
+                status,
+                body: message,
+            },
+        }
+    }
+}
+
+// Jeda dari detail `google.rpc.RetryInfo`, misalnya `"retryDelay": "12s"` atau `"0.5s"`.
+fn retry_info_delay(json: &serde_json::Value) -> Option<Duration> {
+    json["error"]["details"]
+        .as_array()?
+        .iter()
+        .find(|detail| {
+            detail["@type"]
+                .as_str()
+                .is_some_and(|t| t.ends_with("google.rpc.RetryInfo"))
+        })
+        .and_then(|detail| detail["retryDelay"].as_str())
+        .and_then(|delay| delay.trim().strip_suffix('s'))
+        .and_then(|secs| secs.parse::<f64>().ok())
+        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+// Alasan blokir dari `promptFeedback.blockReason` atau `finishReason` kandidat pertama.
+fn blocked_reason(json: &serde_json::Value) -> Option<String> {
+    if let Some(reason) = json["promptFeedback"]["blockReason"].as_str() {
+        return Some(reason.to_string());
+    }
+
+    let candidate = &json["candidates"][0];
+    let has_text = candidate["content"]["parts"][0]["text"].is_string();
+    match candidate["finishReason"].as_str() {
+        Some(reason @ ("SAFETY" | "BLOCKLIST" | "PROHIBITED_CONTENT" | "SPII" | "RECITATION")) if !has_text => {
+            Some(reason.to_string())
+        }
+        _ => None,
+    }
+}
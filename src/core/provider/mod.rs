@@ -0,0 +1,116 @@
+// src/core/provider/mod.rs
+// Abstraksi backend LLM: membangun request, transport, decoding respons, dan pemetaan error.
+
+pub mod gemini;
+pub mod openai;
+pub mod transport;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use crate::core::error::ValidationError;
+
+pub use gemini::{GeminiProvider, GEMINI_BASE_URL};
+pub use openai::OpenAiCompatibleProvider;
+pub use transport::{HttpTransport, TransportOptions, DEFAULT_USER_AGENT};
+
+/// Hasil operasi provider. Error dibawa apa adanya ke pemanggil validator.
+pub type ProviderResult<T> = Result<T, ValidationError>;
+
+/// Future yang dikembalikan provider. Di WASM future tidak perlu (dan tidak bisa) `Send`.
+#[cfg(not(target_arch = "wasm32"))]
+pub type ProviderFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+#[cfg(target_arch = "wasm32")]
+pub type ProviderFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// Permintaan ke LLM yang netral terhadap backend.
+#[derive(Debug, Clone, Copy)]
+pub struct LlmRequest<'a> {
+    /// Nama model yang diminta validator (misal `gemini-2.5-flash`).
+    pub model: &'a str,
+    /// Instruksi sistem (aturan validasi). Jika ada, `prompt` hanya berisi nilai pengguna.
+    pub system: Option<&'a str>,
+    /// Prompt lengkap hasil `format_prompt`, atau bagian user jika `system` diisi.
+    pub prompt: &'a str,
+    /// Skema JSON keluaran (format `responseSchema` Gemini). Diabaikan provider yang
+    /// tidak mendukung structured output.
+    pub response_schema: Option<&'a serde_json::Value>,
+    /// Kredensial yang dipakai untuk request ini (boleh kosong untuk server lokal).
+    pub api_key: &'a str,
+}
+
+/// Request HTTP yang sudah dibangun provider, siap dikirim oleh transport.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: serde_json::Value,
+}
+
+/// Respons HTTP mentah yang dikembalikan transport.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// Nama header disimpan dalam huruf kecil.
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Nilai header `Retry-After` dalam bentuk detik (format HTTP-date diabaikan).
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.header("retry-after")
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+    }
+}
+
+/// Backend LLM yang dapat dipasang ke `SemanticValidator`.
+///
+/// Provider bertanggung jawab membangun request, mengubah respons sukses menjadi
+/// teks keluaran model, dan memetakan respons gagal menjadi error. Transport
+/// bawaan memakai reqwest; provider (misalnya fake untuk testing) boleh
+/// menggantinya dengan meng-override `send_blocking` dan `send`.
+pub trait LlmProvider: Send + Sync {
+    /// Nama singkat provider untuk pesan error dan diagnostik.
+    fn name(&self) -> &str;
+
+    fn build_request(&self, request: &LlmRequest<'_>) -> ProviderResult<HttpRequest>;
+
+    /// `true` jika `model` menegakkan `response_schema`, sehingga keluarannya
+    /// diperiksa ketat terhadap skema alih-alih di-parse secara toleran.
+    fn supports_response_schema(&self, _model: &str) -> bool {
+        false
+    }
+
+    /// Mengambil teks keluaran model dari respons sukses (status 2xx).
+    fn decode_response(&self, request: &LlmRequest<'_>, response: &HttpResponse) -> ProviderResult<String>;
+
+    /// Memetakan respons gagal (status bukan 2xx) menjadi error.
+    fn map_error(&self, request: &LlmRequest<'_>, response: &HttpResponse) -> ValidationError;
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    fn send_blocking(&self, transport: &HttpTransport, request: HttpRequest) -> ProviderResult<HttpResponse> {
+        transport.send_blocking(request)
+    }
+
+    fn send<'a>(
+        &'a self,
+        transport: &'a HttpTransport,
+        request: HttpRequest,
+    ) -> ProviderFuture<'a, ProviderResult<HttpResponse>> {
+        Box::pin(transport.send(request))
+    }
+}
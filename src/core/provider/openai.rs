@@ -0,0 +1,130 @@
+// src/core/provider/openai.rs
+// Implementasi LlmProvider untuk server `/v1/chat/completions` yang kompatibel dengan OpenAI
+// (OpenAI, Ollama, vLLM, llama.cpp server, dan sejenisnya).
+
+use crate::core::error::ValidationError;
+use crate::models::OpenAiChatResponse;
+
+use super::{HttpRequest, HttpResponse, LlmProvider, LlmRequest, ProviderResult};
+
+/// Provider untuk backend self-hosted yang berbicara protokol chat-completions OpenAI.
+#[derive(Debug, Clone)]
+pub struct OpenAiCompatibleProvider {
+    base_url: String,
+    model: String,
+    json_mode: bool,
+    temperature: f32,
+}
+
+impl OpenAiCompatibleProvider {
+    /// `base_url` adalah prefix sebelum `/chat/completions`, misalnya
+    /// `http://localhost:11434/v1` untuk Ollama atau `http://localhost:8000/v1` untuk vLLM.
+    /// `model` adalah nama model di server tersebut (misal `llama3.1:8b`).
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            model: model.into(),
+            json_mode: true,
+            temperature: 0.1,
+        }
+    }
+
+    /// Mengaktifkan/mematikan `response_format: json_object`.
+    /// Matikan untuk server yang menolak field tersebut.
+    pub fn json_mode(mut self, enabled: bool) -> Self {
+        self.json_mode = enabled;
+        self
+    }
+
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+}
+
+impl LlmProvider for OpenAiCompatibleProvider {
+    fn name(&self) -> &str {
+        "openai-compatible"
+    }
+
+    fn build_request(&self, request: &LlmRequest<'_>) -> ProviderResult<HttpRequest> {
+        let mut body = serde_json::json!({
+            "model": self.model,
+            "messages": [
+                { "role": "user", "content": request.prompt }
+            ],
+            "temperature": self.temperature,
+            "stream": false
+        });
+
+        if let (Some(system), Some(messages)) = (request.system, body["messages"].as_array_mut()) {
+            messages.insert(0, serde_json::json!({ "role": "system", "content": system }));
+        }
+
+        if self.json_mode {
+            if let Some(map) = body.as_object_mut() {
+                map.insert(
+                    "response_format".to_string(),
+                    serde_json::json!({ "type": "json_object" }),
+                );
+            }
+        }
+
+        // Server lokal (Ollama, llama.cpp) biasanya tidak memerlukan API key.
+        let mut headers = Vec::new();
+        if !request.api_key.is_empty() {
+            headers.push(("Authorization".to_string(), format!("Bearer {}", request.api_key)));
+        }
+
+        Ok(HttpRequest {
+            url: format!("{}/chat/completions", self.base_url),
+            headers,
+            body,
+        })
+    }
+
+    fn decode_response(&self, _request: &LlmRequest<'_>, response: &HttpResponse) -> ProviderResult<String> {
+        let chat_response: OpenAiChatResponse = serde_json::from_str(&response.body)
+            .map_err(|e| ValidationError::malformed(&response.body, e.to_string()))?;
+        chat_response
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .ok_or_else(|| ValidationError::malformed(&response.body, "Gagal mengekstrak teks dari respons LLM."))
+    }
+
+    fn map_error(&self, _request: &LlmRequest<'_>, response: &HttpResponse) -> ValidationError {
+        // OpenAI: {"error": {"message": ...}}, Ollama: {"error": "..."}
+        let message = match serde_json::from_str::<serde_json::Value>(&response.body) {
+            Ok(json) => json["error"]["message"]
+                .as_str()
+                .or_else(|| json["error"].as_str())
+                .unwrap_or_default()
+                .to_string(),
+            Err(_) => response.body.clone(),
+        };
+
+        match response.status {
+            429 => ValidationError::RateLimited {
+                model: self.model.clone(),
+                retry_after: response.retry_after(),
+                message,
+            },
+            401 | 403 => ValidationError::Unauthorized {
+                status: response.status,
+                message,
+            },
+            status => ValidationError::Http { status, body: message },
+        }
+    }
+}
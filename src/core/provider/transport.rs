@@ -0,0 +1,188 @@
+// src/core/provider/transport.rs
+// Transport HTTP bersama (reqwest) untuk jalur sinkron dan asinkron.
+
+use std::time::Duration;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+use once_cell::sync::OnceCell;
+#[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+use reqwest::blocking::Client as BlockingClient;
+
+use reqwest::Client as AsyncClient;
+
+use crate::core::error::ValidationError;
+use crate::core::validator::DEFAULT_TIMEOUT;
+
+use super::{HttpRequest, HttpResponse, ProviderResult};
+
+/// User agent bawaan untuk semua request HTTP.
+pub const DEFAULT_USER_AGENT: &str = concat!("validation_semantic/", env!("CARGO_PKG_VERSION"));
+
+/// Pengaturan koneksi HTTP untuk [`HttpTransport`].
+///
+/// Di WASM koneksi diatur oleh browser, sehingga semua opsi ini diabaikan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportOptions {
+    /// Batas waktu total satu request, termasuk membaca body respons.
+    pub timeout: Duration,
+    /// Batas waktu membuka koneksi (TCP + TLS). `None` memakai bawaan reqwest.
+    pub connect_timeout: Option<Duration>,
+    /// URL proxy HTTP/HTTPS untuk semua request. Tanpa ini, proxy dari variabel
+    /// lingkungan sistem (`HTTPS_PROXY`, dll) tetap dipakai.
+    pub proxy: Option<String>,
+    /// Sertifikat root CA tambahan dalam format PEM (satu entri boleh berisi beberapa sertifikat).
+    pub root_certificates: Vec<Vec<u8>>,
+    pub user_agent: Option<String>,
+}
+
+impl Default for TransportOptions {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            user_agent: Some(DEFAULT_USER_AGENT.to_string()),
+        }
+    }
+}
+
+// Builder async dan blocking punya method yang sama tetapi tipenya berbeda.
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! apply_options {
+    ($builder:expr, $options:expr) => {{
+        let options: &TransportOptions = $options;
+        let mut builder = $builder.timeout(options.timeout);
+        if let Some(connect_timeout) = options.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = &options.proxy {
+            // URL proxy bisa memuat kredensial, jadi tidak disertakan di pesan error.
+            let proxy = reqwest::Proxy::all(proxy.as_str())
+                .map_err(|_| ValidationError::Config("URL proxy tidak valid.".to_string()))?;
+            builder = builder.proxy(proxy);
+        }
+        for pem in &options.root_certificates {
+            for certificate in parse_certificates(pem)? {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        if let Some(user_agent) = &options.user_agent {
+            builder = builder.user_agent(user_agent.as_str());
+        }
+        builder
+    }};
+}
+
+/// Pemegang HTTP client yang dipakai ulang oleh satu validator.
+pub struct HttpTransport {
+    options: TransportOptions,
+    async_client: AsyncClient,
+    // Blocking client dibuat saat pertama kali dipakai, karena membangunnya
+    // di dalam runtime async (misalnya tokio) akan panic.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    blocking_client: OnceCell<BlockingClient>,
+}
+
+impl HttpTransport {
+    pub fn new(timeout: Duration) -> ProviderResult<Self> {
+        Self::with_options(TransportOptions {
+            timeout,
+            ..TransportOptions::default()
+        })
+    }
+
+    /// Membangun transport dengan pengaturan koneksi lengkap. Proxy dan
+    /// sertifikat yang tidak valid langsung dilaporkan sebagai `ValidationError::Config`.
+    pub fn with_options(options: TransportOptions) -> ProviderResult<Self> {
+        let async_client = {
+            let builder = AsyncClient::builder();
+
+            #[cfg(not(target_arch = "wasm32"))]
+            let builder = apply_options!(builder, &options);
+
+            builder.build().map_err(client_build_error)?
+        };
+
+        Ok(Self {
+            options,
+            async_client,
+            #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+            blocking_client: OnceCell::new(),
+        })
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.options.timeout
+    }
+
+    pub fn options(&self) -> &TransportOptions {
+        &self.options
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn send_blocking(&self, request: HttpRequest) -> ProviderResult<HttpResponse> {
+        let client = self
+            .blocking_client
+            .get_or_try_init(|| -> ProviderResult<BlockingClient> {
+                apply_options!(BlockingClient::builder(), &self.options)
+                    .build()
+                    .map_err(client_build_error)
+            })?;
+
+        let mut builder = client.post(&request.url).json(&request.body);
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+
+        let response = builder.send()?;
+        let status = response.status().as_u16();
+        let headers = collect_headers(response.headers());
+        let body = response.text()?; // simpan hasil text dulu
+
+        Ok(HttpResponse { status, headers, body })
+    }
+
+    pub async fn send(&self, request: HttpRequest) -> ProviderResult<HttpResponse> {
+        let mut builder = self.async_client.post(&request.url).json(&request.body);
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+
+        // Kirim permintaan dan dapatkan responsnya
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        let headers = collect_headers(response.headers());
+        let body = response.text().await?; // Ambil body sebagai string
+
+        Ok(HttpResponse { status, headers, body })
+    }
+}
+
+fn client_build_error(e: reqwest::Error) -> ValidationError {
+    ValidationError::Config(format!("Failed to build HTTP client: {}", e))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_certificates(pem: &[u8]) -> ProviderResult<Vec<reqwest::Certificate>> {
+    let certificates = reqwest::Certificate::from_pem_bundle(pem)
+        .map_err(|e| ValidationError::Config(format!("Sertifikat root CA tidak valid: {}", e)))?;
+    if certificates.is_empty() {
+        return Err(ValidationError::Config(
+            "Sertifikat root CA tidak berisi blok PEM `CERTIFICATE`.".to_string(),
+        ));
+    }
+    Ok(certificates)
+}
+
+fn collect_headers(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|v| (name.as_str().to_string(), v.to_string()))
+        })
+        .collect()
+}
@@ -0,0 +1,65 @@
+// src/core/rate_limit.rs
+// Pembatas requests-per-minute per model, dibagi semua pemanggilan satu validator.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::core::cache::now_millis;
+
+/// Pembatas laju per model dengan jeda merata: batas `n` RPM berarti request
+/// ke model itu diberi jarak minimal `60 / n` detik. Model tanpa batas tidak ditunda.
+///
+/// Setiap percobaan HTTP (termasuk retry dan model cadangan) memakai satu slot,
+/// sehingga batas tetap terjaga walaupun banyak validasi berjalan paralel.
+#[derive(Default)]
+pub struct RateLimiter {
+    models: Mutex<HashMap<String, ModelWindow>>,
+}
+
+struct ModelWindow {
+    requests_per_minute: u32,
+    next_slot_ms: u64,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mengatur batas RPM untuk `model`; `0` menghapus batasnya.
+    pub fn set_limit(&self, model: &str, requests_per_minute: u32) {
+        let mut models = self.lock();
+        if requests_per_minute == 0 {
+            models.remove(model);
+            return;
+        }
+        let window = models.entry(model.to_string()).or_insert(ModelWindow {
+            requests_per_minute,
+            next_slot_ms: 0,
+        });
+        window.requests_per_minute = requests_per_minute;
+    }
+
+    pub fn limit(&self, model: &str) -> Option<u32> {
+        self.lock().get(model).map(|window| window.requests_per_minute)
+    }
+
+    /// Memesan slot berikutnya untuk `model` dan mengembalikan lama tunggu sampai slot itu.
+    pub(crate) fn reserve(&self, model: &str) -> Duration {
+        let mut models = self.lock();
+        let Some(window) = models.get_mut(model) else {
+            return Duration::ZERO;
+        };
+
+        let now = now_millis();
+        let interval = 60_000u64.div_ceil(u64::from(window.requests_per_minute));
+        let slot = window.next_slot_ms.max(now);
+        window.next_slot_ms = slot + interval;
+        Duration::from_millis(slot - now)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, ModelWindow>> {
+        self.models.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -0,0 +1,167 @@
+// src/core/retry.rs
+// Kebijakan retry dengan exponential backoff untuk request ke LLM.
+
+use std::time::Duration;
+
+use crate::core::error::ValidationError;
+
+/// Kebijakan retry untuk satu pemanggilan LLM.
+///
+/// Percobaan ulang dilakukan untuk status HTTP di `retry_on_status` dan (opsional)
+/// error transport/timeout. Jeda dihitung secara eksponensial dari `base_delay`,
+/// kecuali server menyarankan jeda sendiri lewat `Retry-After` atau `RetryInfo`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Jumlah total percobaan, termasuk percobaan pertama. `1` berarti tanpa retry.
+    pub max_attempts: u32,
+    /// Jeda sebelum retry pertama; berlipat dua di setiap retry berikutnya.
+    pub base_delay: Duration,
+    /// Batas atas jeda, termasuk jeda yang disarankan server.
+    pub max_delay: Duration,
+    /// Mengacak jeda backoff ke rentang 50%–100% agar klien tidak retry bersamaan.
+    pub jitter: bool,
+    /// Status HTTP yang layak dicoba ulang.
+    pub retry_on_status: Vec<u16>,
+    /// Mencoba ulang kegagalan koneksi dan timeout.
+    pub retry_transport: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_on_status: vec![429, 500, 502, 503, 504],
+            retry_transport: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Kebijakan tanpa retry: error langsung dikembalikan ke pemanggil.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn retry_on_status(mut self, statuses: impl Into<Vec<u16>>) -> Self {
+        self.retry_on_status = statuses.into();
+        self
+    }
+
+    pub fn retry_transport(mut self, retry_transport: bool) -> Self {
+        self.retry_transport = retry_transport;
+        self
+    }
+
+    /// Apakah `error` pada percobaan ke-`attempt` (mulai dari 1) perlu dicoba ulang.
+    pub fn should_retry(&self, attempt: u32, error: &ValidationError) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        match error {
+            ValidationError::Transport(_) | ValidationError::Timeout(_) => self.retry_transport,
+            _ => error
+                .status()
+                .is_some_and(|status| self.retry_on_status.contains(&status)),
+        }
+    }
+
+    /// Jeda sebelum percobaan berikutnya setelah percobaan ke-`attempt` gagal.
+    pub fn delay_for(&self, attempt: u32, error: &ValidationError) -> Duration {
+        if let Some(server_delay) = error.retry_after() {
+            return server_delay.min(self.max_delay);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            backoff.mul_f64(0.5 + 0.5 * random_unit())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Menunggu secara blocking (jalur sinkron native).
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn sleep_blocking(duration: Duration) {
+    std::thread::sleep(duration);
+}
+
+/// Menunggu tanpa memblokir executor.
+#[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Menunggu tanpa memblokir executor. Di WASM tidak ada timer tokio, jadi
+/// dipakai `setTimeout` milik host (browser, worker, atau Node) lewat Promise.
+#[cfg(all(target_arch = "wasm32", feature = "wasm_bindings_setup"))]
+pub(crate) async fn sleep(duration: Duration) {
+    use wasm_bindgen::{JsCast, JsValue};
+
+    let millis = duration.as_millis().min(i32::MAX as u128) as i32;
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        let set_timeout = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("setTimeout"))
+            .ok()
+            .and_then(|f| f.dyn_into::<js_sys::Function>().ok());
+        match set_timeout {
+            Some(set_timeout) => {
+                let _ = set_timeout.call2(&JsValue::NULL, &resolve, &JsValue::from(millis));
+            }
+            // Host tanpa timer: lanjutkan tanpa jeda daripada menggantung selamanya.
+            None => {
+                let _ = resolve.call0(&JsValue::NULL);
+            }
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+// Bilangan acak [0, 1) untuk jitter; tidak perlu kriptografis.
+#[cfg(all(target_arch = "wasm32", feature = "wasm_bindings_setup"))]
+fn random_unit() -> f64 {
+    js_sys::Math::random()
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm_bindings_setup")))]
+fn random_unit() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,343 @@
+// src/core/rules.rs
+// Tipe input buatan pengguna yang dimuat dari file aturan TOML atau YAML.
+
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::core::cache::canonical_input_type;
+use crate::core::error::ValidationError;
+use crate::core::locale::{LocalMessage, Locale};
+
+/// Kelas karakter yang wajib muncul minimal sekali dalam input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CharClass {
+    Digit,
+    Letter,
+    Uppercase,
+    Lowercase,
+    Symbol,
+}
+
+impl CharClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CharClass::Digit => "digit",
+            CharClass::Letter => "letter",
+            CharClass::Uppercase => "uppercase",
+            CharClass::Lowercase => "lowercase",
+            CharClass::Symbol => "symbol",
+        }
+    }
+
+    pub fn matches(&self, c: char) -> bool {
+        match self {
+            CharClass::Digit => c.is_numeric(),
+            CharClass::Letter => c.is_alphabetic(),
+            CharClass::Uppercase => c.is_uppercase(),
+            CharClass::Lowercase => c.is_lowercase(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+
+    /// Nama kelas karakter untuk pesan error dalam `locale`.
+    pub fn describe(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::Id, CharClass::Digit) => "angka",
+            (Locale::Id, CharClass::Letter) => "huruf",
+            (Locale::Id, CharClass::Uppercase) => "huruf besar",
+            (Locale::Id, CharClass::Lowercase) => "huruf kecil",
+            (Locale::Id, CharClass::Symbol) => "simbol",
+            (Locale::En, CharClass::Digit) => "a digit",
+            (Locale::En, CharClass::Letter) => "a letter",
+            (Locale::En, CharClass::Uppercase) => "an uppercase letter",
+            (Locale::En, CharClass::Lowercase) => "a lowercase letter",
+            (Locale::En, CharClass::Symbol) => "a symbol",
+        }
+    }
+}
+
+/// Definisi satu tipe input dalam file aturan.
+///
+/// Contoh TOML:
+///
+/// ```toml
+/// [[types]]
+/// name = "kode voucher"
+/// aliases = ["voucher", "voucher code"]
+/// min_length = 6
+/// max_length = 12
+/// pattern = "^[A-Z0-9-]+$"
+/// require = ["digit", "uppercase"]
+/// prompt_rules = ["Kode harus diawali prefix toko 'VC'."]
+/// valid_examples = ["VC-2024-AB"]
+/// invalid_examples = ["asdfgh"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeRule {
+    /// Nama kanonis tipe; juga berlaku sebagai alias.
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Penjelasan singkat tipe untuk prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Panjang minimal input (dalam karakter, setelah trim).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+    /// Panjang maksimal input (dalam karakter, setelah trim).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+    /// Regex yang harus cocok dengan input (gunakan `^...$` untuk seluruh input).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Kelas karakter yang wajib ada.
+    #[serde(default)]
+    pub require: Vec<CharClass>,
+    /// Aturan semantik tambahan untuk LLM.
+    #[serde(default)]
+    pub prompt_rules: Vec<String>,
+    /// Contoh input valid (few-shot).
+    #[serde(default)]
+    pub valid_examples: Vec<String>,
+    /// Contoh input tidak valid (few-shot).
+    #[serde(default)]
+    pub invalid_examples: Vec<String>,
+    /// Bahasa pesan khusus tipe ini; menimpa locale validator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Locale>,
+}
+
+impl TypeRule {
+    /// Semua label yang merujuk ke tipe ini: nama lalu alias.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+
+    /// Bagian tugas prompt untuk `user_input`, tanpa instruksi metadata putusan.
+    /// Pesan diminta dalam `language` tipe ini, atau bahasa Indonesia jika kosong.
+    pub fn task_prompt(&self, user_input: &str) -> String {
+        self.task_prompt_for(user_input, Locale::default())
+    }
+
+    /// Seperti `task_prompt`, dengan `locale` sebagai bahasa pesan jika tipe ini tidak menentukan `language`.
+    pub fn task_prompt_for(&self, user_input: &str, locale: Locale) -> String {
+        let language = self.language.unwrap_or(locale).language_name();
+        let mut prompt = format!(
+            "Note: This input has passed basic syntactic validation. \
+Focus on semantic validity, reasonableness, and relevant business rules. \
+Reject meaningless, dummy, or random input.\n\
+Validate the following input, which is claimed to be of type \"{}\".\n",
+            self.name
+        );
+        if let Some(description) = &self.description {
+            prompt.push_str(&format!("Type description: {}\n", description));
+        }
+        prompt.push_str(&format!("Input: \"{}\"\n", user_input.replace('"', "\\\"")));
+
+        prompt.push_str("\nValidation rules:\n");
+        prompt.push_str("- Reject gibberish, placeholders, and generic dummy data.\n");
+        prompt.push_str(&format!(
+            "- The input must semantically match the intended type \"{}\".\n",
+            self.name
+        ));
+        for rule in &self.prompt_rules {
+            prompt.push_str(&format!("- {}\n", rule));
+        }
+        push_examples(&mut prompt, "Examples of VALID input:", &self.valid_examples);
+        push_examples(&mut prompt, "Examples of INVALID input:", &self.invalid_examples);
+
+        prompt.push_str(&format!(
+            "\nRespond ONLY with a raw JSON object (no Markdown, no extra text):\n\
+{{\"valid\": true or false, \"message\": \"short explanation in {}, with a correction suggestion if invalid\"}}\n",
+            language
+        ));
+        prompt
+    }
+}
+
+fn push_examples(prompt: &mut String, heading: &str, examples: &[String]) {
+    if examples.is_empty() {
+        return;
+    }
+    prompt.push_str(&format!("\n{}\n", heading));
+    for example in examples {
+        prompt.push_str(&format!("- \"{}\"\n", example.replace('"', "\\\"")));
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(default)]
+    types: Vec<TypeRule>,
+}
+
+/// Kumpulan tipe input buatan pengguna yang sudah divalidasi dan siap dipakai.
+///
+/// Label dicocokkan tanpa membedakan huruf besar/kecil. Tipe di sini didahulukan
+/// dari tipe bawaan dengan label yang sama, sehingga tim bisa menimpa aturan
+/// bawaan seperti "nama institusi".
+#[derive(Debug, Clone, Default)]
+pub struct TypeRules {
+    rules: Vec<CompiledRule>,
+    labels: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    rule: TypeRule,
+    pattern: Option<Regex>,
+}
+
+impl TypeRules {
+    /// Membangun kumpulan aturan, memeriksa nama kosong, regex, batas panjang, dan label ganda.
+    pub fn new(rules: Vec<TypeRule>) -> Result<Self, ValidationError> {
+        let mut compiled = Vec::with_capacity(rules.len());
+        let mut labels = HashMap::new();
+        for (index, rule) in rules.into_iter().enumerate() {
+            if rule.name.trim().is_empty() {
+                return Err(ValidationError::Config(format!(
+                    "Tipe ke-{} dalam file aturan tidak memiliki nama.",
+                    index + 1
+                )));
+            }
+            if let (Some(min), Some(max)) = (rule.min_length, rule.max_length) {
+                if min > max {
+                    return Err(ValidationError::Config(format!(
+                        "Tipe '{}': min_length ({}) lebih besar dari max_length ({}).",
+                        rule.name, min, max
+                    )));
+                }
+            }
+            let pattern = rule
+                .pattern
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|e| {
+                    ValidationError::Config(format!("Tipe '{}': pattern tidak valid: {}", rule.name, e))
+                })?;
+            for label in rule.labels() {
+                if labels.insert(canonical_input_type(label), index).is_some() {
+                    return Err(ValidationError::Config(format!(
+                        "Label '{}' dipakai lebih dari sekali dalam file aturan.",
+                        label
+                    )));
+                }
+            }
+            compiled.push(CompiledRule { rule, pattern });
+        }
+        Ok(Self { rules: compiled, labels })
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, ValidationError> {
+        let file: RulesFile = toml::from_str(text)
+            .map_err(|e| ValidationError::Config(format!("File aturan TOML tidak valid: {}", e)))?;
+        Self::new(file.types)
+    }
+
+    pub fn from_yaml_str(text: &str) -> Result<Self, ValidationError> {
+        let file: RulesFile = serde_yaml::from_str(text)
+            .map_err(|e| ValidationError::Config(format!("File aturan YAML tidak valid: {}", e)))?;
+        Self::new(file.types)
+    }
+
+    /// Memuat file aturan; format ditentukan dari ekstensi (`.toml`, `.yaml`, atau `.yml`).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ValidationError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            ValidationError::Config(format!("Gagal membaca file aturan '{}': {}", path.display(), e))
+        })?;
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        match extension.as_str() {
+            "toml" => Self::from_toml_str(&text),
+            "yaml" | "yml" => Self::from_yaml_str(&text),
+            _ => Err(ValidationError::Config(format!(
+                "Ekstensi file aturan '{}' tidak dikenali. Gunakan .toml, .yaml, atau .yml.",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Mem-parse teks aturan tanpa ekstensi file: TOML dicoba lebih dulu, lalu YAML.
+    pub fn parse(text: &str) -> Result<Self, ValidationError> {
+        match toml::from_str::<RulesFile>(text) {
+            Ok(file) => Self::new(file.types),
+            Err(toml_error) => Self::from_yaml_str(text).map_err(|yaml_error| {
+                ValidationError::Config(format!(
+                    "File aturan bukan TOML maupun YAML yang valid. TOML: {} | {}",
+                    toml_error, yaml_error
+                ))
+            }),
+        }
+    }
+
+    /// Tipe yang cocok dengan `label` (tanpa membedakan huruf besar/kecil).
+    pub fn get(&self, label: &str) -> Option<&TypeRule> {
+        self.compiled(label).map(|compiled| &compiled.rule)
+    }
+
+    pub fn types(&self) -> impl Iterator<Item = &TypeRule> {
+        self.rules.iter().map(|compiled| &compiled.rule)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Pemeriksaan lokal untuk tipe buatan pengguna; `None` jika `label` tidak terdaftar.
+    pub fn check(&self, label: &str, user_input: &str) -> Option<Result<(), LocalMessage>> {
+        let compiled = self.compiled(label)?;
+        Some(compiled.check(user_input))
+    }
+
+    fn compiled(&self, label: &str) -> Option<&CompiledRule> {
+        self.labels.get(&canonical_input_type(label)).map(|&index| &self.rules[index])
+    }
+}
+
+impl CompiledRule {
+    fn check(&self, user_input: &str) -> Result<(), LocalMessage> {
+        let input = user_input.trim();
+        if input.is_empty() {
+            return Err(LocalMessage::EmptyInput);
+        }
+
+        let input_type = self.rule.name.clone();
+        let length = input.chars().count();
+        if let Some(min) = self.rule.min_length {
+            if length < min {
+                return Err(LocalMessage::LengthBelow { input_type, min });
+            }
+        }
+        if let Some(max) = self.rule.max_length {
+            if length > max {
+                return Err(LocalMessage::LengthAbove { input_type, max });
+            }
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(input) {
+                return Err(LocalMessage::PatternMismatch { input_type });
+            }
+        }
+        if let Some(&class) = self.rule.require.iter().find(|class| !input.chars().any(|c| class.matches(c))) {
+            return Err(LocalMessage::MissingCharClass { input_type, class });
+        }
+        Ok(())
+    }
+}
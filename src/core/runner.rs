@@ -0,0 +1,104 @@
+// src/core/runner.rs
+// Menjalankan validasi banyak input secara paralel dengan batas konkurensi.
+
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::Serialize;
+
+use crate::core::batch::BatchInput;
+use crate::core::error::ValidationError;
+use crate::core::validator::SemanticValidator;
+use crate::models::{SupportedModel, ValidationResponse};
+
+/// Jumlah validasi yang berjalan bersamaan jika pemanggil tidak menentukan.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Hasil validasi satu item dalam runner.
+pub type ItemResult = Result<ValidationResponse, ValidationError>;
+
+/// Kemajuan runner, dilaporkan setiap kali satu item selesai.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Progress {
+    pub completed: usize,
+    pub total: usize,
+    /// Item yang berakhir dengan error (bukan sekadar `valid: false`).
+    pub failed: usize,
+}
+
+// Runtime bersama untuk jalur sinkron, agar HTTP client async (dan pool koneksinya)
+// tidak terikat pada runtime yang dibuang setelah setiap pemanggilan.
+#[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+static RUNTIME: once_cell::sync::Lazy<tokio::runtime::Runtime> = once_cell::sync::Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .thread_name("validation-semantic-runner")
+        .enable_all()
+        .build()
+        .expect("Gagal membuat runtime tokio untuk runner validasi")
+});
+
+impl SemanticValidator {
+    /// Memvalidasi setiap item sebagai request terpisah dengan paling banyak
+    /// `concurrency` request berjalan bersamaan. Hasil keluar sesuai urutan selesai,
+    /// berpasangan dengan indeks item aslinya.
+    ///
+    /// Batas requests-per-minute per model dari validator tetap berlaku.
+    pub fn validate_stream<'a>(
+        &'a self,
+        items: &'a [BatchInput],
+        model: SupportedModel,
+        concurrency: usize,
+    ) -> impl Stream<Item = (usize, ItemResult)> + 'a {
+        stream::iter(items.iter().enumerate())
+            .map(move |(index, item)| async move {
+                let result = self
+                    .validate_with_model_name_async(&item.value, model.as_str(), &item.input_type)
+                    .await;
+                (index, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Memvalidasi semua item secara paralel dan mengembalikan hasilnya sesuai
+    /// urutan input. `on_progress` dipanggil setiap kali satu item selesai.
+    pub async fn validate_many_async(
+        &self,
+        items: &[BatchInput],
+        model: SupportedModel,
+        concurrency: usize,
+        mut on_progress: impl FnMut(Progress),
+    ) -> Vec<ItemResult> {
+        let mut slots: Vec<Option<ItemResult>> = items.iter().map(|_| None).collect();
+        let mut progress = Progress {
+            completed: 0,
+            total: items.len(),
+            failed: 0,
+        };
+
+        let mut results = std::pin::pin!(self.validate_stream(items, model, concurrency));
+        while let Some((index, result)) = results.next().await {
+            progress.completed += 1;
+            if result.is_err() {
+                progress.failed += 1;
+            }
+            slots[index] = Some(result);
+            on_progress(progress);
+        }
+
+        slots
+            .into_iter()
+            .map(|slot| slot.expect("setiap item menghasilkan tepat satu hasil"))
+            .collect()
+    }
+
+    /// Versi blocking dari `validate_many_async` untuk binding sinkron.
+    /// Jangan dipanggil dari dalam runtime tokio.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "native_ffi_setup"))]
+    pub fn validate_many_sync(
+        &self,
+        items: &[BatchInput],
+        model: SupportedModel,
+        concurrency: usize,
+        on_progress: impl FnMut(Progress),
+    ) -> Vec<ItemResult> {
+        RUNTIME.block_on(self.validate_many_async(items, model, concurrency, on_progress))
+    }
+}
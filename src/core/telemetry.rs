@@ -0,0 +1,41 @@
+// src/core/telemetry.rs
+// Diagnostik lewat facade `tracing`: span per request dengan ID korelasi, dan
+// penyamaran input pengguna. Library tidak pernah menulis ke stdout; aplikasi
+// yang ingin melihat log memasang subscriber sendiri (misalnya `tracing-subscriber`).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::core::error::ValidationError;
+use crate::models::ValidationResponse;
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// ID korelasi baru, unik dalam satu proses. Dicatat sebagai field `request_id`
+/// pada span `validate`/`validate_batch` sehingga semua event satu request bisa dikelompokkan.
+pub fn next_request_id() -> u64 {
+    NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Bentuk input pengguna yang aman untuk log. Tanpa `reveal`, hanya panjangnya yang dicatat.
+pub fn redact_input(user_input: &str, reveal: bool) -> String {
+    if reveal {
+        user_input.to_string()
+    } else {
+        format!("<disamarkan, {} karakter>", user_input.chars().count())
+    }
+}
+
+// Event penutup satu request. Error hanya dicatat kodenya: pesannya bisa memuat keluaran mentah model.
+pub(crate) fn log_outcome(result: &Result<ValidationResponse, ValidationError>) {
+    match result {
+        Ok(response) => tracing::info!(
+            valid = response.valid,
+            stage = response.stage.as_str(),
+            code = response.code.as_str(),
+            model = response.model.as_deref().unwrap_or(""),
+            latency_ms = response.latency_ms,
+            "validasi selesai"
+        ),
+        Err(err) => tracing::warn!(error = err.code(), status = err.status(), "validasi gagal"),
+    }
+}
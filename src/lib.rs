@@ -15,7 +15,11 @@ pub mod utils;
 pub mod python_entry;
 
 #[cfg(feature = "native_ffi_setup")]
-pub use crate::bindings::c_ffi::{free_rust_string, validate_text_ffi};
+pub use crate::bindings::c_ffi::{
+    cache_clear_ffi, cache_configure_ffi, cache_stats_ffi, check_form_ffi, classify_input_ffi, configure_api_key_ffi,
+    free_rust_string, list_input_types_ffi, reset_configuration_ffi, resolve_input_type_ffi, resolve_label_ffi,
+    validate_batch_ffi, validate_text_ffi,
+};
 // Dan juga re-export tipe yang mungkin dibutuhkan oleh main.rs untuk pengujian
 // #[cfg(feature = "native_ffi_setup")]
 // pub use crate::core_logic::{SupportedModel, ValidationResponse};
@@ -0,0 +1,195 @@
+// src/models/input_type.rs
+// Daftar tipe input bawaan beserta aliasnya, satu-satunya sumber untuk pencocokan label.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+/// Tipe input bawaan yang dikenali validator.
+///
+/// Label dari pengguna dipetakan ke tipe kanonis lewat [`InputType::resolve`]
+/// (tanpa membedakan huruf besar/kecil, spasi berlebih dirapatkan).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputType {
+    Email,
+    Url,
+    Phone,
+    Username,
+    PersonName,
+    Institution,
+    Company,
+    Product,
+    Location,
+    Title,
+    Occupation,
+    Tag,
+    Address,
+    Content,
+    IdentityNumber,
+    Date,
+    Number,
+}
+
+static ALIAS_INDEX: Lazy<HashMap<&'static str, InputType>> = Lazy::new(|| {
+    InputType::ALL
+        .iter()
+        .flat_map(|input_type| input_type.aliases().iter().map(move |alias| (*alias, *input_type)))
+        .collect()
+});
+
+impl InputType {
+    pub const ALL: [InputType; 17] = [
+        InputType::Email,
+        InputType::Url,
+        InputType::Phone,
+        InputType::Username,
+        InputType::PersonName,
+        InputType::Institution,
+        InputType::Company,
+        InputType::Product,
+        InputType::Location,
+        InputType::Title,
+        InputType::Occupation,
+        InputType::Tag,
+        InputType::Address,
+        InputType::Content,
+        InputType::IdentityNumber,
+        InputType::Date,
+        InputType::Number,
+    ];
+
+    /// Tipe kanonis untuk `label`, atau `None` jika label tidak dikenal.
+    pub fn resolve(label: &str) -> Option<InputType> {
+        let normalized = label.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        ALIAS_INDEX.get(normalized.as_str()).copied()
+    }
+
+    /// Id stabil (snake_case) untuk dibaca mesin.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputType::Email => "email",
+            InputType::Url => "url",
+            InputType::Phone => "phone",
+            InputType::Username => "username",
+            InputType::PersonName => "person_name",
+            InputType::Institution => "institution",
+            InputType::Company => "company",
+            InputType::Product => "product",
+            InputType::Location => "location",
+            InputType::Title => "title",
+            InputType::Occupation => "occupation",
+            InputType::Tag => "tag",
+            InputType::Address => "address",
+            InputType::Content => "content",
+            InputType::IdentityNumber => "identity_number",
+            InputType::Date => "date",
+            InputType::Number => "number",
+        }
+    }
+
+    /// Label utama (alias pertama), cocok untuk ditampilkan dan dikirim kembali ke validator.
+    pub fn label(&self) -> &'static str {
+        self.aliases()[0]
+    }
+
+    /// Deskripsi singkat untuk UI.
+    pub fn description(&self) -> &'static str {
+        match self {
+            InputType::Email => "Alamat email dengan domain yang nyata (bukan domain dummy).",
+            InputType::Url => "Link web, domain, atau URL situs.",
+            InputType::Phone => "Nomor telepon atau HP (7-15 digit).",
+            InputType::Username => "Nama pengguna/handle akun.",
+            InputType::PersonName => "Nama orang yang wajar.",
+            InputType::Institution => "Nama institusi, organisasi, atau lembaga.",
+            InputType::Company => "Nama perusahaan atau badan usaha (PT, CV, Inc, dll).",
+            InputType::Product => "Nama produk, barang, atau item.",
+            InputType::Location => "Nama tempat, venue, atau area.",
+            InputType::Title => "Judul, subjek, atau headline.",
+            InputType::Occupation => "Pekerjaan, jabatan, atau profesi.",
+            InputType::Tag => "Tag, kategori, atau kata kunci.",
+            InputType::Address => "Alamat fisik (jalan, kota, kode pos).",
+            InputType::Content => "Teks panjang seperti deskripsi, komentar, atau ulasan.",
+            InputType::IdentityNumber => "Nomor identitas seperti NIK, KTP, NPWP, atau paspor.",
+            InputType::Date => "Tanggal atau waktu, misalnya tanggal lahir.",
+            InputType::Number => "Nilai angka seperti umur, harga, atau gaji.",
+        }
+    }
+
+    /// Semua label yang dikenali untuk tipe ini (huruf kecil), dimulai dari label utama.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            InputType::Email => &["email", "alamat email", "email address", "mail"],
+            InputType::Url => &["website", "url", "link", "tautan", "situs", "domain", "homepage", "web"],
+            InputType::Phone => &["nomor hp", "nomor hp indonesia", "phone", "phone number", "no hp", "mobile", "tel"],
+            InputType::Username => &["username", "nama pengguna", "handle", "user id", "account name", "id pengguna"],
+            InputType::PersonName => &[
+                "nama", "nama lengkap", "full name", "name", "complete name", "nickname", "first name", "last name",
+            ],
+            InputType::Institution => &[
+                "nama institusi", "nama lembaga", "institusi", "lembaga",
+                "institution name", "organization name", "institution", "organization", "agency", "institute",
+            ],
+            InputType::Company => &[
+                "nama perusahaan", "perusahaan", "company name", "company", "business name", "business", "corporate",
+            ],
+            InputType::Product => &[
+                "nama produk", "product name", "produk", "product",
+                "nama barang", "barang", "item name", "item",
+                "nama item", "merchandise", "goods", "komoditas", "jenis barang",
+            ],
+            InputType::Location => &[
+                "nama lokasi", "lokasi", "tempat", "location name", "location", "place", "venue", "spot", "area",
+            ],
+            InputType::Title => &["judul", "title", "subject", "headline", "caption", "topic"],
+            InputType::Occupation => &[
+                "pekerjaan", "job", "occupation", "profesi", "profession", "jabatan", "role", "peran",
+                "posisi", "position", "karir", "career", "job title",
+            ],
+            InputType::Tag => &["tag", "kategori", "category", "label", "keyword", "tags"],
+            InputType::Address => &["alamat", "address", "home address", "street address", "domicile"],
+            InputType::Content => &[
+                "deskripsi", "text area", "teks area", "konten", "blog", "cerita", "komentar",
+                "content", "description", "story", "comment", "body", "message", "post", "article", "review", "summary",
+            ],
+            InputType::IdentityNumber => &[
+                "nomor identitas", "nik", "ktp", "npwp", "identity number", "passport", "sim", "id card", "no ktp",
+            ],
+            InputType::Date => &[
+                "tanggal", "date", "tanggal lahir", "dob", "birth date", "waktu", "time", "tgl", "tgl lahir",
+            ],
+            InputType::Number => &[
+                "angka", "umur", "age", "harga", "price", "gaji", "salary", "nominal", "amount", "jumlah", "biaya", "cost",
+            ],
+        }
+    }
+
+    /// Teks panjang yang dikecualikan dari batas panjang global.
+    pub fn is_long_text(&self) -> bool {
+        matches!(self, InputType::Content)
+    }
+
+    pub fn info(&self) -> InputTypeInfo {
+        InputTypeInfo {
+            id: self.as_str().to_string(),
+            label: self.label().to_string(),
+            description: self.description().to_string(),
+            aliases: self.aliases().iter().map(|alias| alias.to_string()).collect(),
+        }
+    }
+}
+
+/// Ringkasan satu tipe input untuk introspeksi (misalnya dropdown di UI).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputTypeInfo {
+    pub id: String,
+    pub label: String,
+    pub description: String,
+    pub aliases: Vec<String>,
+}
+
+/// Semua tipe input bawaan, dalam urutan [`InputType::ALL`].
+pub fn list_input_types() -> Vec<InputTypeInfo> {
+    InputType::ALL.iter().map(InputType::info).collect()
+}
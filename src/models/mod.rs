@@ -1,8 +1,14 @@
 pub mod validation;
+pub mod input_type;
 pub mod supported_model;
+pub mod model_capabilities;
 pub mod gemini;
+pub mod openai;
 // Re-export (opsional tapi disarankan)
-pub use validation::ValidationResponse;
+pub use validation::{ReasonCode, ValidationResponse, ValidationStage};
 pub use supported_model::SupportedModel;
+pub use model_capabilities::{GenerationDefaults, ModelCapabilities};
+pub use input_type::{list_input_types, InputType, InputTypeInfo};
 
 pub use gemini::*;
+pub use openai::*;
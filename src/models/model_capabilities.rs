@@ -0,0 +1,123 @@
+// src/models/model_capabilities.rs
+// Deskripsi kemampuan tiap model. Body request dibangun dari data ini, sehingga
+// menambah model cukup dengan menambah entri, tanpa cabang `starts_with` baru.
+
+use super::SupportedModel;
+
+/// Parameter generasi bawaan yang dikirim di `generationConfig`. Nilai `None`
+/// tidak dikirim, sehingga server memakai nilai bawaannya sendiri.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GenerationDefaults {
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub top_k: Option<u32>,
+    /// Batas token keluaran yang diminta; dipotong ke `ModelCapabilities::max_output_tokens`.
+    pub max_output_tokens: Option<u32>,
+    /// Anggaran token thinking (`0` mematikan thinking). Hanya dikirim jika model mendukung thinking config.
+    pub thinking_budget: Option<i32>,
+    pub stop_sequences: &'static [&'static str],
+}
+
+/// Kemampuan satu model yang menentukan isi body request.
+///
+/// Bawaan (`Default`) adalah model teks paling sederhana: tanpa mode JSON, tanpa
+/// instruksi sistem, tanpa skema, dan tanpa thinking config.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelCapabilities {
+    /// Mendukung `responseMimeType: application/json`.
+    pub json_mode: bool,
+    /// Mendukung `systemInstruction`; jika tidak, aturan digabung ke giliran user.
+    pub system_instruction: bool,
+    /// Mendukung `responseSchema` (hanya berlaku bersama `json_mode`).
+    pub response_schema: bool,
+    /// Mendukung `thinkingConfig`.
+    pub thinking: bool,
+    /// Batas token keluaran model.
+    pub max_output_tokens: u32,
+    pub generation: GenerationDefaults,
+}
+
+impl Default for ModelCapabilities {
+    fn default() -> Self {
+        Self {
+            json_mode: false,
+            system_instruction: false,
+            response_schema: false,
+            thinking: false,
+            max_output_tokens: 8192,
+            generation: GenerationDefaults::default(),
+        }
+    }
+}
+
+// Kemampuan per keluarga untuk ID model yang belum punya entri `SupportedModel`
+// (misalnya `gemini-2.0-flash`), agar tidak jatuh ke model teks tanpa mode JSON.
+const MODEL_FAMILIES: &[(&str, ModelCapabilities)] = &[
+    ("gemini-", ModelCapabilities::GEMINI_2_5),
+    ("gemma-", ModelCapabilities::GEMMA_3),
+];
+
+impl ModelCapabilities {
+    /// Keluarga Gemini 2.5 (Flash, Flash-Lite, dan alias `-latest`).
+    pub const GEMINI_2_5: Self = Self {
+        json_mode: true,
+        system_instruction: true,
+        response_schema: true,
+        thinking: true,
+        max_output_tokens: 65_536,
+        generation: GenerationDefaults {
+            temperature: Some(0.1),
+            top_p: Some(0.8),
+            top_k: Some(40),
+            max_output_tokens: None,
+            thinking_budget: None,
+            stop_sequences: &["\n\n", "Input:", "Example:", "Note:"],
+        },
+    };
+
+    /// Gemma 3 lewat Gemini API: teks biasa saja.
+    pub const GEMMA_3: Self = Self {
+        json_mode: false,
+        system_instruction: false,
+        response_schema: false,
+        thinking: false,
+        max_output_tokens: 8192,
+        generation: GenerationDefaults {
+            temperature: None,
+            top_p: None,
+            top_k: None,
+            max_output_tokens: None,
+            thinking_budget: None,
+            stop_sequences: &[],
+        },
+    };
+
+    /// Kemampuan model dengan ID `model_id`. ID yang tidak terdaftar memakai kemampuan
+    /// keluarganya menurut prefiks (`gemini-`, `gemma-`), atau `None` jika keluarganya pun tidak dikenal.
+    pub fn for_model_id(model_id: &str) -> Option<Self> {
+        SupportedModel::from_name(model_id)
+            .map(|model| model.capabilities())
+            .or_else(|| {
+                MODEL_FAMILIES
+                    .iter()
+                    .find(|(prefix, _)| model_id.starts_with(prefix))
+                    .map(|(_, capabilities)| *capabilities)
+            })
+    }
+
+    /// `true` jika model menegakkan `responseSchema`.
+    pub fn enforces_schema(&self) -> bool {
+        self.json_mode && self.response_schema
+    }
+}
+
+impl SupportedModel {
+    pub fn capabilities(&self) -> ModelCapabilities {
+        match self {
+            SupportedModel::GeminiFlash
+            | SupportedModel::GeminiFlashLite
+            | SupportedModel::GeminiFlashLatest => ModelCapabilities::GEMINI_2_5,
+            SupportedModel::Gemma => ModelCapabilities::GEMMA_3,
+        }
+    }
+}
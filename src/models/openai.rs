@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+// Struct untuk parsing respons `/v1/chat/completions` (OpenAI, Ollama, vLLM, llama.cpp)
+#[derive(Debug, Deserialize)]
+pub struct OpenAiMessage {
+    #[serde(default)]
+    pub content: Option<String>,
+}
+#[derive(Debug, Deserialize)]
+pub struct OpenAiChoice {
+    pub message: OpenAiMessage,
+}
+#[derive(Debug, Deserialize)]
+pub struct OpenAiChatResponse {
+    pub choices: Vec<OpenAiChoice>,
+}
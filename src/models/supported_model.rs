@@ -9,6 +9,13 @@ pub enum SupportedModel {
 
 
 impl SupportedModel {
+    pub const ALL: [SupportedModel; 4] = [
+        SupportedModel::GeminiFlash,
+        SupportedModel::GeminiFlashLite,
+        SupportedModel::GeminiFlashLatest,
+        SupportedModel::Gemma,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             SupportedModel::GeminiFlash => "gemini-2.5-flash",
@@ -17,6 +24,11 @@ impl SupportedModel {
             SupportedModel::Gemma => "gemma-3-27b-it",
         }
     }
+    /// Model bawaan dengan ID API `name` (misalnya `gemini-2.5-flash`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|model| model.as_str() == name)
+    }
+
     pub fn from_int(value: i32) -> Option<Self> {
         match value {
             0 => Some(SupportedModel::GeminiFlash),
@@ -27,9 +39,7 @@ impl SupportedModel {
         }
     }
     pub fn valid_options_desc() -> String {
-         format!(
-            "0 (GeminiFlash), 1 (GeminiFlashLite), 2 (GeminiFlashLatest), 3 (Gemma)"
-        )
+        "0 (GeminiFlash), 1 (GeminiFlashLite), 2 (GeminiFlashLatest), 3 (Gemma)".to_string()
     }
 }
 
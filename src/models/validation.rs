@@ -1,9 +1,177 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Tahap yang menghasilkan putusan.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationStage {
+    /// Ditolak validasi sintaksis lokal, tanpa memanggil API.
+    Syntax,
+    /// Diambil dari cache hasil (memori atau file).
+    Cache,
+    #[default]
+    Llm,
+}
+
+impl ValidationStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValidationStage::Syntax => "syntax",
+            ValidationStage::Cache => "cache",
+            ValidationStage::Llm => "llm",
+        }
+    }
+}
+
+/// Kode alasan yang stabil untuk dibaca mesin. Kode tak dikenal dari model
+/// dipetakan ke `SemanticInvalid`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasonCode {
+    #[default]
+    Valid,
+    EmptyInput,
+    SyntaxInvalid,
+    /// Teks acak tanpa makna (mis. "asdf").
+    Gibberish,
+    /// Data contoh/dummy (mis. "Lorem Ipsum", "test@example.com").
+    Placeholder,
+    /// Isi tidak sesuai tipe input yang diminta.
+    TypeMismatch,
+    /// Formatnya benar tetapi tidak masuk akal atau tidak mungkin ada.
+    Unrealistic,
+    /// Input berisi instruksi untuk model, bukan data (prompt injection).
+    PromptInjection,
+    #[serde(other)]
+    SemanticInvalid,
+}
+
+impl ReasonCode {
+    /// Kode yang boleh dipilih model untuk putusan tidak valid.
+    pub const LLM_CODES: [ReasonCode; 6] = [
+        ReasonCode::Gibberish,
+        ReasonCode::Placeholder,
+        ReasonCode::TypeMismatch,
+        ReasonCode::Unrealistic,
+        ReasonCode::PromptInjection,
+        ReasonCode::SemanticInvalid,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReasonCode::Valid => "valid",
+            ReasonCode::EmptyInput => "empty_input",
+            ReasonCode::SyntaxInvalid => "syntax_invalid",
+            ReasonCode::Gibberish => "gibberish",
+            ReasonCode::Placeholder => "placeholder",
+            ReasonCode::TypeMismatch => "type_mismatch",
+            ReasonCode::Unrealistic => "unrealistic",
+            ReasonCode::PromptInjection => "prompt_injection",
+            ReasonCode::SemanticInvalid => "semantic_invalid",
+        }
+    }
+}
+
+/// Putusan validasi.
+///
+/// Struct ini `#[non_exhaustive]` agar metadata baru bisa ditambahkan tanpa memutus
+/// kode pemanggil: buat lewat [`ValidationResponse::new`] lalu ubah field yang perlu.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[non_exhaustive]
 pub struct ValidationResponse {
     pub valid: bool,
     pub message: String,
+    /// Model yang menghasilkan putusan; `None` jika input sudah ditolak validasi sintaksis lokal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub stage: ValidationStage,
+    #[serde(default)]
+    pub code: ReasonCode,
+    /// Keyakinan model antara 0 dan 1, jika model menyertakannya.
+    #[serde(default, deserialize_with = "lenient_confidence", skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Usulan perbaikan atau bentuk baku dari input, jika ada.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+    /// Lama pemrosesan dalam milidetik, termasuk retry dan model cadangan.
+    #[serde(default)]
+    pub latency_ms: u64,
+}
+
+impl ValidationResponse {
+    /// Putusan dari LLM dengan metadata bawaan; kode alasan mengikuti `valid`.
+    pub fn new(valid: bool, message: impl Into<String>) -> Self {
+        ValidationResponse {
+            valid,
+            message: message.into(),
+            code: if valid { ReasonCode::Valid } else { ReasonCode::SemanticInvalid },
+            ..Default::default()
+        }
+    }
+
+    /// Menandai model yang menghasilkan putusan.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Putusan dari validasi sintaksis lokal.
+    pub fn syntax_rejected(user_input: &str, message: String) -> Self {
+        let code = if user_input.trim().is_empty() {
+            ReasonCode::EmptyInput
+        } else {
+            ReasonCode::SyntaxInvalid
+        };
+        ValidationResponse {
+            valid: false,
+            message,
+            stage: ValidationStage::Syntax,
+            code,
+            ..Default::default()
+        }
+    }
+
+    /// Putusan untuk input yang berisi instruksi bagi model: dideteksi lokal (`Syntax`)
+    /// atau karena model menyalin putusan dari input (`Llm`).
+    pub fn injection_rejected(message: String, stage: ValidationStage) -> Self {
+        ValidationResponse {
+            valid: false,
+            message,
+            stage,
+            code: ReasonCode::PromptInjection,
+            ..Default::default()
+        }
+    }
+
+    /// Menyelaraskan metadata keluaran model: kode mengikuti `valid` dan usulan
+    /// kosong dibuang.
+    pub(crate) fn normalize_llm_verdict(&mut self) {
+        self.stage = ValidationStage::Llm;
+        self.code = match (self.valid, self.code) {
+            (true, _) => ReasonCode::Valid,
+            (false, code) if ReasonCode::LLM_CODES.contains(&code) => code,
+            (false, _) => ReasonCode::SemanticInvalid,
+        };
+        if self.suggestion.as_deref().is_some_and(|s| s.trim().is_empty()) {
+            self.suggestion = None;
+        }
+    }
+}
+
+// Model kadang memberi confidence sebagai persen (95) atau string; nilai yang tidak
+// bisa dipahami diabaikan agar tidak menggagalkan putusan. Nilai sedikit di atas 1
+// (misalnya 1.5) dianggap pembulatan yang kebablasan, bukan 1.5%.
+fn lenient_confidence<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    let number = match value {
+        Some(serde_json::Value::Number(n)) => n.as_f64(),
+        Some(serde_json::Value::String(s)) => s.trim().trim_end_matches('%').trim().parse().ok(),
+        _ => None,
+    };
+    Ok(number.filter(|n| n.is_finite() && *n >= 0.0).map(|n| {
+        let n = if n >= 2.0 { n / 100.0 } else { n };
+        n.min(1.0)
+    }))
 }
 
 // Untuk tahap RED, kita bisa menyederhanakan struct ValidationResponse
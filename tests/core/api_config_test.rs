@@ -1,5 +1,12 @@
 
-use validation_semantic::config::API_CONFIG;
+use std::time::Duration;
+
+use validation_semantic::config::{ApiConfig, API_CONFIG};
+use validation_semantic::core::{Locale, SemanticValidator, DEFAULT_USER_AGENT};
+use validation_semantic::models::SupportedModel;
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
 #[test]
 fn test_api_config_initialization() {
     println!("🧪 Testing API_CONFIG initialization");
@@ -16,3 +23,130 @@ fn test_api_config_initialization() {
         }
     }
 }
+
+const TEST_ROOT_CA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/support/test_root_ca.pem");
+
+const CONFIG_TOML: &str = r#"
+api_key = "kunci-file"
+base_url = "http://file.local/v1beta"
+timeout_secs = 30
+connect_timeout_secs = 2.5
+default_model = "gemma-3-27b-it"
+user_agent = "dari-file/1.0"
+locale = "en"
+"#;
+
+#[test]
+fn test_sources_follow_fixed_precedence() {
+    // Prefix unik per test agar aman dijalankan paralel.
+    std::env::set_var("VS_PRECEDENCE_BASE_URL", "http://env.local/v1beta");
+    std::env::set_var("VS_PRECEDENCE_TIMEOUT_SECS", "45");
+
+    // Builder dipanggil sebelum sumber lain, tetapi tetap menang.
+    let config = ApiConfig::builder()
+        .timeout(Duration::from_secs(5))
+        .toml_str(CONFIG_TOML)
+        .unwrap()
+        .env_prefixed("VS_PRECEDENCE_")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(config.timeout, Duration::from_secs(5)); // builder > env > file
+    assert_eq!(config.base_url, "http://env.local/v1beta"); // env > file
+    assert_eq!(config.connect_timeout, Some(Duration::from_millis(2500))); // hanya di file
+    assert_eq!(config.default_model, SupportedModel::Gemma);
+    assert_eq!(config.user_agent, "dari-file/1.0");
+    assert_eq!(config.locale, Locale::En);
+    assert_eq!(config.proxy, None); // bawaan
+}
+
+#[test]
+fn test_env_prefix_reads_every_setting() {
+    let vars = [
+        ("API_KEY", "kunci-env"),
+        ("MODEL", "gemini-flash-lite-latest"),
+        ("CONNECT_TIMEOUT_SECS", "3"),
+        ("PROXY", "http://proxy.local:3128"),
+        ("CA_CERTS", TEST_ROOT_CA),
+        ("USER_AGENT", "aplikasi-saya/2.0"),
+    ];
+    for (name, value) in vars {
+        std::env::set_var(format!("VS_ENVONLY_{}", name), value);
+    }
+
+    let config = ApiConfig::from_env_prefixed("VS_ENVONLY_").unwrap();
+    assert_eq!(config.api_key, "kunci-env");
+    assert_eq!(config.default_model, SupportedModel::GeminiFlashLite);
+    assert_eq!(config.connect_timeout, Some(Duration::from_secs(3)));
+    assert_eq!(config.proxy.as_deref(), Some("http://proxy.local:3128"));
+    assert_eq!(config.root_certificates, vec![TEST_ROOT_CA.to_string()]);
+    assert_eq!(config.user_agent, "aplikasi-saya/2.0");
+    assert!(!format!("{:?}", config).contains("kunci-env"));
+
+    std::env::set_var("VS_BADENV_TIMEOUT_SECS", "sebentar");
+    let err = ApiConfig::from_env_prefixed("VS_BADENV_").unwrap_err();
+    assert!(err.to_string().contains("VS_BADENV_TIMEOUT_SECS"));
+}
+
+#[test]
+fn test_invalid_toml_values_are_config_errors() {
+    for text in [
+        "api_key = \"k\"\nmodel = \"gemini\"",
+        "api_key = \"k\"\ndefault_model = \"gpt-4\"",
+        "api_key = \"k\"\ntimeout_secs = -1",
+    ] {
+        assert_eq!(ApiConfig::from_toml_str(text).unwrap_err().code(), "config", "{}", text);
+    }
+
+    let defaults = ApiConfig::from_toml_str("api_key = \"k\"").unwrap();
+    assert_eq!(defaults.user_agent, DEFAULT_USER_AGENT);
+    assert_eq!(defaults.timeout, Duration::from_secs(60));
+}
+
+#[test]
+fn test_validator_from_config_uses_proxy_and_user_agent() {
+    let verdict = serde_json::json!({
+        "candidates": [{ "content": { "parts": [{ "text": r#"{"valid": true, "message": "OK", "code": "valid"}"# }] } }]
+    })
+    .to_string();
+    let proxy = StubServer::start(vec![StubResponse::json(200, &verdict)]);
+
+    let config = ApiConfig::builder()
+        .api_key("k")
+        .base_url("http://gateway.internal/v1beta")
+        .proxy(proxy.base_url.clone())
+        .user_agent("uji-proxy/1.0")
+        .root_certificate(TEST_ROOT_CA)
+        .build()
+        .unwrap();
+    let validator = SemanticValidator::from_config(&config).unwrap();
+    assert_eq!(validator.transport_options().root_certificates.len(), 1);
+
+    let response = validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap();
+    assert!(response.valid);
+
+    let captured = &proxy.requests()[0];
+    assert!(captured
+        .request_line
+        .contains("http://gateway.internal/v1beta/models/gemini-2.5-flash:generateContent"));
+    assert_eq!(captured.header("user-agent"), Some("uji-proxy/1.0"));
+}
+
+#[test]
+fn test_unreadable_or_invalid_certificates_fail_at_build() {
+    let missing = ApiConfig::builder()
+        .api_key("k")
+        .root_certificate("/tidak/ada/ca.pem")
+        .build()
+        .unwrap();
+    assert_eq!(SemanticValidator::from_config(&missing).err().unwrap().code(), "config");
+
+    let err = SemanticValidator::builder()
+        .api_key("k")
+        .root_certificate_pem("bukan sertifikat")
+        .build()
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("root CA"));
+}
@@ -0,0 +1,216 @@
+use std::ffi::CStr;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use validation_semantic::core::{CacheKey, ResultCache, SemanticValidator};
+use validation_semantic::models::{SupportedModel, ValidationResponse, ValidationStage};
+use validation_semantic::{cache_clear_ffi, cache_configure_ffi, cache_stats_ffi, free_rust_string};
+
+use crate::support::fake_provider::{self, validator_with, FakeProvider};
+
+const VERDICT: &str = r#"{"valid": true, "message": "OK"}"#;
+
+fn verdict(message: &str) -> ValidationResponse {
+    ValidationResponse::new(true, message)
+}
+
+fn cached_validator(provider: Arc<FakeProvider>) -> SemanticValidator {
+    let validator = validator_with(provider, |b| b);
+    validator.configure_cache(16, None);
+    validator
+}
+
+#[test]
+fn test_cache_key_normalizes_input_and_type() {
+    let a = CacheKey::new("  Universitas Indonesia ", "Nama  Institusi", "gemini-2.5-flash");
+    let b = CacheKey::new("Universitas Indonesia", "nama institusi", "gemini-2.5-flash");
+    assert_eq!(a, b);
+
+    let other_model = CacheKey::new("Universitas Indonesia", "nama institusi", "gemma-3-27b-it");
+    assert_ne!(a, other_model);
+}
+
+#[test]
+fn test_cache_key_tracks_prompt_template() {
+    let email = CacheKey::new("a@b.co", "email", "m");
+    let email_alias = CacheKey::new("a@b.co", "alamat email", "m");
+    let institution = CacheKey::new("a@b.co", "nama institusi", "m");
+
+    assert_eq!(email.prompt_version, email_alias.prompt_version);
+    assert_ne!(email.prompt_version, institution.prompt_version);
+}
+
+#[test]
+fn test_cache_key_uses_canonical_input_type() {
+    let email = CacheKey::new("a@b.co", "email", "m");
+    assert_eq!(email.input_type, "email");
+    assert_eq!(email, CacheKey::new("a@b.co", "Alamat  Email", "m"));
+    assert_eq!(email, CacheKey::new("a@b.co", "E-mail Kantor", "m"));
+
+    // Label yang tidak dikenal tetap memakai bentuk yang dinormalisasi.
+    assert_eq!(CacheKey::new("x", "Kode  Rahasia Internal", "m").input_type, "kode rahasia internal");
+}
+
+#[test]
+fn test_aliases_share_one_cache_entry() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let validator = cached_validator(provider.clone());
+
+    validator.validate_sync("budi.santoso@ugm.ac.id", "email").unwrap();
+    let second = validator.validate_sync("budi.santoso@ugm.ac.id", "alamat email").unwrap();
+
+    assert_eq!(second.stage, ValidationStage::Cache);
+    assert_eq!(provider.call_count(), 1);
+    let stats = validator.cache_stats();
+    assert_eq!((stats.hits, stats.entries), (1, 1));
+}
+
+#[test]
+fn test_disabled_cache_stores_nothing() {
+    let cache = ResultCache::disabled();
+    let key = CacheKey::new("x", "email", "m");
+    cache.insert(key.clone(), verdict("OK"));
+
+    assert!(!cache.is_enabled());
+    assert!(cache.get(&key).is_none());
+    assert_eq!(cache.stats().misses, 0);
+}
+
+#[test]
+fn test_least_recently_used_entry_is_evicted() {
+    let cache = ResultCache::new(2, None);
+    let (a, b, c) = (
+        CacheKey::new("a", "email", "m"),
+        CacheKey::new("b", "email", "m"),
+        CacheKey::new("c", "email", "m"),
+    );
+
+    cache.insert(a.clone(), verdict("a"));
+    cache.insert(b.clone(), verdict("b"));
+    assert!(cache.get(&a).is_some());
+    cache.insert(c.clone(), verdict("c"));
+
+    assert!(cache.get(&b).is_none());
+    assert_eq!(cache.get(&a).unwrap().message, "a");
+    assert_eq!(cache.get(&c).unwrap().message, "c");
+    assert_eq!(cache.stats().entries, 2);
+}
+
+#[test]
+fn test_eviction_order_follows_reads_and_overwrites() {
+    let cache = ResultCache::new(3, None);
+    let key = |input: &str| CacheKey::new(input, "email", "m");
+    for input in ["a", "b", "c"] {
+        cache.insert(key(input), verdict(input));
+    }
+    // Menimpa "a" dan membaca "b" membuat "c" menjadi yang paling lama tidak dipakai.
+    cache.insert(key("a"), verdict("a2"));
+    assert!(cache.get(&key("b")).is_some());
+    cache.insert(key("d"), verdict("d"));
+
+    assert!(cache.get(&key("c")).is_none());
+    assert_eq!(cache.get(&key("a")).unwrap().message, "a2");
+    assert_eq!(cache.stats().entries, 3);
+}
+
+#[test]
+fn test_entries_expire_after_ttl() {
+    let cache = ResultCache::new(4, Some(Duration::from_millis(30)));
+    let key = CacheKey::new("a", "email", "m");
+    cache.insert(key.clone(), verdict("a"));
+    assert!(cache.get(&key).is_some());
+
+    thread::sleep(Duration::from_millis(60));
+    assert!(cache.get(&key).is_none());
+    assert_eq!(cache.stats().entries, 0);
+}
+
+#[test]
+fn test_configure_shrinks_and_clear_resets() {
+    let cache = ResultCache::new(4, None);
+    for input in ["a", "b", "c"] {
+        cache.insert(CacheKey::new(input, "email", "m"), verdict(input));
+    }
+    cache.configure(1, None);
+    assert_eq!(cache.stats().entries, 1);
+
+    cache.clear();
+    let stats = cache.stats();
+    assert_eq!((stats.entries, stats.hits, stats.misses, stats.capacity), (0, 0, 0, 1));
+}
+
+#[test]
+fn test_validator_serves_repeated_input_from_cache() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let validator = cached_validator(provider.clone());
+
+    let first = validator.validate_sync("Universitas Indonesia", "Nama Institusi").unwrap();
+    let second = validator.validate_sync(" Universitas Indonesia ", "nama institusi").unwrap();
+
+    assert_eq!(first.message, second.message);
+    assert_eq!(second.model, first.model);
+    assert_eq!(first.stage, ValidationStage::Llm);
+    assert_eq!(second.stage, ValidationStage::Cache);
+    assert_eq!(provider.call_count(), 1);
+
+    let stats = validator.cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+
+    validator
+        .validate_with_model_sync("Universitas Indonesia", SupportedModel::Gemma, "nama institusi")
+        .unwrap();
+    assert_eq!(provider.call_count(), 2);
+}
+
+#[test]
+fn test_errors_are_not_cached() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(500, "boom"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let validator = cached_validator(provider.clone());
+
+    assert!(validator.validate_sync("Universitas Indonesia", "nama institusi").is_err());
+    assert!(validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap().valid);
+    assert_eq!(provider.call_count(), 2);
+}
+
+#[test]
+fn test_cache_can_be_shared_between_validators() {
+    let cache = Arc::new(ResultCache::new(16, None));
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let build = || validator_with(provider.clone(), |b| b.cache(Arc::clone(&cache)));
+
+    build().validate_sync("Universitas Indonesia", "nama institusi").unwrap();
+    build().validate_sync("Universitas Indonesia", "nama institusi").unwrap();
+    assert_eq!(provider.call_count(), 1);
+}
+
+#[tokio::test]
+async fn test_async_path_uses_cache() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let validator = cached_validator(provider.clone());
+
+    for _ in 0..3 {
+        validator
+            .validate_async("Universitas Indonesia", "nama institusi")
+            .await
+            .unwrap();
+    }
+    assert_eq!(provider.call_count(), 1);
+}
+
+#[test]
+fn test_c_ffi_cache_controls() {
+    cache_configure_ffi(8, 0);
+    cache_clear_ffi();
+
+    let ptr = cache_stats_ffi();
+    let stats: serde_json::Value = serde_json::from_str(unsafe { CStr::from_ptr(ptr) }.to_str().unwrap()).unwrap();
+    free_rust_string(ptr);
+
+    assert_eq!(stats["capacity"], 8);
+    assert_eq!(stats["entries"], 0);
+    cache_configure_ffi(0, 0);
+}
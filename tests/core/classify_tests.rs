@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use validation_semantic::core::classify::{format_classify_prompt, parse_classify_output};
+use validation_semantic::core::{detect_types, DetectionSource, ValidationError};
+use validation_semantic::models::InputType;
+
+use crate::support::fake_provider::{validator_with, FakeProvider};
+
+fn top(value: &str) -> Option<InputType> {
+    detect_types(value).first().map(|candidate| candidate.input_type)
+}
+
+#[test]
+fn test_local_detectors() {
+    assert_eq!(top("budi.santoso@ugm.ac.id"), Some(InputType::Email));
+    assert_eq!(top("https://ugm.ac.id/pendaftaran"), Some(InputType::Url));
+    assert_eq!(top("ugm.ac.id"), Some(InputType::Url));
+    assert_eq!(top("+62 812-3456-7890"), Some(InputType::Phone));
+    assert_eq!(top("3174012345678901"), Some(InputType::IdentityNumber));
+    assert_eq!(top("09.254.294.3-407.000"), Some(InputType::IdentityNumber));
+    assert_eq!(top("17/08/1945"), Some(InputType::Date));
+    assert_eq!(top("Rp 1.500.000"), Some(InputType::Number));
+    assert_eq!(top("@budi_s"), Some(InputType::Username));
+    assert_eq!(top("#kuliner #jakarta"), Some(InputType::Tag));
+    assert_eq!(top("PT Telkom Indonesia Tbk"), Some(InputType::Company));
+    assert_eq!(top("Universitas Gadjah Mada"), Some(InputType::Institution));
+    assert_eq!(top("Jl. Sudirman No. 5, Jakarta"), Some(InputType::Address));
+    assert_eq!(top("Budi Santoso"), Some(InputType::PersonName));
+    assert_eq!(top("xyz qwerty"), None);
+
+    let ambiguous = detect_types("1234567");
+    assert_eq!(ambiguous.len(), 2);
+    assert!(ambiguous.iter().all(|candidate| candidate.source == DetectionSource::Local && candidate.score < 0.85));
+}
+
+#[test]
+fn test_confident_local_result_skips_llm_and_flags_mismatch() {
+    let provider = Arc::new(FakeProvider::always(r#"{"candidates": []}"#));
+    let validator = validator_with(provider.clone(), |b| b);
+
+    let result = validator.classify_input_sync("budi@ugm.ac.id", Some("Nama Lengkap")).unwrap();
+    assert_eq!(provider.call_count(), 0);
+    assert_eq!(result.model, None);
+    assert_eq!(result.best().unwrap().input_type, InputType::Email);
+
+    let mismatch = result.label_mismatch.unwrap();
+    assert_eq!((mismatch.expected, mismatch.detected), (InputType::PersonName, InputType::Email));
+    assert_eq!(mismatch.message, "Nilai untuk 'Nama Lengkap' terlihat seperti tipe 'email'.");
+
+    // Label yang sejalan atau tidak dikenal tidak memicu ketidakcocokan.
+    let same = validator.classify_input_sync("budi@ugm.ac.id", Some("E-mail Kantor")).unwrap();
+    assert!(same.label_mismatch.is_none());
+    let institution = validator.classify_input_sync("PT Telkom Indonesia Tbk", Some("instansi")).unwrap();
+    assert!(institution.label_mismatch.is_none());
+    let unknown = validator.classify_input_sync("budi@ugm.ac.id", Some("catatan tambahan")).unwrap();
+    assert!(unknown.label_mismatch.is_none());
+}
+
+#[test]
+fn test_llm_fallback_merges_candidates() {
+    let provider = Arc::new(FakeProvider::always(
+        r#"```json
+{"candidates": [{"type": "product", "score": 0.8}, {"type": "person_name", "score": 0.3}, {"type": "planet", "score": 0.9}]}
+```"#,
+    ));
+    let validator = validator_with(provider.clone(), |b| b);
+
+    let result = validator.classify_input_sync("Indomie Goreng", Some("nama")).unwrap();
+    assert_eq!(provider.call_count(), 1);
+    assert!(result.model.is_some());
+
+    let types: Vec<(InputType, DetectionSource)> =
+        result.candidates.iter().map(|candidate| (candidate.input_type, candidate.source)).collect();
+    assert_eq!(
+        types,
+        vec![(InputType::Product, DetectionSource::Llm), (InputType::PersonName, DetectionSource::Local)]
+    );
+    // Kandidat LLM di bawah ambang pasti tidak memicu ketidakcocokan.
+    assert!(result.label_mismatch.is_none());
+
+    let parts = format_classify_prompt("Indomie Goreng");
+    let body = &provider.requests()[0].body;
+    assert_eq!(body["system"], parts.system.as_str());
+    assert_eq!(body["prompt"], parts.user.as_str());
+    assert!(parts.system.contains("- identity_number: "));
+    assert!(!parts.system.contains("Indomie"));
+    assert_eq!(parts.user, "<user_input>\n\"Indomie Goreng\"\n</user_input>");
+}
+
+#[test]
+fn test_classify_errors() {
+    let provider = Arc::new(FakeProvider::always("bukan json"));
+    let validator = validator_with(provider, |b| b);
+
+    assert!(matches!(validator.classify_input_sync("  ", None), Err(ValidationError::Config(_))));
+    assert!(matches!(
+        validator.classify_input_sync("Indomie Goreng", None),
+        Err(ValidationError::ModelOutputMalformed { .. })
+    ));
+
+    let parsed = parse_classify_output(r#"{"candidates": [{"type": "alamat email", "score": 3}]}"#).unwrap();
+    assert_eq!((parsed[0].input_type, parsed[0].score), (InputType::Email, 1.0));
+}
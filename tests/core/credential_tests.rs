@@ -0,0 +1,171 @@
+use std::ffi::{CStr, CString};
+
+use validation_semantic::bindings::kotlin::uniffi::AppError;
+use validation_semantic::config::ApiConfig;
+use validation_semantic::core::{
+    configure_default, redact_secrets, GeminiProvider, LlmProvider, LlmRequest, RetryPolicy, SemanticValidator,
+    ValidationError, REDACTED,
+};
+use validation_semantic::models::SupportedModel;
+use validation_semantic::{configure_api_key_ffi, free_rust_string, reset_configuration_ffi, validate_text_ffi};
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
+const SECRET: &str = "AIzaSy-rahasia-0123456789";
+
+fn validator_for(base_url: &str) -> SemanticValidator {
+    SemanticValidator::builder()
+        .api_key(SECRET)
+        .base_url(base_url)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+}
+
+/// Semua bentuk teks error yang bisa keluar dari crate: `Display`, `Debug`,
+/// dan error UniFFI yang diteruskan ke Kotlin/Swift.
+fn error_strings(err: &ValidationError) -> Vec<String> {
+    let app_error = AppError::from(err.clone());
+    vec![
+        err.to_string(),
+        format!("{:?}", err),
+        app_error.to_string(),
+        format!("{:?}", app_error),
+    ]
+}
+
+fn assert_no_secret(err: &ValidationError) {
+    for text in error_strings(err) {
+        assert!(!text.contains(SECRET), "API key bocor di pesan error: {}", text);
+    }
+}
+
+#[test]
+fn test_gemini_sends_key_in_header_not_url() {
+    let request = LlmRequest {
+        model: "gemini-2.5-flash",
+        system: None,
+        response_schema: None,
+        prompt: "hello",
+        api_key: SECRET,
+    };
+
+    let http_request = GeminiProvider::default().build_request(&request).unwrap();
+    assert!(!http_request.url.contains(SECRET));
+    assert!(!http_request.url.contains('?'));
+    assert!(http_request
+        .headers
+        .iter()
+        .any(|(name, value)| name == "x-goog-api-key" && value == SECRET));
+
+    let server = StubServer::start(vec![StubResponse::json(500, "{}")]);
+    let _ = validator_for(&server.base_url).validate_sync("Universitas Indonesia", "nama institusi");
+
+    let captured = &server.requests()[0];
+    assert!(!captured.request_line.contains(SECRET));
+    assert_eq!(captured.header("x-goog-api-key"), Some(SECRET));
+}
+
+#[test]
+fn test_key_never_appears_in_error_strings() {
+    let echoed = format!(
+        r#"{{"error": {{"code": 400, "message": "API key not valid: {}", "status": "INVALID_ARGUMENT",
+            "details": [{{"@type": "type.googleapis.com/google.rpc.ErrorInfo", "reason": "API_KEY_INVALID"}}]}}}}"#,
+        SECRET
+    );
+    let responses = vec![
+        StubResponse::json(400, &echoed),
+        StubResponse::json(429, &format!(r#"{{"error": {{"message": "quota for {}"}}}}"#, SECRET)),
+        StubResponse::json(502, &format!("upstream https://proxy.local/x?key={}&alt=json", SECRET)),
+        StubResponse::json(200, &format!("<html>gateway rusak, key={}</html>", SECRET)),
+    ];
+
+    for response in responses {
+        let status = response.status;
+        let server = StubServer::start(vec![response]);
+        let err = validator_for(&server.base_url)
+            .validate_sync("Universitas Indonesia", "nama institusi")
+            .unwrap_err();
+        assert_no_secret(&err);
+        assert!(err.to_string().contains(REDACTED), "status {}: {}", status, err);
+    }
+
+    // Gagal transport: koneksi ke port yang (hampir pasti) tertutup.
+    let err = validator_for("http://127.0.0.1:9/v1beta")
+        .validate_sync("Universitas Indonesia", "nama institusi")
+        .unwrap_err();
+    assert_eq!(err.code(), "transport");
+    assert_no_secret(&err);
+}
+
+#[tokio::test]
+async fn test_async_path_redacts_key() {
+    let server = StubServer::start(vec![StubResponse::json(
+        500,
+        &format!("internal error for key {}", SECRET),
+    )]);
+    let err = validator_for(&server.base_url)
+        .validate_async("Universitas Indonesia", "nama institusi")
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status(), Some(500));
+    assert_no_secret(&err);
+}
+
+#[test]
+fn test_redact_secrets_strips_credential_query_params() {
+    let text = "error sending request for url (https://host/v1/models/m:generateContent?key=abc123&alt=json)";
+    let redacted = redact_secrets(text, "");
+    assert!(!redacted.contains("abc123"));
+    assert!(redacted.contains("alt=json"));
+    assert_eq!(redact_secrets("token rahasia", "rahasia"), format!("token {}", REDACTED));
+}
+
+fn ffi_json(ptr: *mut std::os::raw::c_char) -> serde_json::Value {
+    let json = serde_json::from_str(unsafe { CStr::from_ptr(ptr) }.to_str().unwrap()).unwrap();
+    free_rust_string(ptr);
+    json
+}
+
+#[test]
+fn test_default_validator_key_can_be_configured_and_rotated() {
+    let verdict = serde_json::json!({
+        "candidates": [{ "content": { "parts": [{ "text": r#"{"valid": true, "message": "OK", "code": "valid"}"# }] } }]
+    })
+    .to_string();
+    let server = StubServer::start(vec![StubResponse::json(200, &verdict)]);
+    let input_type = CString::new("nama institusi").unwrap();
+    // Input berbeda tiap panggilan agar cache bawaan (jika diaktifkan test lain) tidak menjawab.
+    let validate = |text: &str| {
+        let text = CString::new(text).unwrap();
+        ffi_json(validate_text_ffi(text.as_ptr(), SupportedModel::GeminiFlash, input_type.as_ptr()))
+    };
+
+    let config = ApiConfig::builder()
+        .api_key("kunci-lama")
+        .base_url(server.base_url.clone())
+        .build()
+        .unwrap();
+    configure_default(config).unwrap();
+    assert_eq!(validate("Universitas Indonesia")["valid"], true);
+
+    // Rotasi: host dan pengaturan lain tetap, hanya key yang berganti.
+    let new_key = CString::new("kunci-baru").unwrap();
+    assert!(configure_api_key_ffi(new_key.as_ptr()).is_null());
+    assert_eq!(validate("Institut Teknologi Bandung")["valid"], true);
+
+    // Key kosong ditolak dan validator yang aktif tidak berubah.
+    let empty = CString::new("").unwrap();
+    assert_eq!(ffi_json(configure_api_key_ffi(empty.as_ptr()))["error"]["code"], "config");
+    assert_eq!(validate("Universitas Gadjah Mada")["valid"], true);
+
+    let keys: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|r| r.header("x-goog-api-key").unwrap_or_default().to_string())
+        .collect();
+    assert_eq!(keys, ["kunci-lama", "kunci-baru", "kunci-baru"]);
+
+    reset_configuration_ffi();
+}
@@ -0,0 +1,176 @@
+use std::ffi::{CStr, CString};
+use std::time::Duration;
+
+use validation_semantic::core::{
+    parse_validation_output, GeminiProvider, HttpResponse, LlmProvider, LlmRequest, RetryPolicy,
+    SemanticValidator, ValidationError,
+};
+use validation_semantic::models::SupportedModel;
+use validation_semantic::{check_form_ffi, classify_input_ffi, free_rust_string, resolve_label_ffi, validate_text_ffi};
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
+const REQUEST: LlmRequest<'static> = LlmRequest {
+    model: "gemini-2.5-flash",
+    system: None,
+    response_schema: None,
+    prompt: "prompt",
+    api_key: "k",
+};
+
+fn response(status: u16, headers: &[(&str, &str)], body: &str) -> HttpResponse {
+    HttpResponse {
+        status,
+        headers: headers
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        body: body.to_string(),
+    }
+}
+
+#[test]
+fn test_gemini_429_maps_to_rate_limited_with_retry_after() {
+    let provider = GeminiProvider::default();
+    let err = provider.map_error(
+        &REQUEST,
+        &response(
+            429,
+            &[("retry-after", "7")],
+            r#"{"error": {"code": 429, "message": "Quota exceeded", "status": "RESOURCE_EXHAUSTED"}}"#,
+        ),
+    );
+
+    assert_eq!(err.code(), "rate_limited");
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+    match err {
+        ValidationError::RateLimited { model, message, .. } => {
+            assert_eq!(model, "gemini-2.5-flash");
+            assert_eq!(message, "Quota exceeded");
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_gemini_invalid_key_maps_to_unauthorized() {
+    let provider = GeminiProvider::default();
+    let body = r#"{"error": {"code": 400, "message": "API key not valid.", "status": "INVALID_ARGUMENT",
+        "details": [{"@type": "type.googleapis.com/google.rpc.ErrorInfo", "reason": "API_KEY_INVALID"}]}}"#;
+
+    let err = provider.map_error(&REQUEST, &response(400, &[], body));
+    assert!(matches!(err, ValidationError::Unauthorized { status: 400, .. }));
+
+    let err = provider.map_error(&REQUEST, &response(403, &[], "forbidden"));
+    assert!(matches!(err, ValidationError::Unauthorized { status: 403, .. }));
+}
+
+#[test]
+fn test_gemini_other_status_maps_to_http() {
+    let provider = GeminiProvider::default();
+    let err = provider.map_error(
+        &REQUEST,
+        &response(503, &[], r#"{"error": {"message": "The model is overloaded."}}"#),
+    );
+
+    assert_eq!(err.status(), Some(503));
+    assert!(matches!(err, ValidationError::Http { ref body, .. } if body == "The model is overloaded."));
+}
+
+#[test]
+fn test_gemini_safety_block_maps_to_blocked() {
+    let provider = GeminiProvider::default();
+
+    let prompt_blocked = r#"{"promptFeedback": {"blockReason": "SAFETY"}}"#;
+    let err = provider
+        .decode_response(&REQUEST, &response(200, &[], prompt_blocked))
+        .unwrap_err();
+    assert!(matches!(err, ValidationError::Blocked { ref reason } if reason == "SAFETY"));
+
+    let candidate_blocked = r#"{"candidates": [{"finishReason": "PROHIBITED_CONTENT"}]}"#;
+    let err = provider
+        .decode_response(&REQUEST, &response(200, &[], candidate_blocked))
+        .unwrap_err();
+    assert_eq!(err.code(), "blocked");
+}
+
+#[test]
+fn test_malformed_model_output_keeps_raw_text() {
+    let err = parse_validation_output("Sure! The input is valid.").unwrap_err();
+    match err {
+        ValidationError::ModelOutputMalformed { raw, .. } => assert_eq!(raw, "Sure! The input is valid."),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_builder_without_key_is_config_error() {
+    let err = SemanticValidator::builder().build().err().unwrap();
+    assert_eq!(err.code(), "config");
+}
+
+#[test]
+fn test_rate_limit_end_to_end_through_stub_server() {
+    let server = StubServer::start(vec![StubResponse::json(
+        429,
+        r#"{"error": {"code": 429, "message": "Resource has been exhausted", "status": "RESOURCE_EXHAUSTED"}}"#,
+    )]);
+    let validator = SemanticValidator::builder()
+        .api_key("k")
+        .base_url(format!("{}/v1beta", server.base_url))
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+
+    let err = validator
+        .validate_with_model_sync("Universitas Indonesia", SupportedModel::GeminiFlashLite, "nama institusi")
+        .unwrap_err();
+    assert!(matches!(err, ValidationError::RateLimited { ref model, .. } if model == "gemini-flash-lite-latest"));
+}
+
+#[test]
+fn test_transport_failure_is_typed() {
+    // Port 9 (discard) di localhost hampir pasti menolak koneksi.
+    let validator = SemanticValidator::builder()
+        .api_key("k")
+        .base_url("http://127.0.0.1:9")
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+
+    let err = validator
+        .validate_sync("Universitas Indonesia", "nama institusi")
+        .unwrap_err();
+    assert!(matches!(err, ValidationError::Transport(_) | ValidationError::Timeout(_)));
+}
+
+#[test]
+fn test_c_ffi_error_json_contains_code() {
+    let input_type = CString::new("email").unwrap();
+    let result_ptr = validate_text_ffi(std::ptr::null(), SupportedModel::GeminiFlash, input_type.as_ptr());
+
+    let json: serde_json::Value =
+        serde_json::from_str(unsafe { CStr::from_ptr(result_ptr) }.to_str().unwrap()).unwrap();
+    free_rust_string(result_ptr);
+
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["error"]["code"], "invalid_argument");
+}
+
+#[test]
+fn test_c_ffi_null_arguments_are_reported_per_argument() {
+    let results = [
+        (check_form_ffi(std::ptr::null(), SupportedModel::GeminiFlash), "form"),
+        (classify_input_ffi(std::ptr::null(), std::ptr::null(), SupportedModel::GeminiFlash), "value"),
+        (resolve_label_ffi(std::ptr::null()), "label"),
+    ];
+
+    for (result_ptr, argument) in results {
+        let json: serde_json::Value =
+            serde_json::from_str(unsafe { CStr::from_ptr(result_ptr) }.to_str().unwrap()).unwrap();
+        free_rust_string(result_ptr);
+
+        assert_eq!(json["error"]["code"], "invalid_argument");
+        assert!(json["message"].as_str().unwrap().contains(argument), "{}", json);
+    }
+}
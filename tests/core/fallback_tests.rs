@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use validation_semantic::models::SupportedModel;
+
+use crate::support::fake_provider::{self, validator_with, FakeProvider};
+
+const VERDICT: &str = r#"{"valid": true, "message": "OK"}"#;
+
+fn requested_models(provider: &FakeProvider) -> Vec<String> {
+    provider
+        .requests()
+        .into_iter()
+        .map(|r| r.url.trim_start_matches("fake://").to_string())
+        .collect()
+}
+
+#[test]
+fn test_verdict_reports_model_without_fallback() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let validator = validator_with(provider, |b| b.default_model(SupportedModel::GeminiFlash));
+
+    let response = validator
+        .validate_with_model_sync("Universitas Indonesia", SupportedModel::Gemma, "nama institusi")
+        .unwrap();
+    assert_eq!(response.model.as_deref(), Some("gemma-3-27b-it"));
+}
+
+#[test]
+fn test_falls_back_down_the_chain_on_quota_and_overload() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(429, "quota"),
+        fake_provider::status(503, "overloaded"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let validator = validator_with(provider.clone(), |b| {
+        b.default_model(SupportedModel::GeminiFlash).fallback_models(vec![
+            SupportedModel::GeminiFlashLatest,
+            SupportedModel::GeminiFlashLite,
+            SupportedModel::Gemma,
+        ])
+    });
+
+    let response = validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap();
+
+    assert!(response.valid);
+    assert_eq!(response.model.as_deref(), Some(SupportedModel::GeminiFlashLite.as_str()));
+    assert_eq!(
+        requested_models(&provider),
+        vec![
+            SupportedModel::GeminiFlash.as_str(),
+            SupportedModel::GeminiFlashLatest.as_str(),
+            SupportedModel::GeminiFlashLite.as_str(),
+        ]
+    );
+}
+
+#[test]
+fn test_requested_model_is_not_repeated_in_chain() {
+    let provider = Arc::new(FakeProvider::sequence(vec![fake_provider::status(503, "overloaded")]));
+    let validator = validator_with(provider.clone(), |b| {
+        b.default_model(SupportedModel::GeminiFlash).fallback_models(vec![
+            SupportedModel::GeminiFlash,
+            SupportedModel::Gemma,
+            SupportedModel::Gemma,
+        ])
+    });
+
+    let err = validator
+        .validate_sync("Universitas Indonesia", "nama institusi")
+        .unwrap_err();
+
+    assert_eq!(err.status(), Some(503));
+    assert_eq!(
+        requested_models(&provider),
+        vec![SupportedModel::GeminiFlash.as_str(), SupportedModel::Gemma.as_str()]
+    );
+}
+
+#[test]
+fn test_non_availability_error_stops_the_chain() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(400, "bad request"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let validator = validator_with(provider.clone(), |b| {
+        b.default_model(SupportedModel::GeminiFlash).fallback_models(vec![SupportedModel::Gemma])
+    });
+
+    let err = validator
+        .validate_sync("Universitas Indonesia", "nama institusi")
+        .unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(provider.call_count(), 1);
+}
+
+#[test]
+fn test_syntax_rejection_has_no_model() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let validator = validator_with(provider.clone(), |b| {
+        b.default_model(SupportedModel::GeminiFlash).fallback_models(vec![SupportedModel::Gemma])
+    });
+
+    let response = validator.validate_sync("bukan-email", "email").unwrap();
+    assert!(!response.valid);
+    assert_eq!(response.model, None);
+    assert_eq!(provider.call_count(), 0);
+}
+
+#[tokio::test]
+async fn test_async_path_falls_back() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(429, "quota"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let validator = validator_with(provider.clone(), |b| {
+        b.default_model(SupportedModel::GeminiFlash).fallback_models(vec![SupportedModel::Gemma])
+    });
+
+    let response = validator
+        .validate_async("Universitas Indonesia", "nama institusi")
+        .await
+        .unwrap();
+    assert_eq!(response.model.as_deref(), Some(SupportedModel::Gemma.as_str()));
+}
@@ -0,0 +1,242 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use validation_semantic::core::{CacheKey, FileCache};
+use validation_semantic::models::ValidationResponse;
+
+use crate::support::fake_provider::{validator_with, FakeProvider};
+
+const VERDICT: &str = r#"{"valid": true, "message": "OK"}"#;
+
+// Path unik per test di direktori sementara; direktori dibuat oleh `FileCache::open`.
+fn temp_cache_path(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    std::env::temp_dir()
+        .join(format!(
+            "validation_semantic_test_{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+        .join(format!("{}.jsonl", name))
+}
+
+fn key(input: &str) -> CacheKey {
+    CacheKey::new(input, "nama institusi", "gemini-2.5-flash")
+}
+
+fn verdict(message: &str) -> ValidationResponse {
+    ValidationResponse::new(true, message).with_model("gemini-2.5-flash")
+}
+
+fn line_count(path: &PathBuf) -> usize {
+    fs::read_to_string(path).unwrap().lines().count()
+}
+
+#[test]
+fn test_entries_survive_reopen() {
+    let path = temp_cache_path("reopen");
+    {
+        let cache = FileCache::open(&path, None).unwrap();
+        cache.insert(key("Universitas Indonesia"), verdict("UI")).unwrap();
+    }
+
+    let reopened = FileCache::open(&path, None).unwrap();
+    let hit = reopened.get(&key("Universitas Indonesia")).unwrap();
+    assert_eq!(hit.message, "UI");
+    assert_eq!(hit.model.as_deref(), Some("gemini-2.5-flash"));
+    assert!(reopened.get(&key("Institut Teknologi Bandung")).is_none());
+
+    let stats = reopened.stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+}
+
+#[test]
+fn test_expired_entries_are_ignored() {
+    let path = temp_cache_path("ttl");
+    let cache = FileCache::open(&path, Some(Duration::from_millis(30))).unwrap();
+    cache.insert(key("Universitas Indonesia"), verdict("UI")).unwrap();
+    assert!(cache.get(&key("Universitas Indonesia")).is_some());
+
+    thread::sleep(Duration::from_millis(60));
+    assert!(cache.get(&key("Universitas Indonesia")).is_none());
+    assert_eq!(cache.stats().entries, 0);
+}
+
+#[test]
+fn test_compaction_drops_superseded_and_expired_lines() {
+    let path = temp_cache_path("compact");
+    let cache = FileCache::open(&path, None).unwrap();
+    for round in 0..3 {
+        cache.insert(key("Universitas Indonesia"), verdict(&format!("v{}", round))).unwrap();
+    }
+    let short_lived = FileCache::open(&path, Some(Duration::from_millis(1))).unwrap();
+    short_lived.insert(key("Institut Teknologi Bandung"), verdict("ITB")).unwrap();
+    thread::sleep(Duration::from_millis(10));
+
+    assert_eq!(line_count(&path), 5);
+    assert_eq!(cache.compact().unwrap(), 1);
+    assert_eq!(line_count(&path), 2);
+
+    // Instance lain mendeteksi file yang ditulis ulang dan tetap membaca nilai terbaru.
+    assert_eq!(short_lived.get(&key("Universitas Indonesia")).unwrap().message, "v2");
+    assert!(short_lived.get(&key("Institut Teknologi Bandung")).is_none());
+}
+
+#[test]
+fn test_instances_see_each_others_writes() {
+    let path = temp_cache_path("shared");
+    let a = FileCache::open(&path, None).unwrap();
+    let b = FileCache::open(&path, None).unwrap();
+
+    a.insert(key("Universitas Indonesia"), verdict("dari A")).unwrap();
+    assert_eq!(b.get(&key("Universitas Indonesia")).unwrap().message, "dari A");
+
+    a.clear().unwrap();
+    b.insert(key("Universitas Gadjah Mada"), verdict("dari B")).unwrap();
+    assert!(a.get(&key("Universitas Indonesia")).is_none());
+    assert_eq!(a.get(&key("Universitas Gadjah Mada")).unwrap().message, "dari B");
+}
+
+#[test]
+fn test_concurrent_writers_do_not_corrupt_the_file() {
+    let path = temp_cache_path("concurrent");
+    FileCache::open(&path, None).unwrap();
+
+    let writers: Vec<_> = (0..4)
+        .map(|writer| {
+            let path = path.clone();
+            thread::spawn(move || {
+                let cache = FileCache::open(&path, None).unwrap();
+                for i in 0..25 {
+                    cache
+                        .insert(key(&format!("input-{}-{}", writer, i)), verdict("OK"))
+                        .unwrap();
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+
+    for line in fs::read_to_string(&path).unwrap().lines() {
+        serde_json::from_str::<serde_json::Value>(line).unwrap();
+    }
+    assert_eq!(FileCache::open(&path, None).unwrap().stats().entries, 100);
+}
+
+#[test]
+fn test_partial_trailing_line_is_skipped() {
+    let path = temp_cache_path("partial");
+    let cache = FileCache::open(&path, None).unwrap();
+    cache.insert(key("Universitas Indonesia"), verdict("UI")).unwrap();
+
+    // Meniru proses yang mati di tengah penulisan baris.
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(br#"{"key": {"input": "rusak"#).unwrap();
+
+    let reopened = FileCache::open(&path, None).unwrap();
+    assert_eq!(reopened.stats().entries, 1);
+    assert!(reopened.get(&key("Universitas Indonesia")).is_some());
+}
+
+#[test]
+fn test_append_after_torn_line_starts_a_new_line() {
+    let path = temp_cache_path("torn");
+    let cache = FileCache::open(&path, None).unwrap();
+    cache.insert(key("Universitas Indonesia"), verdict("UI")).unwrap();
+
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(br#"{"key": {"input": "rusak"#).unwrap();
+    cache.insert(key("Institut Teknologi Bandung"), verdict("ITB")).unwrap();
+
+    let reopened = FileCache::open(&path, None).unwrap();
+    assert_eq!(reopened.stats().entries, 2);
+    assert_eq!(reopened.get(&key("Institut Teknologi Bandung")).unwrap().message, "ITB");
+}
+
+fn lock_path(path: &std::path::Path) -> PathBuf {
+    PathBuf::from(format!("{}.lock", path.display()))
+}
+
+fn unix_ms_ago(age: Duration) -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() - age.as_millis()
+}
+
+// Hanya Unix yang bisa memeriksa PID; di OS lain lock lama selalu dibongkar karena umurnya.
+#[cfg(unix)]
+#[test]
+fn test_old_lock_of_live_process_is_not_broken() {
+    let path = temp_cache_path("live_lock");
+    let cache = FileCache::open(&path, None).unwrap();
+
+    // Pemegang masih hidup (proses ini) walaupun lock sudah diambil lama sekali,
+    // misalnya karena kompaksi panjang.
+    let lock = lock_path(&path);
+    fs::write(&lock, format!("{} {}\n", std::process::id(), unix_ms_ago(Duration::from_secs(3600)))).unwrap();
+    let holder = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(300));
+        fs::remove_file(lock).unwrap();
+    });
+
+    let started = Instant::now();
+    cache.insert(key("Universitas Indonesia"), verdict("UI")).unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(250));
+    holder.join().unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_lock_of_dead_process_is_broken() {
+    let path = temp_cache_path("dead_lock");
+    let cache = FileCache::open(&path, None).unwrap();
+
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let dead_pid = child.id();
+    child.wait().unwrap();
+    fs::write(lock_path(&path), format!("{} {}\n", dead_pid, unix_ms_ago(Duration::ZERO))).unwrap();
+
+    let started = Instant::now();
+    cache.insert(key("Universitas Indonesia"), verdict("UI")).unwrap();
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(!lock_path(&path).exists());
+}
+
+#[test]
+fn test_old_lock_of_dead_process_is_broken_on_every_platform() {
+    // PID di luar jangkauan tidak pernah hidup di Unix; di OS lain PID tidak bisa
+    // diperiksa, jadi lock dibongkar karena umurnya. Keduanya tidak boleh menunggu timeout.
+    let path = temp_cache_path("dead_old_lock");
+    let cache = FileCache::open(&path, None).unwrap();
+    fs::write(lock_path(&path), format!("{} {}\n", u32::MAX, unix_ms_ago(Duration::from_secs(120)))).unwrap();
+
+    let started = Instant::now();
+    cache.insert(key("Universitas Indonesia"), verdict("UI")).unwrap();
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(!lock_path(&path).exists());
+    assert_eq!(FileCache::open(&path, None).unwrap().get(&key("Universitas Indonesia")).unwrap().message, "UI");
+}
+
+#[test]
+fn test_validator_reuses_verdicts_from_previous_run() {
+    let path = temp_cache_path("validator");
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let run = || {
+        let validator = validator_with(provider.clone(), |b| {
+            b.file_cache(Arc::new(FileCache::open(&path, None).unwrap()))
+        });
+        validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap()
+    };
+
+    let first = run();
+    let second = run();
+
+    assert!(first.valid && second.valid);
+    assert_eq!(second.model, first.model);
+    assert_eq!(provider.call_count(), 1);
+}
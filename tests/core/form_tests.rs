@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use validation_semantic::core::form::{check_locally, today, LocalCheck};
+use validation_semantic::core::{FindingSource, FormInput, Relationship, RelationshipKind, ValidationError};
+
+use crate::support::fake_provider::{validator_with, FakeProvider};
+
+const CONSISTENT: &str = r#"{"consistent": true, "findings": []}"#;
+
+fn local(form: &FormInput) -> LocalCheck {
+    check_locally(&form.relationships[0], form, today())
+}
+
+#[test]
+fn test_birth_date_and_age_checked_locally() {
+    let (year, month, day) = today();
+    let age = year - 1990 - i32::from((month, day) < (7, 1));
+    let form = |birth: &str, age: &str| {
+        FormInput::new()
+            .field("tgl_lahir", birth, "tanggal lahir")
+            .field("umur", age, "umur")
+            .relationship(Relationship::birth_date_age("tgl_lahir", "umur"))
+    };
+
+    assert_eq!(local(&form("1990-07-01", &age.to_string())), LocalCheck::Consistent);
+    assert_eq!(local(&form("01/07/1990", &format!("{} tahun", age))), LocalCheck::Consistent);
+    assert!(matches!(local(&form("1970-03-12", "25")), LocalCheck::Inconsistent(_)));
+    assert!(matches!(local(&form("17 Agustus 1945", "25")), LocalCheck::Inconsistent(_)));
+    assert_eq!(local(&form("kemarin", "25")), LocalCheck::Undecided);
+}
+
+#[test]
+fn test_city_and_postal_code_checked_locally() {
+    let form = |city: &str, postal: &str| {
+        FormInput::new()
+            .field("kota", city, "kota")
+            .field("kode_pos", postal, "kode pos")
+            .relationship(Relationship::city_postal_code("kota", "kode_pos"))
+    };
+
+    assert_eq!(local(&form("Surabaya", "60111")), LocalCheck::Consistent);
+    assert_eq!(local(&form("Jakarta Selatan", "12190")), LocalCheck::Consistent);
+    assert!(matches!(local(&form("DKI Jakarta", "60111")), LocalCheck::Inconsistent(_)));
+    assert!(matches!(local(&form("Bandung", "401")), LocalCheck::Inconsistent(_)));
+    assert_eq!(local(&form("Kupang", "85111")), LocalCheck::Undecided);
+}
+
+#[test]
+fn test_email_domain_and_company_checked_locally() {
+    let form = |email: &str, company: &str| {
+        FormInput::new()
+            .field("email", email, "email")
+            .field("perusahaan", company, "nama perusahaan")
+            .relationship(Relationship::email_domain_company("email", "perusahaan"))
+    };
+
+    assert!(matches!(local(&form("budi@gmail.com", "PT Telkom Indonesia")), LocalCheck::Inconsistent(_)));
+    assert_eq!(local(&form("budi@telkom.co.id", "PT Telkom Indonesia Tbk")), LocalCheck::Consistent);
+    assert_eq!(local(&form("budi@bri.co.id", "PT Bank Rakyat Indonesia")), LocalCheck::Consistent);
+    assert_eq!(local(&form("budi@mandiri.co.id", "PT Bank Rakyat Indonesia")), LocalCheck::Undecided);
+}
+
+#[test]
+fn test_local_findings_skip_the_llm() {
+    let provider = Arc::new(FakeProvider::always(CONSISTENT));
+    let form = FormInput::new()
+        .field("tgl_lahir", "1970-03-12", "tanggal lahir")
+        .field("umur", "25", "umur")
+        .field("nama", "Budi", "nama")
+        .relationship(Relationship::birth_date_age("tgl_lahir", "umur"));
+
+    let report = validator_with(provider.clone(), |b| b).check_form_sync(&form).unwrap();
+
+    assert_eq!(provider.call_count(), 0);
+    assert!(!report.consistent);
+    assert!(report.model.is_none());
+    assert_eq!(report.findings[0].source, FindingSource::Local);
+    assert_eq!(report.field_findings["umur"].len(), 1);
+    assert!(report.field_findings["nama"].is_empty());
+}
+
+#[test]
+fn test_undecided_relationships_go_to_one_llm_pass() {
+    let provider = Arc::new(FakeProvider::always(
+        r#"{"consistent": false, "findings": [{"relationship": 1, "fields": [], "message": "Kode pos bukan wilayah Kupang"}]}"#,
+    ));
+    let form = FormInput::new()
+        .field("kota", "Kupang", "kota")
+        .field("kode_pos", "10110", "kode pos")
+        .field("jabatan", "Direktur", "jabatan")
+        .field("gaji", "Rp 1.000", "gaji")
+        .relationship(Relationship::city_postal_code("kota", "kode_pos"))
+        .relationship(Relationship::custom(["jabatan", "gaji"], "gaji harus wajar untuk jabatan"));
+
+    let report = validator_with(provider.clone(), |b| b).check_form_sync(&form).unwrap();
+
+    assert_eq!(provider.call_count(), 1);
+    let body = &provider.requests()[0].body;
+    let system = body["system"].as_str().unwrap();
+    assert!(system.contains("[city_postal_code]") && system.contains("[custom]"));
+    assert!(!system.contains("Kupang"));
+    assert!(body["prompt"].as_str().unwrap().contains("<user_input field=\"kota\">\n\"Kupang\"\n</user_input>"));
+
+    assert!(!report.consistent);
+    assert_eq!(report.model.as_deref(), Some("gemini-2.5-flash"));
+    let finding = &report.findings[0];
+    assert_eq!(finding.relationship, Some(RelationshipKind::CityPostalCode));
+    assert_eq!(finding.fields, vec!["kota", "kode_pos"]);
+    assert_eq!(report.field_findings["kota"], vec!["Kode pos bukan wilayah Kupang"]);
+}
+
+#[test]
+fn test_unknown_relationship_field_is_rejected() {
+    let provider = Arc::new(FakeProvider::always(CONSISTENT));
+    let form = FormInput::new()
+        .field("kota", "Surabaya", "kota")
+        .relationship(Relationship::city_postal_code("kota", "kode_pos"));
+
+    let err = validator_with(provider, |b| b).check_form_sync(&form).unwrap_err();
+    assert!(matches!(err, ValidationError::Config(_)));
+}
+
+#[test]
+fn test_form_input_deserializes_from_json() {
+    let form: FormInput = serde_json::from_str(
+        r#"{"fields": {"email": {"value": "a@gmail.com", "inputType": "email"}, "perusahaan": {"value": "PT ABC", "input_type": "nama perusahaan"}},
+            "relationships": [{"kind": "email_domain_company", "fields": ["email", "perusahaan"]}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(form.relationships[0].kind, RelationshipKind::EmailDomainCompany);
+    assert_eq!(form.fields["email"].input_type, "email");
+}
+
+#[tokio::test]
+async fn test_async_form_check() {
+    let provider = Arc::new(FakeProvider::always(CONSISTENT));
+    let form = FormInput::new()
+        .field("email", "budi@mandiri.co.id", "email")
+        .field("perusahaan", "PT Bank Rakyat Indonesia", "nama perusahaan")
+        .relationship(Relationship::email_domain_company("email", "perusahaan"));
+
+    let report = validator_with(provider.clone(), |b| b).check_form_async(&form).await.unwrap();
+
+    assert_eq!(provider.call_count(), 1);
+    assert!(report.consistent);
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use validation_semantic::config::ApiConfig;
+use validation_semantic::core::{ApiKeyPool, KeySelection, RetryPolicy, SemanticValidator};
+use validation_semantic::models::SupportedModel;
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
+const KEYS: [&str; 3] = ["kunci-proyek-aaaa", "kunci-proyek-bbbb", "kunci-proyek-cccc"];
+
+// Input berbeda tiap panggilan agar urutan key mudah dibaca dari request yang tercatat.
+const INPUTS: [&str; 4] = [
+    "Universitas Indonesia",
+    "Institut Teknologi Bandung",
+    "Universitas Gadjah Mada",
+    "Institut Pertanian Bogor",
+];
+
+fn verdict() -> StubResponse {
+    let body = serde_json::json!({
+        "candidates": [{ "content": { "parts": [{ "text": r#"{"valid": true, "message": "OK", "code": "valid"}"# }] } }]
+    });
+    StubResponse::json(200, &body.to_string())
+}
+
+fn rate_limited() -> StubResponse {
+    StubResponse::json(429, r#"{"error": {"code": 429, "message": "Quota exceeded"}}"#)
+}
+
+fn validator_with(server: &StubServer, pool: ApiKeyPool) -> SemanticValidator {
+    // Backoff panjang: jika rotasi key ikut menunggu backoff, test akan terasa lambat.
+    let policy = RetryPolicy::default()
+        .max_attempts(3)
+        .base_delay(Duration::from_secs(5))
+        .jitter(false);
+    SemanticValidator::builder()
+        .api_key_pool(Arc::new(pool))
+        .base_url(server.base_url.clone())
+        .retry_policy(policy)
+        .build()
+        .unwrap()
+}
+
+fn keys_sent(server: &StubServer) -> Vec<String> {
+    server
+        .requests()
+        .iter()
+        .map(|r| r.header("x-goog-api-key").unwrap_or_default().to_string())
+        .collect()
+}
+
+#[test]
+fn test_round_robin_spreads_requests_and_reports_usage() {
+    let server = StubServer::start(vec![verdict()]);
+    let validator = validator_with(&server, ApiKeyPool::new(KEYS).unwrap());
+
+    for input in INPUTS {
+        assert!(validator.validate_sync(input, "nama institusi").unwrap().valid);
+    }
+
+    assert_eq!(keys_sent(&server), [KEYS[0], KEYS[1], KEYS[2], KEYS[0]]);
+    let usage = validator.key_usage();
+    assert_eq!(usage.iter().map(|u| u.requests).collect::<Vec<_>>(), [2, 1, 1]);
+    assert_eq!(usage[0].successes, 2);
+    assert_eq!(usage[1].key_hint, "bbbb");
+    assert!(!format!("{:?}", usage).contains("kunci-proyek"));
+}
+
+#[test]
+fn test_rate_limited_key_is_quarantined_and_skipped_without_backoff() {
+    let server = StubServer::start(vec![rate_limited(), verdict()]);
+    let validator = validator_with(&server, ApiKeyPool::new(KEYS).unwrap());
+
+    let started = Instant::now();
+    assert!(validator.validate_sync(INPUTS[0], "nama institusi").unwrap().valid);
+    assert!(validator.validate_sync(INPUTS[1], "nama institusi").unwrap().valid);
+    assert!(validator.validate_sync(INPUTS[2], "nama institusi").unwrap().valid);
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    // Key pertama dikarantina setelah 429, jadi giliran berikutnya melewatinya.
+    assert_eq!(keys_sent(&server), [KEYS[0], KEYS[1], KEYS[2], KEYS[1]]);
+    let usage = validator.key_usage();
+    assert_eq!(usage[0].rate_limited, 1);
+    assert!(usage[0].quarantine_remaining_ms > 0);
+    assert_eq!(usage[1].quarantine_remaining_ms, 0);
+}
+
+#[test]
+fn test_rate_limit_quarantines_key_only_for_that_model() {
+    // Kedua key habis kuota untuk Flash, Gemma masih tersedia untuk keduanya.
+    let server = StubServer::start(vec![rate_limited(), rate_limited(), rate_limited(), verdict()]);
+    let policy = RetryPolicy::default()
+        .max_attempts(2)
+        .base_delay(Duration::from_secs(5))
+        .jitter(false);
+    let validator = SemanticValidator::builder()
+        .api_key_pool(Arc::new(ApiKeyPool::new([KEYS[0], KEYS[1]]).unwrap()))
+        .base_url(server.base_url.clone())
+        .retry_policy(policy)
+        .default_model(SupportedModel::GeminiFlash)
+        .fallback_models(vec![SupportedModel::Gemma])
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    let response = validator.validate_sync(INPUTS[0], "nama institusi").unwrap();
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    assert_eq!(response.model.as_deref(), Some(SupportedModel::Gemma.as_str()));
+    // 429 dari Gemma dengan KEYS[0] tetap bisa langsung berpindah ke KEYS[1]:
+    // karantina Flash tidak menghalangi key itu untuk Gemma.
+    assert_eq!(keys_sent(&server), [KEYS[0], KEYS[1], KEYS[0], KEYS[1]]);
+    let models: Vec<bool> = server
+        .requests()
+        .iter()
+        .map(|r| r.request_line.contains(SupportedModel::Gemma.as_str()))
+        .collect();
+    assert_eq!(models, [false, false, true, true]);
+
+    let usage = validator.key_usage();
+    assert_eq!(usage[0].quarantined_models, [SupportedModel::GeminiFlash.as_str(), SupportedModel::Gemma.as_str()]);
+    assert_eq!(usage[1].quarantined_models, [SupportedModel::GeminiFlash.as_str()]);
+    assert_eq!(usage[1].successes, 1);
+}
+
+#[test]
+fn test_forbidden_key_is_rejected_and_next_key_is_used() {
+    let server = StubServer::start(vec![
+        StubResponse::json(403, r#"{"error": {"code": 403, "message": "Permission denied"}}"#),
+        verdict(),
+    ]);
+    let validator = validator_with(&server, ApiKeyPool::new([KEYS[0], KEYS[1]]).unwrap());
+
+    assert!(validator.validate_sync(INPUTS[0], "nama institusi").unwrap().valid);
+    assert_eq!(keys_sent(&server), [KEYS[0], KEYS[1]]);
+    assert_eq!(validator.key_usage()[0].rejected, 1);
+}
+
+#[test]
+fn test_least_recently_rate_limited_prefers_keys_without_recent_429() {
+    let server = StubServer::start(vec![rate_limited(), verdict()]);
+    // Tanpa cooldown: key pertama langsung siap lagi, tetapi tetap didahulukan paling akhir.
+    let pool = ApiKeyPool::new(KEYS)
+        .unwrap()
+        .selection(KeySelection::LeastRecentlyRateLimited)
+        .cooldown(Duration::ZERO);
+    let validator = validator_with(&server, pool);
+
+    for input in &INPUTS[..3] {
+        assert!(validator.validate_sync(input, "nama institusi").unwrap().valid);
+    }
+
+    // Round-robin akan memakai KEYS[0] pada panggilan ketiga.
+    assert_eq!(keys_sent(&server), [KEYS[0], KEYS[1], KEYS[2], KEYS[1]]);
+}
+
+#[tokio::test]
+async fn test_async_path_rotates_keys() {
+    let server = StubServer::start(vec![rate_limited(), verdict()]);
+    let validator = validator_with(&server, ApiKeyPool::new([KEYS[0], KEYS[1]]).unwrap());
+
+    let response = validator.validate_async(INPUTS[0], "nama institusi").await.unwrap();
+    assert!(response.valid);
+    assert_eq!(keys_sent(&server), [KEYS[0], KEYS[1]]);
+    assert_eq!(validator.key_usage()[0].rate_limited, 1);
+}
+
+#[test]
+fn test_config_builds_pool_from_api_keys() {
+    let config = ApiConfig::from_toml_str(
+        r#"
+api_keys = ["kunci-satu", "kunci-dua", "kunci-tiga"]
+key_selection = "least_recently_rate_limited"
+key_cooldown_secs = 120
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.api_key, "kunci-satu");
+    assert_eq!(config.api_keys, ["kunci-dua", "kunci-tiga"]);
+
+    let validator = SemanticValidator::from_config(&config).unwrap();
+    let pool = validator.api_key_pool();
+    assert_eq!(pool.len(), 3);
+    assert_eq!(pool.selection_strategy(), KeySelection::LeastRecentlyRateLimited);
+    assert_eq!(pool.cooldown_duration(), Duration::from_secs(120));
+
+    assert!(ApiKeyPool::new(Vec::<String>::new()).is_err());
+    assert!(ApiKeyPool::new(["k", " "]).is_err());
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use validation_semantic::core::label_resolver::{edit_distance, input_type_for, normalize_label, MIN_AUTO_CONFIDENCE};
+use validation_semantic::core::{check_syntax, format_prompt, resolve_label, LocalMessage, MatchMethod};
+use validation_semantic::models::InputType;
+
+use crate::support::fake_provider::{validator_with, FakeProvider};
+
+fn resolved(label: &str) -> (InputType, MatchMethod) {
+    let resolution = resolve_label(label).unwrap_or_else(|| panic!("label '{}' tidak terpetakan", label));
+    (resolution.input_type, resolution.method)
+}
+
+#[test]
+fn test_normalize_label() {
+    assert_eq!(normalize_label("Tgl. Lahir (dd/mm/yyyy)"), "tgl lahir");
+    assert_eq!(normalize_label("nama_lengkap"), "nama lengkap");
+    assert_eq!(normalize_label("namaLengkap"), "nama lengkap");
+    assert_eq!(normalize_label("E-mail Kantor"), "email kantor");
+    assert_eq!(normalize_label("No. WA [wajib]"), "no wa");
+}
+
+#[test]
+fn test_real_world_form_labels() {
+    assert_eq!(resolved("Email"), (InputType::Email, MatchMethod::Exact));
+    assert_eq!(resolved("nama_lengkap"), (InputType::PersonName, MatchMethod::Normalized));
+    assert_eq!(resolved("Tgl. Lahir (dd/mm/yyyy)"), (InputType::Date, MatchMethod::Normalized));
+    assert_eq!(resolved("person_name"), (InputType::PersonName, MatchMethod::Normalized));
+    assert_eq!(resolved("E-mail Kantor"), (InputType::Email, MatchMethod::Keyword));
+    assert_eq!(resolved("No. WA"), (InputType::Phone, MatchMethod::Keyword));
+    assert_eq!(resolved("Alamat Email Kantor"), (InputType::Email, MatchMethod::Keyword));
+
+    let whatsapp = resolve_label("No. WA").unwrap();
+    assert_eq!(whatsapp.matched, "wa");
+    assert!(whatsapp.confidence >= MIN_AUTO_CONFIDENCE);
+}
+
+#[test]
+fn test_autocomplete_tokens() {
+    assert_eq!(resolved("bday"), (InputType::Date, MatchMethod::Autocomplete));
+    assert_eq!(resolved("street-address"), (InputType::Address, MatchMethod::Autocomplete));
+    assert_eq!(resolved("section-kirim shipping tel-national"), (InputType::Phone, MatchMethod::Autocomplete));
+    assert_eq!(resolved("given-name"), (InputType::PersonName, MatchMethod::Autocomplete));
+}
+
+#[test]
+fn test_fuzzy_matching_and_confidence() {
+    assert_eq!(edit_distance("emial", "email"), 1);
+    assert_eq!(edit_distance("alamat", "alamat"), 0);
+
+    let typo = resolve_label("Emial").unwrap();
+    assert_eq!((typo.input_type, typo.method), (InputType::Email, MatchMethod::Fuzzy));
+    assert!(typo.confidence < resolve_label("E-mail Kantor").unwrap().confidence);
+
+    // Kata kunci yang hanya menutup sebagian kecil label tidak cukup untuk dipakai otomatis.
+    let partial = resolve_label("nama panggilan kucing").unwrap();
+    assert_eq!(partial.input_type, InputType::PersonName);
+    assert!(partial.confidence < MIN_AUTO_CONFIDENCE);
+    assert_eq!(input_type_for("nama panggilan kucing"), None);
+
+    assert_eq!(resolve_label("Catatan Tambahan"), None);
+    assert_eq!(resolve_label("  (opsional) "), None);
+}
+
+#[test]
+fn test_validator_uses_resolved_type() {
+    assert_eq!(check_syntax("budi", "E-mail Kantor"), Err(LocalMessage::EmailFormat));
+    assert_eq!(check_syntax("0812", "No. WA"), Err(LocalMessage::PhoneLength));
+    assert!(format_prompt("Budi", "nama_lengkap").contains("valid human full name"));
+
+    let provider = Arc::new(FakeProvider::always(r#"{"valid": true, "message": "OK"}"#));
+    let validator = validator_with(provider.clone(), |b| b.strict_input_types(true));
+    assert!(validator.validate_sync("1990-05-17", "Tgl. Lahir (dd/mm/yyyy)").unwrap().valid);
+    assert_eq!(provider.call_count(), 1);
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use validation_semantic::core::{
+    check_syntax, format_prompt_for, pre_validate_syntactically, BatchInput, CacheKey, FormInput, LocalMessage,
+    Locale, Relationship, ValidationError,
+};
+
+use crate::support::fake_provider::{sent_prompt, validator_with, FakeProvider};
+
+const VERDICT: &str = r#"{"valid": true, "message": "Looks fine"}"#;
+
+#[test]
+fn test_locale_codes() {
+    assert_eq!(Locale::from_code("id"), Some(Locale::Id));
+    assert_eq!(Locale::from_code("en-US"), Some(Locale::En));
+    assert_eq!(Locale::from_code(" EN_gb "), Some(Locale::En));
+    assert_eq!(Locale::default(), Locale::Id);
+
+    let err = "fr".parse::<Locale>().unwrap_err();
+    assert!(matches!(err, ValidationError::Config(ref message) if message.contains("id, en")));
+}
+
+#[test]
+fn test_syntax_errors_use_stable_codes() {
+    let error = check_syntax("budi@", "email").unwrap_err();
+    assert_eq!(error, LocalMessage::EmailFormat);
+    assert_eq!(error.code(), "email_format");
+    assert_eq!(error.render(Locale::En), "Invalid email format (example: user@domain.com).");
+
+    // Fungsi lama tetap mengembalikan pesan bahasa Indonesia.
+    assert_eq!(
+        pre_validate_syntactically("budi@", "email").unwrap_err(),
+        error.render(Locale::Id)
+    );
+
+    let error = check_syntax("12345", "nama").unwrap_err();
+    assert_eq!(error.code(), "digits_only");
+    assert_eq!(error.render(Locale::En), "'nama' must not consist of digits only.");
+}
+
+#[test]
+fn test_english_validator_localizes_syntax_messages() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let response = validator_with(provider.clone(), |b| b.locale(Locale::En))
+        .validate_sync("", "email")
+        .unwrap();
+
+    assert_eq!(response.message, "Input must not be empty.");
+    assert_eq!(provider.call_count(), 0);
+}
+
+#[test]
+fn test_prompt_steers_message_language() {
+    let indonesian = format_prompt_for("Universitas Indonesia", "nama institusi", Locale::Id);
+    let english = format_prompt_for("Universitas Indonesia", "nama institusi", Locale::En);
+    // Setiap prompt hanya menyebut satu bahasa untuk pesan.
+    assert!(indonesian.contains("in Indonesian (Bahasa Indonesia)"));
+    assert!(!indonesian.contains("English"));
+    assert!(english.contains("in English"));
+    assert!(!english.contains("Indonesian"));
+
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    validator_with(provider.clone(), |b| b.locale(Locale::En))
+        .validate_sync("Universitas Indonesia", "nama institusi")
+        .unwrap();
+    assert!(sent_prompt(&provider, 0).contains("in English"));
+}
+
+#[test]
+fn test_cache_keys_differ_per_locale() {
+    let id = CacheKey::for_locale("Budi", "nama", "gemini-2.5-flash", Locale::Id);
+    let en = CacheKey::for_locale("Budi", "nama", "gemini-2.5-flash", Locale::En);
+
+    assert_eq!(id, CacheKey::new("Budi", "nama", "gemini-2.5-flash"));
+    assert_ne!(id.prompt_version, en.prompt_version);
+}
+
+#[test]
+fn test_batch_is_localized() {
+    let provider = Arc::new(FakeProvider::always(
+        r#"[{"field": "a", "valid": true, "message": "ok"}, {"field": "b", "valid": true, "message": "ok"}]"#,
+    ));
+    let items = vec![
+        BatchInput::new("a", "Budi Santoso", "nama"),
+        BatchInput::new("b", "Siti Aminah", "nama"),
+        BatchInput::new("c", "", "email"),
+    ];
+
+    let results = validator_with(provider.clone(), |b| b.locale(Locale::En))
+        .validate_batch_sync(&items)
+        .unwrap();
+
+    let prompt = sent_prompt(&provider, 0);
+    assert!(prompt.contains("in English"));
+    assert!(!prompt.contains("Indonesian"));
+    assert!(results["a"].valid && results["b"].valid);
+    assert_eq!(results["c"].message, "Input must not be empty.");
+}
+
+#[test]
+fn test_form_local_findings_are_localized() {
+    let form = FormInput::new()
+        .field("email", "budi@gmail.com", "email")
+        .field("perusahaan", "PT Telkom Indonesia", "nama perusahaan")
+        .relationship(Relationship::email_domain_company("email", "perusahaan"));
+
+    let report = validator_with(Arc::new(FakeProvider::always(VERDICT)), |b| b.locale(Locale::En))
+        .check_form_sync(&form)
+        .unwrap();
+
+    assert_eq!(
+        report.findings[0].message,
+        "Email 'budi@gmail.com' uses a personal email provider (gmail.com), not a domain owned by 'PT Telkom Indonesia'."
+    );
+}
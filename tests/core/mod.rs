@@ -2,3 +2,23 @@ mod parser_tests;
 mod prompt_test;
 mod body_generation_tests;
 mod api_config_test;
+mod validator_tests;
+mod provider_tests;
+mod openai_provider_tests;
+mod error_tests;
+mod retry_tests;
+mod fallback_tests;
+mod cache_tests;
+mod file_cache_tests;
+mod batch_tests;
+mod runner_tests;
+mod form_tests;
+mod locale_tests;
+mod rules_tests;
+mod label_resolver_tests;
+mod classify_tests;
+mod injection_tests;
+mod response_schema_tests;
+mod telemetry_tests;
+mod credential_tests;
+mod key_pool_tests;
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use validation_semantic::core::{OpenAiCompatibleProvider, SemanticValidator};
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
+fn chat_completion(content: &str) -> String {
+    serde_json::json!({
+        "id": "chatcmpl-1",
+        "object": "chat.completion",
+        "choices": [
+            { "index": 0, "message": { "role": "assistant", "content": content }, "finish_reason": "stop" }
+        ]
+    })
+    .to_string()
+}
+
+fn validator_for(server: &StubServer, api_key: Option<&str>) -> SemanticValidator {
+    let provider = OpenAiCompatibleProvider::new(format!("{}/v1", server.base_url), "llama3.1:8b");
+    let mut builder = SemanticValidator::builder().provider(Arc::new(provider));
+    if let Some(key) = api_key {
+        builder = builder.api_key(key);
+    }
+    builder.build().unwrap()
+}
+
+#[test]
+fn test_openai_provider_sends_prompt_and_parses_reply() {
+    let server = StubServer::start(vec![StubResponse::json(
+        200,
+        &chat_completion(r#"{"valid": true, "message": "Nama institusi valid."}"#),
+    )]);
+    let validator = validator_for(&server, None);
+
+    let response = validator.validate_sync("Universitas Gadjah Mada", "nama institusi").unwrap();
+    assert!(response.valid);
+    assert_eq!(response.message, "Nama institusi valid.");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].request_line, "POST /v1/chat/completions HTTP/1.1");
+    assert!(requests[0].header("authorization").is_none());
+
+    let body = requests[0].json();
+    assert_eq!(body["model"], "llama3.1:8b");
+    assert_eq!(body["response_format"]["type"], "json_object");
+    assert_eq!(body["messages"][0]["role"], "system");
+    assert!(body["messages"][0]["content"].as_str().unwrap().contains("<user_input>"));
+    assert_eq!(body["messages"][1]["role"], "user");
+    assert!(body["messages"][1]["content"]
+        .as_str()
+        .unwrap()
+        .contains("Universitas Gadjah Mada"));
+}
+
+#[test]
+fn test_openai_provider_sends_bearer_token_when_key_given() {
+    let server = StubServer::start(vec![StubResponse::json(
+        200,
+        &chat_completion(r#"```json
+{"valid": false, "message": "Bukan nama nyata."}
+```"#),
+    )]);
+    let validator = validator_for(&server, Some("sk-local"));
+
+    let response = validator.validate_sync("asdf qwerty", "nama institusi").unwrap();
+    assert!(!response.valid);
+    assert_eq!(server.requests()[0].header("authorization"), Some("Bearer sk-local"));
+}
+
+#[test]
+fn test_openai_provider_can_disable_json_mode() {
+    let server = StubServer::start(vec![StubResponse::json(
+        200,
+        &chat_completion(r#"{"valid": true, "message": "OK"}"#),
+    )]);
+    let provider = OpenAiCompatibleProvider::new(format!("{}/v1/", server.base_url), "qwen2.5")
+        .json_mode(false);
+    let validator = SemanticValidator::builder()
+        .provider(Arc::new(provider))
+        .build()
+        .unwrap();
+
+    validator.validate_sync("Universitas Gadjah Mada", "nama institusi").unwrap();
+    assert!(server.requests()[0].json().get("response_format").is_none());
+}
+
+#[test]
+fn test_openai_provider_maps_error_body() {
+    let server = StubServer::start(vec![StubResponse::json(404, r#"{"error": "model 'llama3.1:8b' not found"}"#)]);
+    let validator = validator_for(&server, None);
+
+    let err = validator
+        .validate_sync("Universitas Gadjah Mada", "nama institusi")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("404"));
+    assert!(err.contains("not found"));
+}
+
+#[tokio::test]
+async fn test_openai_provider_async_path() {
+    let server = StubServer::start(vec![StubResponse::json(
+        200,
+        &chat_completion(r#"{"valid": true, "message": "OK"}"#),
+    )]);
+    let validator = validator_for(&server, None);
+
+    let response = validator
+        .validate_async("Universitas Gadjah Mada", "nama institusi")
+        .await
+        .unwrap();
+    assert!(response.valid);
+}
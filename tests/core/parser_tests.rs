@@ -1,9 +1,10 @@
 use validation_semantic::core::{
     parse_gemini_response,
-   
+    parse_validation_output,
 };
 
 use validation_semantic::models::{
+    ReasonCode, ValidationStage,
     GeminiApiContent, GeminiApiPart,
     GeminiApiResponse, 
     GeminiApiResponseCandidate,
@@ -56,3 +57,38 @@ fn test_parse_gemini_response_invalid_json() {
 
     assert!(parse_gemini_response(response).is_err());
 }
+
+#[test]
+fn test_parse_validation_output_reads_metadata() {
+    let parsed = parse_validation_output(
+        r#"{"valid": false, "message": "Data contoh", "code": "placeholder", "confidence": 0.85, "suggestion": "budi@gmail.com"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(parsed.stage, ValidationStage::Llm);
+    assert_eq!(parsed.code, ReasonCode::Placeholder);
+    assert_eq!(parsed.confidence, Some(0.85));
+    assert_eq!(parsed.suggestion.as_deref(), Some("budi@gmail.com"));
+}
+
+#[test]
+fn test_parse_validation_output_normalizes_metadata() {
+    // Kode tak dikenal menjadi semantic_invalid, persen diubah ke 0..1, usulan kosong dibuang.
+    let parsed = parse_validation_output(
+        r#"{"valid": false, "message": "x", "code": "weird", "confidence": 95, "suggestion": " "}"#,
+    )
+    .unwrap();
+    assert_eq!(parsed.code, ReasonCode::SemanticInvalid);
+    assert_eq!(parsed.confidence, Some(0.95));
+    assert!(parsed.suggestion.is_none());
+
+    // Kode mengikuti `valid`, dan confidence yang tidak bisa dibaca diabaikan.
+    let parsed = parse_validation_output(r#"{"valid": true, "message": "OK", "code": "gibberish", "confidence": "tinggi"}"#)
+        .unwrap();
+    assert_eq!(parsed.code, ReasonCode::Valid);
+    assert!(parsed.confidence.is_none());
+
+    let parsed = parse_validation_output(r#"{"valid": false, "message": "x", "confidence": "80%"}"#).unwrap();
+    assert_eq!(parsed.code, ReasonCode::SemanticInvalid);
+    assert_eq!(parsed.confidence, Some(0.8));
+}
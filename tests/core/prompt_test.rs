@@ -26,6 +26,8 @@ fn test_format_prompt_comprehensive() {
         assert!(prompt.to_lowercase().contains("json"));
         assert!(prompt.to_lowercase().contains("valid"));
         assert!(prompt.to_lowercase().contains("message"));
+        assert!(prompt.contains("\"confidence\"") && prompt.contains("\"suggestion\""));
+        assert!(prompt.contains("\"placeholder\""));
     }
 }
 
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use validation_semantic::core::{GeminiProvider, LlmProvider, LlmRequest, ValidationError};
+use validation_semantic::models::SupportedModel;
+
+use crate::support::fake_provider::{self, validator_with, FakeProvider};
+
+#[test]
+fn test_fake_provider_receives_prompt_and_verdict_is_parsed() {
+    let provider = Arc::new(FakeProvider::always(r#"{"valid": true, "message": "OK"}"#));
+    let validator = validator_with(provider.clone(), |b| b);
+
+    let response = validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap();
+    assert!(response.valid);
+    assert_eq!(response.message, "OK");
+
+    let requests = provider.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, format!("fake://{}", SupportedModel::GeminiFlash.as_str()));
+    assert!(requests[0].body["prompt"].as_str().unwrap().contains("Universitas Indonesia"));
+}
+
+#[test]
+fn test_syntax_precheck_runs_before_provider() {
+    let provider = Arc::new(FakeProvider::always(r#"{"valid": true, "message": "OK"}"#));
+    let validator = validator_with(provider.clone(), |b| b);
+
+    let response = validator.validate_sync("bukan-email", "email").unwrap();
+    assert!(!response.valid);
+    assert_eq!(provider.call_count(), 0);
+}
+
+#[test]
+fn test_provider_error_mapping_is_used_for_non_success() {
+    let provider = Arc::new(FakeProvider::sequence(vec![fake_provider::status(500, "boom")]));
+    let validator = validator_with(provider, |b| b);
+
+    let err = validator
+        .validate_with_model_sync("Universitas Indonesia", SupportedModel::Gemma, "nama institusi")
+        .unwrap_err();
+    match err {
+        ValidationError::Http { status, body } => {
+            assert_eq!(status, 500);
+            assert_eq!(body, "fake error for gemma-3-27b-it");
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_fake_provider_async_path() {
+    let provider = Arc::new(FakeProvider::always(r#"[{"valid": false, "message": "Dummy"}]"#));
+    let validator = validator_with(provider.clone(), |b| b);
+
+    let response = validator.validate_async("asdf qwerty", "nama institusi").await.unwrap();
+    assert!(!response.valid);
+    assert_eq!(provider.call_count(), 1);
+}
+
+#[test]
+fn test_gemini_provider_builds_generate_content_request() {
+    let provider = GeminiProvider::new("http://localhost:8080/v1beta/");
+    let request = LlmRequest {
+        model: "gemini-2.5-flash",
+        system: None,
+        response_schema: None,
+        prompt: "hello",
+        api_key: "k",
+    };
+
+    let http_request = provider.build_request(&request).unwrap();
+    assert!(http_request
+        .url
+        .starts_with("http://localhost:8080/v1beta/models/gemini-2.5-flash:generateContent"));
+    assert_eq!(http_request.body["contents"][0]["parts"][0]["text"], "hello");
+}
+
+#[test]
+fn test_gemini_provider_decodes_first_candidate() {
+    let provider = GeminiProvider::default();
+    let request = LlmRequest {
+        model: "gemini-2.5-flash",
+        system: None,
+        response_schema: None,
+        prompt: "hello",
+        api_key: "k",
+    };
+    let body = r#"{"candidates":[{"content":{"parts":[{"text":"{\"valid\":true,\"message\":\"OK\"}"}]}}]}"#;
+
+    let text = provider
+        .decode_response(&request, &fake_provider::ok(body))
+        .unwrap();
+    assert_eq!(text, r#"{"valid":true,"message":"OK"}"#);
+}
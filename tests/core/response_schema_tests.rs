@@ -0,0 +1,104 @@
+use validation_semantic::core::{
+    parse_structured_output, validation_response_schema, GeminiProvider, LlmProvider, LlmRequest, SemanticValidator,
+    ValidationError,
+};
+use validation_semantic::models::{ReasonCode, SupportedModel};
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
+fn gemini_reply(text: &str) -> String {
+    serde_json::json!({ "candidates": [{ "content": { "parts": [{ "text": text }] } }] }).to_string()
+}
+
+fn violation_reason(text: &str) -> String {
+    match parse_structured_output(text).unwrap_err() {
+        ValidationError::SchemaViolation { raw, reason } => {
+            assert_eq!(raw, text);
+            reason
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_schema_lists_every_reason_code() {
+    let schema = validation_response_schema();
+    assert_eq!(schema["type"], "OBJECT");
+    assert_eq!(schema["required"], serde_json::json!(["valid", "message", "code"]));
+
+    let codes = schema["properties"]["code"]["enum"].as_array().unwrap();
+    assert_eq!(codes[0], "valid");
+    for code in ReasonCode::LLM_CODES {
+        assert!(codes.contains(&serde_json::json!(code.as_str())), "{}", code.as_str());
+    }
+}
+
+#[test]
+fn test_gemini_sends_schema_only_for_models_that_support_it() {
+    let provider = GeminiProvider::default();
+    let request = |model| LlmRequest {
+        model,
+        system: None,
+        response_schema: Some(validation_response_schema()),
+        prompt: "hello",
+        api_key: "k",
+    };
+
+    let body = provider.build_request(&request("gemini-2.5-flash")).unwrap().body;
+    assert_eq!(body["generationConfig"]["responseMimeType"], "application/json");
+    assert_eq!(&body["generationConfig"]["responseSchema"], validation_response_schema());
+
+    let body = provider.build_request(&request("gemma-3-27b-it")).unwrap().body;
+    assert!(body.get("generationConfig").is_none());
+    assert!(!provider.supports_response_schema("gemma-3-27b-it"));
+}
+
+#[test]
+fn test_structured_output_is_checked_strictly() {
+    let parsed = parse_structured_output(
+        r#"{"valid": false, "message": "Teks acak.", "code": "gibberish", "confidence": 0.9, "suggestion": null}"#,
+    )
+    .unwrap();
+    assert!(!parsed.valid);
+    assert_eq!(parsed.code, ReasonCode::Gibberish);
+    assert_eq!(parsed.confidence, Some(0.9));
+
+    assert!(violation_reason(r#"[{"valid": true, "message": "OK", "code": "valid"}]"#).contains("objek"));
+    assert!(violation_reason(r#"{"valid": "true", "message": "OK", "code": "valid"}"#).contains("valid"));
+    assert!(violation_reason(r#"{"valid": true, "code": "valid"}"#).contains("message"));
+    assert!(violation_reason(r#"{"valid": false, "message": "x", "code": "nonsense"}"#).contains("nonsense"));
+    assert!(violation_reason(r#"{"valid": true, "message": "OK", "code": "valid", "confidence": 95}"#)
+        .contains("confidence"));
+
+    // Teks yang sama sekali bukan JSON tetap `ModelOutputMalformed`.
+    assert!(matches!(
+        parse_structured_output("Sure! The input is valid."),
+        Err(ValidationError::ModelOutputMalformed { .. })
+    ));
+}
+
+#[test]
+fn test_schema_violation_and_gemma_fallback_end_to_end() {
+    let server = StubServer::start(vec![
+        StubResponse::json(200, &gemini_reply(r#"[{"valid": true, "message": "OK"}]"#)),
+        StubResponse::json(200, &gemini_reply("```json\n[{\"valid\": true, \"message\": \"OK\"}]\n```")),
+    ]);
+    let validator = SemanticValidator::builder()
+        .api_key("k")
+        .base_url(format!("{}/v1beta", server.base_url))
+        .build()
+        .unwrap();
+
+    let err = validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap_err();
+    assert_eq!(err.code(), "schema_violation");
+
+    // Gemma tidak mendukung responseSchema, jadi keluaran bebasnya di-parse secara toleran.
+    let response = validator
+        .validate_with_model_sync("Universitas Indonesia", SupportedModel::Gemma, "nama institusi")
+        .unwrap();
+    assert!(response.valid);
+
+    let requests = server.requests();
+    assert!(requests[0].json()["generationConfig"]["responseSchema"].is_object());
+    assert!(requests[1].json().get("generationConfig").is_none());
+}
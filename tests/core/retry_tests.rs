@@ -0,0 +1,187 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use validation_semantic::core::{
+    GeminiProvider, HttpResponse, LlmProvider, LlmRequest, RetryPolicy, SemanticValidator, ValidationError,
+};
+
+use crate::support::fake_provider::{self, validator_with, FakeProvider};
+use crate::support::stub_server::{StubResponse, StubServer};
+
+const VERDICT: &str = r#"{"valid": true, "message": "OK"}"#;
+
+fn fast_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::default()
+        .max_attempts(max_attempts)
+        .base_delay(Duration::from_millis(1))
+        .jitter(false)
+}
+
+fn http(status: u16) -> ValidationError {
+    ValidationError::Http {
+        status,
+        body: String::new(),
+    }
+}
+
+#[test]
+fn test_policy_decides_which_errors_are_retried() {
+    let policy = RetryPolicy::default();
+
+    assert!(policy.should_retry(1, &http(503)));
+    assert!(policy.should_retry(1, &ValidationError::Transport("reset".into())));
+    assert!(policy.should_retry(1, &ValidationError::Timeout("slow".into())));
+    assert!(policy.should_retry(
+        1,
+        &ValidationError::RateLimited {
+            model: "m".into(),
+            retry_after: None,
+            message: String::new()
+        }
+    ));
+
+    assert!(!policy.should_retry(1, &http(400)));
+    assert!(!policy.should_retry(1, &ValidationError::Unauthorized { status: 401, message: String::new() }));
+    assert!(!policy.should_retry(1, &ValidationError::Blocked { reason: "SAFETY".into() }));
+    assert!(!policy.should_retry(policy.max_attempts, &http(503)));
+
+    assert!(!policy.clone().retry_transport(false).should_retry(1, &ValidationError::Transport("x".into())));
+    assert!(!RetryPolicy::none().should_retry(1, &http(503)));
+}
+
+#[test]
+fn test_backoff_is_exponential_and_capped() {
+    let policy = RetryPolicy::default()
+        .base_delay(Duration::from_millis(100))
+        .max_delay(Duration::from_millis(350))
+        .jitter(false);
+
+    assert_eq!(policy.delay_for(1, &http(503)), Duration::from_millis(100));
+    assert_eq!(policy.delay_for(2, &http(503)), Duration::from_millis(200));
+    assert_eq!(policy.delay_for(3, &http(503)), Duration::from_millis(350));
+}
+
+#[test]
+fn test_jitter_stays_within_half_to_full_backoff() {
+    let policy = RetryPolicy::default().base_delay(Duration::from_millis(200));
+    for _ in 0..50 {
+        let delay = policy.delay_for(1, &http(503));
+        assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+    }
+}
+
+#[test]
+fn test_server_retry_after_overrides_backoff_but_respects_max_delay() {
+    let policy = RetryPolicy::default().max_delay(Duration::from_secs(10));
+    let rate_limited = |secs| ValidationError::RateLimited {
+        model: "m".into(),
+        retry_after: Some(Duration::from_secs(secs)),
+        message: String::new(),
+    };
+
+    assert_eq!(policy.delay_for(1, &rate_limited(4)), Duration::from_secs(4));
+    assert_eq!(policy.delay_for(1, &rate_limited(60)), Duration::from_secs(10));
+}
+
+#[test]
+fn test_gemini_retry_info_detail_is_used_as_retry_after() {
+    let body = r#"{"error": {"code": 429, "message": "Quota exceeded", "status": "RESOURCE_EXHAUSTED",
+        "details": [{"@type": "type.googleapis.com/google.rpc.RetryInfo", "retryDelay": "12.5s"}]}}"#;
+    let request = LlmRequest {
+        model: "gemini-2.5-flash",
+        system: None,
+        response_schema: None,
+        prompt: "prompt",
+        api_key: "k",
+    };
+    let response = HttpResponse {
+        status: 429,
+        headers: Vec::new(),
+        body: body.to_string(),
+    };
+
+    let err = GeminiProvider::default().map_error(&request, &response);
+    assert_eq!(err.retry_after(), Some(Duration::from_millis(12_500)));
+}
+
+#[test]
+fn test_transient_failures_are_retried_until_success() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(503, "overloaded"),
+        fake_provider::status(429, "slow down"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let validator = validator_with(provider.clone(), |b| b.retry_policy(fast_policy(3)));
+
+    let response = validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap();
+    assert!(response.valid);
+    assert_eq!(provider.call_count(), 3);
+}
+
+#[test]
+fn test_last_error_is_returned_when_attempts_run_out() {
+    let provider = Arc::new(FakeProvider::sequence(vec![fake_provider::status(500, "boom")]));
+    let validator = validator_with(provider.clone(), |b| b.retry_policy(fast_policy(2)));
+
+    let err = validator
+        .validate_sync("Universitas Indonesia", "nama institusi")
+        .unwrap_err();
+    assert_eq!(err.status(), Some(500));
+    assert_eq!(provider.call_count(), 2);
+}
+
+#[test]
+fn test_non_retryable_status_fails_immediately() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(400, "bad request"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let validator = validator_with(provider.clone(), |b| b.retry_policy(fast_policy(3)));
+
+    assert!(validator.validate_sync("Universitas Indonesia", "nama institusi").is_err());
+    assert_eq!(provider.call_count(), 1);
+}
+
+#[tokio::test]
+async fn test_async_path_retries() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(502, "bad gateway"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let validator = validator_with(provider.clone(), |b| b.retry_policy(fast_policy(3)));
+
+    let response = validator
+        .validate_async("Universitas Indonesia", "nama institusi")
+        .await
+        .unwrap();
+    assert!(response.valid);
+    assert_eq!(provider.call_count(), 2);
+}
+
+#[test]
+fn test_gemini_retry_after_header_is_honored_over_http() {
+    let success = serde_json::json!({
+        // Model Gemini dibatasi responseSchema, jadi putusannya selalu memuat `code`.
+        "candidates": [{ "content": { "parts": [{ "text": r#"{"valid": true, "message": "OK", "code": "valid"}"# }] } }]
+    })
+    .to_string();
+    let server = StubServer::start(vec![
+        StubResponse::json(429, r#"{"error": {"code": 429, "message": "Quota exceeded"}}"#)
+            .with_header("Retry-After", "1"),
+        StubResponse::json(200, &success),
+    ]);
+    let validator = SemanticValidator::builder()
+        .api_key("k")
+        .base_url(format!("{}/v1beta", server.base_url))
+        .retry_policy(fast_policy(2))
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    let response = validator.validate_sync("Universitas Indonesia", "nama institusi").unwrap();
+
+    assert!(response.valid);
+    assert_eq!(server.requests().len(), 2);
+    // Jeda dari server (1 detik) dipakai, bukan base_delay 1 ms.
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use validation_semantic::core::{format_prompt, BatchInput, CharClass, LocalMessage, Locale, TypeRules, ValidationError};
+use validation_semantic::models::{ReasonCode, ValidationStage};
+
+use crate::support::fake_provider::{sent_prompt, validator_with, FakeProvider};
+
+const VERDICT: &str = r#"{"valid": true, "message": "OK"}"#;
+const EXAMPLE_RULES: &str = include_str!("../../rules/example_rules.toml");
+
+const YAML_RULES: &str = r#"
+types:
+  - name: kode voucher
+    aliases: [voucher]
+    min_length: 6
+    pattern: "^VC-"
+    require: [digit]
+    prompt_rules:
+      - "The code must belong to an active campaign."
+    valid_examples: ["VC-2024-AB12"]
+    invalid_examples: ["VC-TEST-0000"]
+"#;
+
+fn config_error(result: Result<TypeRules, ValidationError>) -> String {
+    match result {
+        Err(ValidationError::Config(message)) => message,
+        other => panic!("expected Config error, got {:?}", other.map(|rules| rules.len())),
+    }
+}
+
+#[test]
+fn test_example_file_parses() {
+    let rules = TypeRules::from_toml_str(EXAMPLE_RULES).unwrap();
+
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules.get("  Institution ").unwrap().name, "nama institusi");
+    let voucher = rules.get("VOUCHER CODE").unwrap();
+    assert_eq!(voucher.language, Some(Locale::En));
+    assert_eq!(voucher.require, vec![CharClass::Digit]);
+    assert!(rules.get("email").is_none());
+}
+
+#[test]
+fn test_yaml_and_autodetect() {
+    let yaml = TypeRules::from_yaml_str(YAML_RULES).unwrap();
+    let detected = TypeRules::parse(YAML_RULES).unwrap();
+    assert_eq!(yaml.get("voucher"), detected.get("voucher"));
+
+    let toml = TypeRules::parse(EXAMPLE_RULES).unwrap();
+    assert_eq!(toml.len(), 2);
+}
+
+#[test]
+fn test_local_constraints() {
+    let rules = TypeRules::from_yaml_str(YAML_RULES).unwrap();
+    let check = |input: &str| rules.check("voucher", input).unwrap();
+
+    assert_eq!(check("   "), Err(LocalMessage::EmptyInput));
+    assert_eq!(check("VC-1").unwrap_err().code(), "length_below");
+    assert_eq!(check("AB-2024").unwrap_err().code(), "pattern_mismatch");
+    let missing = check("VC-ABCD").unwrap_err();
+    assert_eq!(missing.render(Locale::Id), "'kode voucher' harus mengandung angka.");
+    assert_eq!(missing.render(Locale::En), "'kode voucher' must contain a digit.");
+    assert_eq!(check("VC-2024-AB12"), Ok(()));
+    assert!(rules.check("email", "x").is_none());
+}
+
+#[test]
+fn test_invalid_rule_files_are_config_errors() {
+    let bad_regex = config_error(TypeRules::from_toml_str("[[types]]\nname = \"a\"\npattern = \"(\""));
+    assert!(bad_regex.contains("pattern"));
+
+    let duplicate = config_error(TypeRules::from_toml_str(
+        "[[types]]\nname = \"a\"\naliases = [\"b\"]\n[[types]]\nname = \"B\"",
+    ));
+    assert!(duplicate.contains("'B'"));
+
+    let bounds = config_error(TypeRules::from_toml_str("[[types]]\nname = \"a\"\nmin_length = 5\nmax_length = 2"));
+    assert!(bounds.contains("min_length"));
+
+    config_error(TypeRules::from_toml_str("[[types]]\nname = \"a\"\nmin_lenght = 5"));
+    config_error(TypeRules::from_yaml_str("types:\n  - name: a\n    require: [emoji]"));
+    config_error(TypeRules::from_path("rules/example_rules.json"));
+}
+
+#[test]
+fn test_from_path_uses_extension() {
+    let path = std::env::temp_dir().join(format!("validation_semantic_rules_{}.yml", std::process::id()));
+    std::fs::write(&path, YAML_RULES).unwrap();
+    let rules = TypeRules::from_path(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(rules.unwrap().get("kode voucher").is_some());
+    assert_eq!(TypeRules::from_path("rules/example_rules.toml").unwrap().len(), 2);
+}
+
+#[test]
+fn test_custom_type_prompt_and_syntax() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let rules = Arc::new(TypeRules::from_yaml_str(YAML_RULES).unwrap());
+    let validator = validator_with(provider.clone(), |b| b.type_rules(rules));
+
+    let rejected = validator.validate_sync("VC-ABCD", "voucher").unwrap();
+    assert_eq!(rejected.stage, ValidationStage::Syntax);
+    assert_eq!(rejected.code, ReasonCode::SyntaxInvalid);
+    assert_eq!(provider.call_count(), 0);
+
+    assert!(validator.validate_sync("VC-2024-AB12", "Voucher").unwrap().valid);
+    let prompt = sent_prompt(&provider, 0);
+    assert!(prompt.contains("type \"kode voucher\""));
+    assert!(prompt.contains("- The code must belong to an active campaign."));
+    assert!(prompt.contains("Examples of VALID input:\n- \"VC-2024-AB12\""));
+    assert!(prompt.contains("Examples of INVALID input:\n- \"VC-TEST-0000\""));
+    assert!(prompt.contains("\"confidence\""));
+}
+
+#[test]
+fn test_rules_override_builtin_type() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let rules = Arc::new(TypeRules::from_toml_str(EXAMPLE_RULES).unwrap());
+    let validator = validator_with(provider.clone(), |b| b.type_rules(rules));
+
+    // Bawaan hanya menolak input < 2 karakter; aturan file meminta minimal 5.
+    let rejected = validator.validate_sync("UGM", "nama institusi").unwrap();
+    assert_eq!(rejected.message, "'nama institusi' minimal 5 karakter.");
+
+    validator.validate_sync("Universitas Indonesia", "lembaga").unwrap();
+    let prompt = sent_prompt(&provider, 0);
+    assert!(prompt.contains("Type description: Nama institusi"));
+    assert_ne!(prompt, format_prompt("Universitas Indonesia", "lembaga"));
+}
+
+#[test]
+fn test_type_language_overrides_validator_locale() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let rules = Arc::new(TypeRules::from_toml_str(EXAMPLE_RULES).unwrap());
+    let validator = validator_with(provider.clone(), |b| b.type_rules(rules));
+    assert_eq!(validator.locale(), Locale::Id);
+
+    let rejected = validator.validate_sync("VC-1", "kode voucher").unwrap();
+    assert_eq!(rejected.message, "'kode voucher' must be at least 6 characters long.");
+
+    validator.validate_sync("VC-2024-AB12", "kode voucher").unwrap();
+    let prompt = sent_prompt(&provider, 0);
+    assert!(prompt.contains("short explanation in English"));
+    assert!(!prompt.contains("Indonesian"));
+}
+
+#[test]
+fn test_batch_uses_custom_task_prompt() {
+    let provider = Arc::new(FakeProvider::always(
+        r#"[{"field": "a", "valid": true, "message": "ok"}, {"field": "b", "valid": true, "message": "ok"}]"#,
+    ));
+    let rules = Arc::new(TypeRules::from_yaml_str(YAML_RULES).unwrap());
+    let validator = validator_with(provider.clone(), |b| b.type_rules(rules));
+    let items = vec![
+        BatchInput::new("a", "VC-2024-AB12", "voucher"),
+        BatchInput::new("b", "Budi Santoso", "nama"),
+        BatchInput::new("c", "VC-ABCD", "voucher"),
+    ];
+
+    let results = validator.validate_batch_sync(&items).unwrap();
+
+    assert!(sent_prompt(&provider, 0).contains("- The code must belong to an active campaign."));
+    assert!(results["a"].valid && results["b"].valid);
+    assert_eq!(results["c"].message, "'kode voucher' harus mengandung angka.");
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use validation_semantic::core::{BatchInput, Progress, RateLimiter, SemanticValidator};
+use validation_semantic::models::SupportedModel;
+
+use crate::support::fake_provider::{self, validator_with, FakeProvider};
+
+const VERDICT: &str = r#"{"valid": true, "message": "OK"}"#;
+
+fn items(count: usize) -> Vec<BatchInput> {
+    (0..count)
+        .map(|i| BatchInput::new(format!("field-{}", i), format!("Universitas Nomor {}", i), "nama institusi"))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_concurrency_is_bounded() {
+    let provider = Arc::new(FakeProvider::always(VERDICT).with_delay(Duration::from_millis(20)));
+    let results = validator_with(provider.clone(), |b| b)
+        .validate_many_async(&items(10), SupportedModel::GeminiFlash, 3, |_| {})
+        .await;
+
+    assert_eq!(results.len(), 10);
+    assert!(results.iter().all(|r| r.as_ref().unwrap().valid));
+    assert_eq!(provider.call_count(), 10);
+    assert_eq!(provider.max_in_flight(), 3);
+}
+
+#[tokio::test]
+async fn test_results_keep_input_order() {
+    let provider = Arc::new(FakeProvider::always(VERDICT).with_delay(Duration::from_millis(10)));
+    let mut input = items(4);
+    input.insert(1, BatchInput::new("email", "bukan-email", "email"));
+
+    let results = validator_with(provider, |b| b)
+        .validate_many_async(&input, SupportedModel::GeminiFlash, 4, |_| {})
+        .await;
+
+    let responses: Vec<_> = results.into_iter().map(Result::unwrap).collect();
+    assert!(!responses[1].valid);
+    assert!(responses[1].model.is_none());
+    assert!(responses.iter().enumerate().all(|(i, r)| i == 1 || r.valid));
+}
+
+#[tokio::test]
+async fn test_progress_reports_every_item_and_failures() {
+    let provider = Arc::new(FakeProvider::sequence(vec![
+        fake_provider::status(500, "boom"),
+        fake_provider::ok(VERDICT),
+    ]));
+    let mut reports: Vec<Progress> = Vec::new();
+
+    let results = validator_with(provider, |b| b)
+        .validate_many_async(&items(3), SupportedModel::GeminiFlash, 1, |p| reports.push(p))
+        .await;
+
+    assert!(results[0].is_err());
+    assert_eq!(reports.len(), 3);
+    assert_eq!(reports.iter().map(|p| p.completed).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(
+        reports.last().copied(),
+        Some(Progress { completed: 3, total: 3, failed: 1 })
+    );
+}
+
+#[tokio::test]
+async fn test_rate_limit_spaces_requests_per_model() {
+    let provider = Arc::new(FakeProvider::always(VERDICT));
+    let validator = SemanticValidator::builder()
+        .provider(provider.clone())
+        .rate_limit(SupportedModel::GeminiFlash, 1200)
+        .build()
+        .unwrap();
+    assert_eq!(validator.rate_limit(SupportedModel::GeminiFlash), Some(1200));
+
+    // 1200 RPM = satu request per 50 ms; request pertama langsung jalan.
+    let started = Instant::now();
+    validator
+        .validate_many_async(&items(3), SupportedModel::GeminiFlash, 3, |_| {})
+        .await;
+    assert!(started.elapsed() >= Duration::from_millis(100));
+
+    // Model lain tidak terkena batas.
+    let started = Instant::now();
+    validator
+        .validate_many_async(&items(3), SupportedModel::Gemma, 3, |_| {})
+        .await;
+    assert!(started.elapsed() < Duration::from_millis(100));
+}
+
+#[test]
+fn test_rate_limiter_can_be_removed() {
+    let limiter = RateLimiter::new();
+    limiter.set_limit("gemini-2.5-flash", 60);
+    assert_eq!(limiter.limit("gemini-2.5-flash"), Some(60));
+
+    limiter.set_limit("gemini-2.5-flash", 0);
+    assert_eq!(limiter.limit("gemini-2.5-flash"), None);
+}
+
+#[test]
+fn test_sync_runner_outside_async_runtime() {
+    let provider = Arc::new(FakeProvider::always(VERDICT).with_delay(Duration::from_millis(5)));
+    let mut last = Progress::default();
+
+    let results = validator_with(provider.clone(), |b| b)
+        .validate_many_sync(&items(5), SupportedModel::GeminiFlash, 2, |p| last = p);
+
+    assert!(results.iter().all(|r| r.is_ok()));
+    assert_eq!(last.completed, 5);
+    assert!(provider.max_in_flight() <= 2);
+}
@@ -0,0 +1,105 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use validation_semantic::core::telemetry::redact_input;
+use validation_semantic::core::{RetryPolicy, SemanticValidator};
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
+const API_KEY: &str = "AIzaSy-secret-test-key";
+
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Menjalankan `run` dengan subscriber yang menampung semua log level DEBUG ke atas.
+fn captured_logs(run: impl FnOnce()) -> String {
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_writer(move || writer.clone())
+        .finish();
+    tracing::subscriber::with_default(subscriber, run);
+    let bytes = buffer.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+fn validator(server: &StubServer, log_user_input: bool) -> SemanticValidator {
+    SemanticValidator::builder()
+        .api_key(API_KEY)
+        .base_url(format!("{}/v1beta", server.base_url))
+        .retry_policy(RetryPolicy::none())
+        .log_user_input(log_user_input)
+        .build()
+        .unwrap()
+}
+
+fn gemini_reply(text: &str) -> String {
+    serde_json::json!({ "candidates": [{ "content": { "parts": [{ "text": text }] } }] }).to_string()
+}
+
+#[test]
+fn test_redact_input_keeps_only_length() {
+    assert_eq!(redact_input("Budi Santoso", false), "<disamarkan, 12 karakter>");
+    assert_eq!(redact_input("Budi Santoso", true), "Budi Santoso");
+}
+
+#[test]
+fn test_logs_mask_input_and_never_contain_api_key() {
+    let server = StubServer::start(vec![
+        StubResponse::json(200, &gemini_reply(r#"{"valid": true, "message": "OK", "code": "valid"}"#)),
+        StubResponse::json(503, r#"{"error": {"code": 503, "message": "overloaded"}}"#),
+    ]);
+    let validator = validator(&server, false);
+
+    let logs = captured_logs(|| {
+        assert!(validator.validate_sync("Budi Santoso", "nama").unwrap().valid);
+        assert!(validator.validate_sync("Siti Aminah", "nama").is_err());
+    });
+
+    assert!(logs.contains("validasi selesai"), "{}", logs);
+    assert!(logs.contains("validasi gagal"), "{}", logs);
+    assert!(logs.contains("request_id="), "{}", logs);
+    assert!(logs.contains("latency_ms="), "{}", logs);
+    assert!(logs.contains("<disamarkan, 12 karakter>"), "{}", logs);
+    assert!(!logs.contains("Budi Santoso"), "{}", logs);
+    assert!(!logs.contains("Siti Aminah"), "{}", logs);
+    assert!(!logs.contains(API_KEY), "{}", logs);
+}
+
+#[test]
+fn test_requests_get_distinct_correlation_ids() {
+    let server = StubServer::start(vec![StubResponse::json(
+        200,
+        &gemini_reply(r#"{"valid": true, "message": "OK", "code": "valid"}"#),
+    )]);
+    let validator = validator(&server, true);
+
+    let logs = captured_logs(|| {
+        validator.validate_sync("Budi Santoso", "nama").unwrap();
+        validator.validate_sync("Budi Santoso", "nama").unwrap();
+    });
+
+    let mut ids: Vec<&str> = logs
+        .lines()
+        .filter(|line| line.contains("validasi selesai"))
+        .filter_map(|line| line.split("request_id=").nth(1))
+        .filter_map(|rest| rest.split(|c: char| !c.is_ascii_digit()).next())
+        .collect();
+    assert_eq!(ids.len(), 2, "{}", logs);
+    ids.dedup();
+    assert_eq!(ids.len(), 2, "{}", logs);
+    // Input hanya ditulis apa adanya jika `log_user_input` diaktifkan.
+    assert!(logs.contains("Budi Santoso"), "{}", logs);
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use validation_semantic::core::SemanticValidator;
+use validation_semantic::models::{ReasonCode, SupportedModel, ValidationStage};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_semantic_validator_is_send_sync() {
+    assert_send_sync::<SemanticValidator>();
+    assert_send_sync::<Arc<SemanticValidator>>();
+}
+
+#[test]
+fn test_builder_requires_api_key() {
+    let result = SemanticValidator::builder().build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_builder_applies_options() {
+    let validator = SemanticValidator::builder()
+        .api_key("test-key")
+        .default_model(SupportedModel::Gemma)
+        .timeout(Duration::from_secs(5))
+        .base_url("http://127.0.0.1:9/v1beta/")
+        .build()
+        .unwrap();
+
+    assert_eq!(validator.default_model(), SupportedModel::Gemma);
+    assert_eq!(validator.timeout(), Duration::from_secs(5));
+    assert_eq!(validator.base_url(), "http://127.0.0.1:9/v1beta");
+}
+
+#[test]
+fn test_syntax_rejection_does_not_call_llm() {
+    // Base URL sengaja tidak bisa dihubungi: input kosong harus ditolak sebelum request dikirim.
+    let validator = SemanticValidator::builder()
+        .api_key("test-key")
+        .base_url("http://127.0.0.1:9")
+        .build()
+        .unwrap();
+
+    let response = validator.validate_sync("", "email").unwrap();
+    assert!(!response.valid);
+    assert_eq!(response.stage, ValidationStage::Syntax);
+    assert_eq!(response.code, ReasonCode::EmptyInput);
+}
+
+#[test]
+fn test_shared_validator_across_threads() {
+    let validator = Arc::new(
+        SemanticValidator::builder()
+            .api_key("test-key")
+            .base_url("http://127.0.0.1:9")
+            .build()
+            .unwrap(),
+    );
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let validator = Arc::clone(&validator);
+            std::thread::spawn(move || validator.validate_sync("invalid-email", "email").unwrap())
+        })
+        .collect();
+
+    for handle in handles {
+        assert!(!handle.join().unwrap().valid);
+    }
+}
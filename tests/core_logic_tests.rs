@@ -1,4 +1,5 @@
 mod core;
 mod models;
 mod integration;
-mod unittest;
\ No newline at end of file
+mod unittest;
+mod support;
\ No newline at end of file
@@ -26,12 +26,12 @@ async fn test_validate_input_with_llm_async_integration() {
                             "   ✅ Success: valid={}, message='{}'",
                             response.valid, response.message
                         );
-                        assert!(response.message.len() > 0);
+                        assert!(!response.message.is_empty());
                     }
                     Err(e) => {
                         println!("   ❌ Error: {}", e);
                         // Error bisa karena API limit, network, dll
-                        assert!(e.to_string().len() > 0);
+                        assert!(!e.to_string().is_empty());
                     }
                 }
             }
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use validation_semantic::core::{check_syntax, format_prompt, LocalMessage, ValidationError};
+use validation_semantic::models::{list_input_types, InputType};
+
+use crate::support::fake_provider::{validator_with, FakeProvider};
+
+#[test]
+fn test_resolve_is_case_insensitive() {
+    assert_eq!(InputType::resolve("Email"), Some(InputType::Email));
+    assert_eq!(InputType::resolve("  NAMA   Lengkap "), Some(InputType::PersonName));
+    assert_eq!(InputType::resolve("Nama Pengguna"), Some(InputType::Username));
+    assert_eq!(InputType::resolve("profession"), Some(InputType::Occupation));
+    assert_eq!(InputType::resolve("kode voucher"), None);
+}
+
+#[test]
+fn test_aliases_are_unique_and_resolve_back() {
+    let mut seen = HashSet::new();
+    for input_type in InputType::ALL {
+        for alias in input_type.aliases() {
+            assert!(seen.insert(*alias), "alias '{}' dipakai lebih dari satu tipe", alias);
+            assert_eq!(InputType::resolve(alias), Some(input_type));
+        }
+        assert_eq!(input_type.label(), input_type.aliases()[0]);
+    }
+}
+
+#[test]
+fn test_list_input_types() {
+    let types = list_input_types();
+    assert_eq!(types.len(), InputType::ALL.len());
+
+    let email = types.iter().find(|info| info.id == "email").unwrap();
+    assert_eq!(email.label, "email");
+    assert!(email.aliases.contains(&"alamat email".to_string()));
+    assert!(!email.description.is_empty());
+
+    let json = serde_json::to_value(InputType::PersonName).unwrap();
+    assert_eq!(json, serde_json::json!("person_name"));
+}
+
+#[test]
+fn test_name_and_username_have_separate_rules() {
+    // Sebelumnya "nama" memakai aturan username ("Username terlalu pendek").
+    assert_eq!(check_syntax("Jo", "nama"), Err(LocalMessage::NameTooShort));
+    assert_eq!(check_syntax("Jo", "username"), Err(LocalMessage::UsernameTooShort));
+    assert_eq!(check_syntax("Budi", "Full Name"), Ok(()));
+
+    // Alias apa pun memilih templat prompt milik tipe kanonisnya.
+    assert!(format_prompt("budi@mail.co.id", "Mail").contains("Validate the following email address"));
+    assert!(format_prompt("Budi", "Nickname").contains("valid human full name"));
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_labels() {
+    let provider = Arc::new(FakeProvider::always(r#"{"valid": true, "message": "OK"}"#));
+    let validator = validator_with(provider.clone(), |b| b.strict_input_types(true));
+
+    let err = validator.validate_sync("Budi", "nama panggilan kucing").unwrap_err();
+    assert!(matches!(err, ValidationError::Config(ref message) if message.contains("nama panggilan kucing")));
+    assert_eq!(provider.call_count(), 0);
+
+    assert!(validator.validate_sync("Budi Santoso", "NAMA").unwrap().valid);
+}
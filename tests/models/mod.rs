@@ -1,2 +1,4 @@
 mod validation_response_tests;
 mod supported_model_tests;
+mod input_type_tests;
+mod model_capabilities_tests;
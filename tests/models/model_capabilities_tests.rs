@@ -0,0 +1,105 @@
+use validation_semantic::core::{common_body_generation, generation_body, GeminiProvider, LlmProvider, LlmRequest, SemanticValidator};
+use validation_semantic::models::{GenerationDefaults, ModelCapabilities, SupportedModel};
+
+use crate::support::stub_server::{StubResponse, StubServer};
+
+#[test]
+fn test_every_supported_model_has_capabilities() {
+    for model in SupportedModel::ALL {
+        assert_eq!(SupportedModel::from_name(model.as_str()), Some(model));
+        assert_eq!(ModelCapabilities::for_model_id(model.as_str()), Some(model.capabilities()));
+    }
+    assert!(SupportedModel::GeminiFlash.capabilities().enforces_schema());
+    assert!(!SupportedModel::Gemma.capabilities().json_mode);
+    assert_eq!(ModelCapabilities::for_model_id("my-tuned-model"), None);
+}
+
+#[test]
+fn test_unlisted_model_ids_use_their_family() {
+    assert_eq!(ModelCapabilities::for_model_id("gemini-2.0-flash"), Some(ModelCapabilities::GEMINI_2_5));
+    assert_eq!(ModelCapabilities::for_model_id("gemma-3-12b-it"), Some(ModelCapabilities::GEMMA_3));
+
+    let body = common_body_generation("nilai", "gemini-2.0-flash");
+    assert_eq!(body["generationConfig"]["responseMimeType"], "application/json");
+    assert_eq!(
+        GeminiProvider::default().capabilities("gemini-2.0-flash"),
+        ModelCapabilities::GEMINI_2_5
+    );
+    assert!(GeminiProvider::default().supports_response_schema("gemini-2.0-flash"));
+    assert!(common_body_generation("nilai", "gemma-3-12b-it").get("generationConfig").is_none());
+}
+
+#[test]
+fn test_gemma_body_has_no_json_mode_and_inlines_system() {
+    let body = generation_body("nilai", Some("aturan"), None, &SupportedModel::Gemma.capabilities());
+    assert!(body.get("generationConfig").is_none());
+    assert!(body.get("systemInstruction").is_none());
+    assert_eq!(body["contents"][0]["parts"][0]["text"], "aturan\n\nnilai");
+
+    let body = generation_body("nilai", Some("aturan"), None, &SupportedModel::GeminiFlash.capabilities());
+    assert_eq!(body["systemInstruction"]["parts"][0]["text"], "aturan");
+    assert_eq!(body["contents"][0]["parts"][0]["text"], "nilai");
+    assert_eq!(body["generationConfig"]["responseMimeType"], "application/json");
+    assert_eq!(body["generationConfig"]["topK"], 40);
+}
+
+#[test]
+fn test_custom_model_capabilities_drive_the_body() {
+    let tuned = ModelCapabilities {
+        json_mode: true,
+        system_instruction: true,
+        response_schema: false,
+        thinking: true,
+        max_output_tokens: 1024,
+        generation: GenerationDefaults {
+            temperature: Some(0.0),
+            max_output_tokens: Some(4096),
+            thinking_budget: Some(0),
+            ..GenerationDefaults::default()
+        },
+    };
+    let provider = GeminiProvider::default().with_model("tunedModels/ktp-validator", tuned);
+    assert_eq!(provider.capabilities("tunedModels/ktp-validator"), tuned);
+    assert_eq!(provider.capabilities("unknown-model"), ModelCapabilities::default());
+    assert!(!provider.supports_response_schema("tunedModels/ktp-validator"));
+
+    let schema = serde_json::json!({ "type": "OBJECT" });
+    let request = LlmRequest {
+        model: "tunedModels/ktp-validator",
+        system: Some("aturan"),
+        response_schema: Some(&schema),
+        prompt: "nilai",
+        api_key: "k",
+    };
+    let config = provider.build_request(&request).unwrap().body["generationConfig"].clone();
+    assert_eq!(config["temperature"], 0.0);
+    assert_eq!(config["maxOutputTokens"], 1024);
+    assert_eq!(config["thinkingConfig"]["thinkingBudget"], 0);
+    assert_eq!(config["responseMimeType"], "application/json");
+    assert!(config.get("responseSchema").is_none());
+    assert!(config.get("topK").is_none());
+}
+
+#[test]
+fn test_validator_accepts_custom_model_id() {
+    let reply = serde_json::json!({
+        "candidates": [{ "content": { "parts": [{ "text": "```json\n{\"valid\": true, \"message\": \"OK\"}\n```" }] } }]
+    });
+    let server = StubServer::start(vec![StubResponse::json(200, &reply.to_string())]);
+    let validator = SemanticValidator::builder()
+        .api_key("k")
+        .base_url(format!("{}/v1beta", server.base_url))
+        .custom_model("my-gateway-model", ModelCapabilities::default())
+        .build()
+        .unwrap();
+
+    let response = validator
+        .validate_with_model_name_sync("Universitas Indonesia", "my-gateway-model", "nama institusi")
+        .unwrap();
+    assert!(response.valid);
+    assert_eq!(response.model.as_deref(), Some("my-gateway-model"));
+
+    let requests = server.requests();
+    assert!(requests[0].request_line.contains("/models/my-gateway-model:generateContent"));
+    assert!(requests[0].json().get("generationConfig").is_none());
+}
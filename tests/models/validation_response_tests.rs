@@ -1,10 +1,10 @@
-use validation_semantic::models::ValidationResponse;
+use validation_semantic::models::{ReasonCode, ValidationResponse, ValidationStage};
 
 #[test]
 fn test_validation_response_serialization() {
     let cases = vec![
-        ValidationResponse { valid: true, message: "OK".into() },
-        ValidationResponse { valid: false, message: "Error".into() },
+        ValidationResponse::new(true, "OK").with_model("gemma-3-27b-it"),
+        ValidationResponse::new(false, "Error"),
     ];
 
     for response in cases {
@@ -13,5 +13,64 @@ fn test_validation_response_serialization() {
 
         assert_eq!(response.valid, parsed.valid);
         assert_eq!(response.message, parsed.message);
+        assert_eq!(response.model, parsed.model);
     }
 }
+
+#[test]
+fn test_metadata_round_trips() {
+    let mut timed = ValidationResponse::new(true, "OK").with_model("gemma-3-27b-it");
+    timed.confidence = Some(0.9);
+    timed.latency_ms = 120;
+    let cases = vec![timed, ValidationResponse::syntax_rejected("", "Error".into())];
+
+    for response in cases {
+        let json = serde_json::to_string(&response).unwrap();
+        let parsed: ValidationResponse = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(response.stage, parsed.stage);
+        assert_eq!(response.code, parsed.code);
+        assert_eq!(response.confidence, parsed.confidence);
+        assert_eq!(response.latency_ms, parsed.latency_ms);
+    }
+}
+
+#[test]
+fn test_confidence_percentages_and_overshoot() {
+    let confidence = |raw: &str| {
+        let json = format!(r#"{{"valid": true, "message": "OK", "confidence": {}}}"#, raw);
+        serde_json::from_str::<ValidationResponse>(&json).unwrap().confidence
+    };
+
+    assert_eq!(confidence("0.75"), Some(0.75));
+    assert_eq!(confidence("95"), Some(0.95));
+    assert_eq!(confidence(r#""80%""#), Some(0.8));
+    // 1.5 adalah pembulatan yang kebablasan, bukan 1.5%.
+    assert_eq!(confidence("1.5"), Some(1.0));
+    assert_eq!(confidence("250"), Some(1.0));
+    assert_eq!(confidence("-1"), None);
+}
+
+#[test]
+fn test_old_json_without_metadata_still_parses() {
+    let parsed: ValidationResponse = serde_json::from_str(r#"{"valid": true, "message": "OK"}"#).unwrap();
+
+    assert_eq!(parsed.stage, ValidationStage::Llm);
+    assert_eq!(parsed.code, ReasonCode::Valid);
+    assert!(parsed.confidence.is_none() && parsed.suggestion.is_none());
+    assert_eq!(parsed.latency_ms, 0);
+}
+
+#[test]
+fn test_syntax_rejection_codes() {
+    let empty = ValidationResponse::syntax_rejected("   ", "Input tidak boleh kosong.".into());
+    assert_eq!(empty.stage, ValidationStage::Syntax);
+    assert_eq!(empty.code, ReasonCode::EmptyInput);
+
+    let invalid = ValidationResponse::syntax_rejected("budi@", "Format email tidak valid".into());
+    assert_eq!(invalid.code, ReasonCode::SyntaxInvalid);
+    assert_eq!(
+        serde_json::to_value(&invalid).unwrap()["code"],
+        serde_json::json!("syntax_invalid")
+    );
+}
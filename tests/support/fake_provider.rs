@@ -0,0 +1,166 @@
+// Provider palsu in-process: tidak ada request jaringan, respons diambil dari antrean.
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use validation_semantic::core::provider::{
+    HttpTransport, ProviderFuture, ProviderResult,
+};
+use validation_semantic::core::{
+    HttpRequest, HttpResponse, LlmProvider, LlmRequest, RetryPolicy, SemanticValidator, SemanticValidatorBuilder,
+    ValidationError,
+};
+
+pub struct FakeProvider {
+    responses: Mutex<VecDeque<HttpResponse>>,
+    fallback: HttpResponse,
+    requests: Mutex<Vec<HttpRequest>>,
+    // Jeda jalur async untuk meniru latensi jaringan, serta jumlah request yang sedang berjalan.
+    delay: Duration,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+impl FakeProvider {
+    /// Selalu menjawab dengan teks model yang sama (status 200).
+    pub fn always(model_text: &str) -> Self {
+        Self {
+            responses: Mutex::new(VecDeque::new()),
+            fallback: ok(model_text),
+            requests: Mutex::new(Vec::new()),
+            delay: Duration::ZERO,
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// Menjawab sesuai urutan antrean, lalu memakai respons terakhir untuk seterusnya.
+    pub fn sequence(responses: Vec<HttpResponse>) -> Self {
+        let fallback = responses.last().cloned().expect("minimal satu respons");
+        Self {
+            responses: Mutex::new(responses.into()),
+            fallback,
+            requests: Mutex::new(Vec::new()),
+            delay: Duration::ZERO,
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// Menunda setiap respons async selama `delay`.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Jumlah maksimum request async yang pernah berjalan bersamaan.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn call_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    fn next_response(&self, request: HttpRequest) -> HttpResponse {
+        self.requests.lock().unwrap().push(request);
+        self.responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_else(|| self.fallback.clone())
+    }
+}
+
+/// Validator yang memakai `provider` tanpa retry; `configure` menambah pengaturan lain
+/// (misalnya `|b| b.locale(Locale::En)`), atau `|b| b` jika tidak ada.
+pub fn validator_with(
+    provider: Arc<FakeProvider>,
+    configure: impl FnOnce(SemanticValidatorBuilder) -> SemanticValidatorBuilder,
+) -> SemanticValidator {
+    let builder = SemanticValidator::builder()
+        .provider(provider)
+        .retry_policy(RetryPolicy::none());
+    configure(builder).build().unwrap()
+}
+
+/// Teks yang dilihat model pada request ke-`index` (lihat [`sent_text`]).
+pub fn sent_prompt(provider: &FakeProvider, index: usize) -> String {
+    sent_text(&provider.requests()[index])
+}
+
+/// Seluruh teks yang dilihat model pada satu request: instruksi sistem (jika ada) lalu prompt.
+pub fn sent_text(request: &HttpRequest) -> String {
+    let prompt = request.body["prompt"].as_str().unwrap();
+    match request.body["system"].as_str() {
+        Some(system) => format!("{}\n\n{}", system, prompt),
+        None => prompt.to_string(),
+    }
+}
+
+pub fn ok(model_text: &str) -> HttpResponse {
+    HttpResponse {
+        status: 200,
+        headers: Vec::new(),
+        body: model_text.to_string(),
+    }
+}
+
+pub fn status(code: u16, body: &str) -> HttpResponse {
+    HttpResponse {
+        status: code,
+        headers: Vec::new(),
+        body: body.to_string(),
+    }
+}
+
+impl LlmProvider for FakeProvider {
+    fn name(&self) -> &str {
+        "fake"
+    }
+
+    fn build_request(&self, request: &LlmRequest<'_>) -> ProviderResult<HttpRequest> {
+        Ok(HttpRequest {
+            url: format!("fake://{}", request.model),
+            headers: Vec::new(),
+            body: serde_json::json!({ "system": request.system, "prompt": request.prompt }),
+        })
+    }
+
+    fn decode_response(&self, _request: &LlmRequest<'_>, response: &HttpResponse) -> ProviderResult<String> {
+        Ok(response.body.clone())
+    }
+
+    fn map_error(&self, request: &LlmRequest<'_>, response: &HttpResponse) -> ValidationError {
+        ValidationError::Http {
+            status: response.status,
+            body: format!("fake error for {}", request.model),
+        }
+    }
+
+    fn send_blocking(&self, _transport: &HttpTransport, request: HttpRequest) -> ProviderResult<HttpResponse> {
+        Ok(self.next_response(request))
+    }
+
+    fn send<'a>(
+        &'a self,
+        _transport: &'a HttpTransport,
+        request: HttpRequest,
+    ) -> ProviderFuture<'a, ProviderResult<HttpResponse>> {
+        let response = self.next_response(request);
+        Box::pin(async move {
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(running, Ordering::SeqCst);
+            if !self.delay.is_zero() {
+                tokio::time::sleep(self.delay).await;
+            }
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(response)
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod fake_provider;
+pub mod stub_server;
@@ -1,7 +1,7 @@
 # Ditempatkan di: .venv/Lib/site-packages/validation_semantic/__init__.pyi
 # Atau di root source paket Anda agar bisa disertakan saat build oleh Maturin.
 
-from typing import Any, Dict
+from typing import Any, Callable, Dict, List, Optional, Tuple
 
 class SupportedModel:
     # Mendefinisikan atribut kelas agar IDE tahu keberadaannya
@@ -18,8 +18,109 @@ class SupportedModel:
     def __repr__(self) -> str: ...
     # def __init__(self, ...) -> None: ... # Jika ada konstruktor Python
 
+class FileCache:
+    # Cache putusan persisten (JSONL) yang aman dibagi beberapa proses
+    def __init__(self, path: str, ttl_secs: Optional[int] = None) -> None: ...
+    @property
+    def path(self) -> str: ...
+    def stats(self) -> Dict[str, int]: ...
+    def compact(self) -> int: ...
+    def clear(self) -> None: ...
+    def __repr__(self) -> str: ...
+
+class SemanticValidator:
+    # Validator dengan konfigurasi sendiri (API key, model bawaan, timeout, base URL)
+    def __init__(
+        self,
+        api_key: str,
+        default_model: Optional[SupportedModel] = None,
+        timeout_secs: Optional[int] = None,
+        base_url: Optional[str] = None,
+        max_attempts: Optional[int] = None,
+        fallback_models: Optional[List[SupportedModel]] = None,
+        file_cache: Optional[FileCache] = None,
+        locale: Optional[str] = None,  # "id" (bawaan) atau "en"
+        rules_file: Optional[str] = None,  # path file aturan tipe input (.toml/.yaml/.yml)
+        extra_api_keys: Optional[List[str]] = None,  # key tambahan; bersama api_key membentuk pool
+        key_selection: Optional[str] = None,  # "round_robin" (bawaan) atau "least_recently_rate_limited"
+        key_cooldown_secs: Optional[int] = None,  # karantina key yang terkena 429/403 (bawaan 60)
+    ) -> None: ...
+    @staticmethod
+    def openai_compatible(
+        base_url: str,
+        model: str,
+        api_key: Optional[str] = None,
+        json_mode: bool = True,
+        timeout_secs: Optional[int] = None,
+        max_attempts: Optional[int] = None,
+        file_cache: Optional[FileCache] = None,
+        locale: Optional[str] = None,
+        rules_file: Optional[str] = None,
+    ) -> "SemanticValidator": ...
+    @property
+    def locale(self) -> str: ...
+    # Hasil: {"valid", "message", "model", "stage", "code", "confidence", "suggestion", "latency_ms"}
+    def validate(self, text: str, label: str, model: Optional[SupportedModel] = None) -> Dict[str, Any]: ...
+    def validate_batch(
+        self, items: List[Tuple[str, str, str]], model: Optional[SupportedModel] = None
+    ) -> Dict[str, Dict[str, Any]]: ...
+    def validate_many(
+        self,
+        items: List[Tuple[str, str, str]],
+        model: Optional[SupportedModel] = None,
+        concurrency: int = 4,
+        progress: Optional[Callable[[Dict[str, int]], None]] = None,
+    ) -> List[Dict[str, Any]]: ...
+    # form: {"fields": {nama: {"value": ..., "input_type": ...}}, "relationships": [{"kind": ..., "fields": [...]}]}
+    def check_form(self, form: Dict[str, Any], model: Optional[SupportedModel] = None) -> Dict[str, Any]: ...
+    # Hasil: {"candidates": [{"input_type", "score", "source"}], "label_mismatch": ..., "model": ...}
+    def classify_input(self, value: str, label: Optional[str] = None, model: Optional[SupportedModel] = None) -> Dict[str, Any]: ...
+    def set_rate_limit(self, model: SupportedModel, requests_per_minute: int) -> None: ...
+    def configure_cache(self, capacity: int = 1024, ttl_secs: Optional[int] = None) -> None: ...
+    def cache_stats(self) -> Dict[str, int]: ...
+    def clear_cache(self) -> None: ...
+    # Pemakaian tiap API key di pool (tanpa isi key)
+    def key_usage(self) -> List[Dict[str, Any]]: ...
+    def __repr__(self) -> str: ...
+
+# Hierarki exception; atribut diisi sesuai jenis error (None jika tidak relevan)
+class ValidationSemanticError(Exception):
+    code: str
+    status: Optional[int]
+    retry_after: Optional[float]
+    raw: Optional[str]
+    reason: Optional[str]
+
+class RateLimitedError(ValidationSemanticError): ...
+class UnauthorizedError(ValidationSemanticError): ...
+class HttpError(ValidationSemanticError): ...
+class TransportError(ValidationSemanticError): ...
+class RequestTimeoutError(ValidationSemanticError): ...
+class ModelOutputError(ValidationSemanticError): ...
+class SchemaViolationError(ModelOutputError): ...
+class BlockedError(ValidationSemanticError): ...
+class ConfigError(ValidationSemanticError): ...
+
 # Definisikan signature untuk fungsi Anda
 def validate_input_py(text: str, model_selector: SupportedModel, input_type: str) -> Dict[str, Any]: ...
+# items: daftar tuple (field_id, value, input_type); hasil per field_id
+def validate_batch_py(items: List[Tuple[str, str, str]], model: SupportedModel) -> Dict[str, Dict[str, Any]]: ...
+
+# API key untuk validate_input_py/validate_batch_py; bisa dirotasi kapan saja
+def configure(api_key: str) -> None: ...
+def reset_configuration() -> None: ...
+
+# Cache hasil milik validate_input_py (nonaktif sampai dikonfigurasi)
+def configure_cache(capacity: int = 1024, ttl_secs: Optional[int] = None) -> None: ...
+def cache_stats() -> Dict[str, int]: ...
+def clear_cache() -> None: ...
+
+# Tipe input bawaan: [{"id", "label", "description", "aliases"}]
+def list_input_types() -> List[Dict[str, Any]]: ...
+# Tipe kanonis untuk sebuah label, atau None jika label tidak dikenal
+def resolve_input_type(label: str) -> Optional[Dict[str, Any]]: ...
+# Pemetaan longgar label formulir ("No. WA", "bday"): {input_type, confidence, method, matched} atau None
+def resolve_label(label: str) -> Optional[Dict[str, Any]]: ...
 
 # Definisikan konstanta level modul
 GEMINI_FLASH: int